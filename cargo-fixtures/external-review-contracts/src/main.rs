@@ -61,7 +61,7 @@ fn main() {
         }
         .redacted_display()
         .to_string(),
-        "0.0.0.99 \"0.0.0.99\""
+        "192.168.0.0 \"192.168.0.0\""
     );
     assert_eq!(
         GenericPolicyBox::<Secret> {
@@ -168,7 +168,7 @@ fn main() {
             .redacted_display()
             .to_string(),
     ] {
-        assert_eq!(output, "0.0.0.99 \"0.0.0.99\"");
+        assert_eq!(output, "192.168.0.0 \"192.168.0.0\"");
     }
     let event = ObservedEvent {
         value: Observed(String::from("raw-canary")),
@@ -18,12 +18,12 @@ struct RenamedPolicies {
 fn main() {
     let redacted = RenamedPolicies {
         amount: 42,
-        address: "192.0.2.7".parse().unwrap(),
-        v4: "192.0.2.8".parse().unwrap(),
+        address: "192.7.2.1".parse().unwrap(),
+        v4: "192.8.2.1".parse().unwrap(),
     }
     .redact();
 
     assert_eq!(redacted.amount, 0);
-    assert_eq!(redacted.address, "0.0.0.7".parse::<ClientIp>().unwrap());
-    assert_eq!(redacted.v4, Ipv4Addr::new(0, 0, 0, 8));
+    assert_eq!(redacted.address, "192.7.0.0".parse::<ClientIp>().unwrap());
+    assert_eq!(redacted.v4, Ipv4Addr::new(192, 8, 0, 0));
 }
@@ -2,7 +2,9 @@
 //!
 //! This module handles attributes on the struct/enum itself, not on fields.
 
-use syn::{Attribute, Meta, Result};
+use syn::{
+    Attribute, Data, Fields, GenericArgument, Meta, PathArguments, Result, Type, parse_quote,
+};
 
 /// Rejects field-only helpers when they are attached to a derived container.
 pub(crate) fn reject_field_only_container_attrs(attrs: &[Attribute]) -> Result<()> {
@@ -34,6 +36,21 @@ pub(crate) struct ContainerOptions {
     /// - `Sensitive` skips `Debug` (lets `SensitiveDisplay` provide it).
     /// - `SensitiveDisplay` skips `slog` and `tracing` (lets `Sensitive` provide them).
     pub(crate) dual: bool,
+    /// If true, `Sensitive` also generates a `serde::Serialize` impl that
+    /// serializes a redacted clone (requires the `json` feature).
+    pub(crate) serde_redact: bool,
+    /// If true, `Sensitive` redacts an enum to its `Default` value, so the
+    /// redacted form does not reveal which variant was set.
+    pub(crate) opaque_variant: bool,
+    /// If true, `Sensitive` also generates a sibling `{Name}Redacted` struct
+    /// whose policy fields are `Redacted<T>`, plus a `From<Name>` conversion.
+    pub(crate) gen_redacted_type: bool,
+    /// If true, `Sensitive` emits each named field as its own slog key instead
+    /// of one placeholder value (requires the `slog` feature).
+    pub(crate) slog_flatten: bool,
+    /// Policy applied to every unannotated string-like field
+    /// (`#[sensitive(default_policy = Pii)]`).
+    pub(crate) default_policy: Option<syn::Path>,
 }
 
 /// Parses container-level `#[sensitive(...)]` attributes.
@@ -58,9 +75,27 @@ pub(crate) fn parse_container_options(attrs: &[Attribute]) -> Result<ContainerOp
                     if meta.path.is_ident("dual") {
                         options.dual = true;
                         Ok(())
+                    } else if meta.path.is_ident("serde_redact") {
+                        options.serde_redact = true;
+                        Ok(())
+                    } else if meta.path.is_ident("opaque_variant") {
+                        options.opaque_variant = true;
+                        Ok(())
+                    } else if meta.path.is_ident("gen_redacted_type") {
+                        options.gen_redacted_type = true;
+                        Ok(())
+                    } else if meta.path.is_ident("slog_flatten") {
+                        options.slog_flatten = true;
+                        Ok(())
+                    } else if meta.path.is_ident("default_policy") {
+                        if options.default_policy.is_some() {
+                            return Err(meta.error("duplicate `default_policy` container option"));
+                        }
+                        options.default_policy = Some(meta.value()?.parse()?);
+                        Ok(())
                     } else {
                         Err(meta.error(format!(
-                            "unknown container option `{}`; expected `dual`",
+                            "unknown container option `{}`; expected `dual`, `serde_redact`, `opaque_variant`, `gen_redacted_type`, `slog_flatten`, or `default_policy`",
                             meta.path
                                 .get_ident()
                                 .map_or_else(|| "?".to_string(), ToString::to_string)
@@ -80,6 +115,76 @@ pub(crate) fn parse_container_options(attrs: &[Attribute]) -> Result<ContainerOp
     Ok(options)
 }
 
+/// Annotates every eligible field with `#[sensitive(policy)]`.
+///
+/// A field is eligible when it carries neither `#[sensitive(...)]` nor
+/// `#[not_sensitive]` and its type is spelled as a string leaf (`String`,
+/// `Cow<str>`, or `Box<str>`), possibly wrapped in `Option`, `Vec`, or `Box`
+/// (`Option<String>`, `Vec<Option<String>>`). Policies recurse through those
+/// wrappers. Other containers and types deriving `Sensitive` are left to their
+/// own annotations. Running before any field is classified means every later
+/// pass sees the same explicit annotation a user could have written.
+pub(crate) fn apply_default_policy(data: &mut Data, policy: &syn::Path) {
+    let fields: Box<dyn Iterator<Item = &mut Fields>> = match data {
+        Data::Struct(data) => Box::new(std::iter::once(&mut data.fields)),
+        Data::Enum(data) => Box::new(data.variants.iter_mut().map(|variant| &mut variant.fields)),
+        Data::Union(_) => return,
+    };
+    for field in fields.flat_map(|fields| fields.iter_mut()) {
+        let annotated = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("sensitive") || attr.path().is_ident("not_sensitive"));
+        if !annotated && is_defaultable(&field.ty) {
+            field.attrs.push(parse_quote!(#[sensitive(#policy)]));
+        }
+    }
+}
+
+/// Matches a string leaf, or an `Option`, `Vec`, or `Box` wrapping one.
+fn is_defaultable(ty: &Type) -> bool {
+    if is_string_leaf(ty) {
+        return true;
+    }
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+    let wrapper = ["Option", "Vec", "Box"]
+        .iter()
+        .any(|wrapper| segment.ident == wrapper);
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) if wrapper && args.args.len() == 1 => {
+            is_defaultable(inner)
+        }
+        _ => false,
+    }
+}
+
+fn is_string_leaf(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+    match &segment.arguments {
+        PathArguments::None => segment.ident == "String",
+        PathArguments::AngleBracketed(args) => {
+            (segment.ident == "Cow" || segment.ident == "Box")
+                && args.args.iter().any(|arg| {
+                    matches!(arg, GenericArgument::Type(Type::Path(inner)) if inner.path.is_ident("str"))
+                })
+        }
+        PathArguments::Parenthesized(_) => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use quote::quote;
@@ -101,6 +206,8 @@ mod tests {
         let attrs = parse_attrs(quote! {});
         let options = parse_container_options(&attrs).unwrap();
         assert!(!options.dual);
+        assert!(!options.serde_redact);
+        assert!(!options.opaque_variant);
     }
 
     #[test]
@@ -119,6 +226,107 @@ mod tests {
         assert!(options.dual);
     }
 
+    #[test]
+    fn serde_redact_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(serde_redact)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.serde_redact);
+        assert!(!options.dual);
+    }
+
+    #[test]
+    fn opaque_variant_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(opaque_variant)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.opaque_variant);
+        assert!(!options.serde_redact);
+    }
+
+    #[test]
+    fn gen_redacted_type_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(gen_redacted_type)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.gen_redacted_type);
+        assert!(!options.opaque_variant);
+    }
+
+    #[test]
+    fn slog_flatten_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(slog_flatten)] });
+        let options = parse_container_options(&attrs).unwrap();
+        assert!(options.slog_flatten);
+        assert!(!options.gen_redacted_type);
+    }
+
+    #[test]
+    fn default_policy_is_parsed() {
+        let attrs = parse_attrs(quote! { #[sensitive(default_policy = redactable::Pii)] });
+        let options = parse_container_options(&attrs).unwrap();
+        let policy = options
+            .default_policy
+            .expect("default policy should be parsed");
+        assert_eq!(
+            quote!(#policy).to_string(),
+            quote!(redactable::Pii).to_string()
+        );
+    }
+
+    #[test]
+    fn duplicate_default_policy_errors() {
+        let attrs = parse_attrs(quote! {
+            #[sensitive(default_policy = Pii, default_policy = Secret)]
+        });
+        let error = parse_container_options(&attrs).unwrap_err();
+        assert!(error.to_string().contains("duplicate `default_policy`"));
+    }
+
+    #[test]
+    fn default_policy_annotates_only_unannotated_string_leaves_and_wrappers() {
+        let mut input: DeriveInput = syn::parse2(quote! {
+            struct Dummy<'a> {
+                name: String,
+                label: std::borrow::Cow<'a, str>,
+                code: Box<str>,
+                #[sensitive(Token)]
+                token: String,
+                #[not_sensitive]
+                id: String,
+                nickname: Option<String>,
+                aliases: Vec<String>,
+                middle: Option<Vec<Box<str>>>,
+                nested: Inner,
+                generic: Option<Inner>,
+                scores: Vec<u32>,
+                map: std::collections::HashMap<String, String>,
+                count: u32,
+            }
+        })
+        .unwrap();
+        apply_default_policy(&mut input.data, &parse_quote!(Pii));
+
+        let Data::Struct(data) = &input.data else {
+            unreachable!("input is a struct");
+        };
+        let annotated: Vec<String> = data
+            .fields
+            .iter()
+            .map(|field| {
+                let attrs = &field.attrs;
+                quote!(#(#attrs)*).to_string()
+            })
+            .collect();
+        let pii = quote!(#[sensitive(Pii)]).to_string();
+        assert_eq!(annotated[0], pii);
+        assert_eq!(annotated[1], pii);
+        assert_eq!(annotated[2], pii);
+        assert_eq!(annotated[3], quote!(#[sensitive(Token)]).to_string());
+        assert_eq!(annotated[4], quote!(#[not_sensitive]).to_string());
+        assert_eq!(annotated[5], pii);
+        assert_eq!(annotated[6], pii);
+        assert_eq!(annotated[7], pii);
+        assert!(annotated[8..].iter().all(String::is_empty));
+    }
+
     #[test]
     fn unknown_option_errors() {
         let attrs = parse_attrs(quote! { #[sensitive(unknown_option)] });
@@ -5,7 +5,7 @@
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{DataEnum, Fields, Result, spanned::Spanned};
+use syn::{DataEnum, Fields, Result, ext::IdentExt, spanned::Spanned};
 
 use crate::{
     DeriveOutput, crate_path,
@@ -14,7 +14,7 @@ use crate::{
         Strategy, parse_field_strategy, parse_redactable_field_options,
         reject_variant_sensitivity_attrs,
     },
-    transform::{DeriveContext, generate_field_transform},
+    transform::{DeriveContext, SharedPolicyFields, debug_field_value, generate_field_transform},
 };
 
 /// Context for deriving a single enum variant.
@@ -24,6 +24,7 @@ struct VariantContext<'a> {
     arms: &'a mut Vec<TokenStream>,
     debug_redacted_arms: &'a mut Vec<TokenStream>,
     debug_unredacted_arms: &'a mut Vec<TokenStream>,
+    redacted_fields: &'a mut Vec<String>,
     formatter: &'a Ident,
     fresh: &'a mut FreshIdentAllocator,
 }
@@ -43,6 +44,14 @@ pub(crate) fn derive_enum(
     let mut debug_redacted_arms = Vec::new();
     let mut debug_unredacted_arms = Vec::new();
     let mut debug_unredacted_generics = Vec::new();
+    let mut redacted_fields = Vec::new();
+    let shared_policy_fields = SharedPolicyFields::collect(
+        data.variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter()),
+        mapper,
+        fresh,
+    );
 
     for variant in data.variants {
         reject_variant_sensitivity_attrs(&variant.attrs)?;
@@ -53,6 +62,7 @@ pub(crate) fn derive_enum(
             arms: &mut arms,
             debug_redacted_arms: &mut debug_redacted_arms,
             debug_unredacted_arms: &mut debug_unredacted_arms,
+            redacted_fields: &mut redacted_fields,
             formatter,
             fresh,
         };
@@ -62,6 +72,7 @@ pub(crate) fn derive_enum(
             policy_predicates: &mut policy_applicable_generics,
             debug_unredacted_predicates: &mut debug_unredacted_generics,
             mapper,
+            shared_policy_fields: Some(&shared_policy_fields),
         };
 
         match variant.fields {
@@ -77,7 +88,9 @@ pub(crate) fn derive_enum(
         }
     }
 
+    let shared_definitions = shared_policy_fields.definitions();
     let body = quote! {
+        #shared_definitions
         match self {
             #(#arms),*
         }
@@ -109,6 +122,7 @@ pub(crate) fn derive_enum(
         debug_redacted_body,
         debug_unredacted_body,
         debug_unredacted_generics,
+        redacted_fields,
     })
 }
 
@@ -158,7 +172,14 @@ fn derive_named_variant(
         patterns.push(quote_spanned! { span => #ident: #binding });
         reconstructions.push(quote_spanned! { span => #ident: #binding });
 
-        let is_sensitive = matches!(&strategy, Strategy::Policy(_));
+        let is_sensitive = matches!(&strategy, Strategy::Policy(_) | Strategy::ProdOnlyPolicy(_));
+        if is_sensitive {
+            variant_ctx.redacted_fields.push(format!(
+                "{}.{}",
+                variant_ident.unraw(),
+                ident.unraw()
+            ));
+        }
         let transform = generate_field_transform(
             derive_ctx,
             ty,
@@ -166,7 +187,8 @@ fn derive_named_variant(
             span,
             &strategy,
             recursive_bound_override,
-        );
+        )?;
+        let debug_value = debug_field_value(&binding, span, &strategy);
         let debug_redacted_field = if is_sensitive {
             // Sensitive: use wildcard pattern to avoid unused binding
             debug_redacted_patterns.push(quote_spanned! { span => #ident: _ });
@@ -177,11 +199,11 @@ fn derive_named_variant(
             // Non-sensitive: normal binding, referenced in the field output
             debug_redacted_patterns.push(quote_spanned! { span => #ident: #binding });
             quote_spanned! { span =>
-                #debug.field(stringify!(#ident), #binding);
+                #debug.field(stringify!(#ident), #debug_value);
             }
         };
         let debug_unredacted_field = quote_spanned! { span =>
-            #debug.field(stringify!(#ident), #binding);
+            #debug.field(stringify!(#ident), #debug_value);
         };
 
         transforms.push(transform);
@@ -241,7 +263,12 @@ fn derive_unnamed_variant(
         let recursive_bound_override = parse_redactable_field_options(&field.attrs)?.recursive;
         bindings.push(ident);
 
-        let is_sensitive = matches!(&strategy, Strategy::Policy(_));
+        let is_sensitive = matches!(&strategy, Strategy::Policy(_) | Strategy::ProdOnlyPolicy(_));
+        if is_sensitive {
+            variant_ctx
+                .redacted_fields
+                .push(format!("{}.{index}", variant_ident.unraw()));
+        }
         let transform = generate_field_transform(
             derive_ctx,
             ty,
@@ -249,7 +276,8 @@ fn derive_unnamed_variant(
             span,
             &strategy,
             recursive_bound_override,
-        );
+        )?;
+        let debug_value = debug_field_value(&binding, span, &strategy);
         let debug_redacted_field = if is_sensitive {
             // Sensitive: use wildcard pattern to avoid unused binding
             debug_redacted_patterns.push(quote_spanned! { span => _ });
@@ -260,11 +288,11 @@ fn derive_unnamed_variant(
             // Non-sensitive: normal binding, referenced in the field output
             debug_redacted_patterns.push(quote_spanned! { span => #binding });
             quote_spanned! { span =>
-                #debug.field(#binding);
+                #debug.field(#debug_value);
             }
         };
         let debug_unredacted_field = quote_spanned! { span =>
-            #debug.field(#binding);
+            #debug.field(#debug_value);
         };
 
         transforms.push(transform);
@@ -2,16 +2,23 @@
 //!
 //! This module generates traversal logic for struct fields and collects generic
 //! parameters that require trait bounds.
+//!
+//! The generated `redact_with` body stays linear in the field count so wide
+//! structs (dozens of fields) compile quickly: one destructuring `let`, one
+//! rebinding statement per field, and one struct expression. Each statement
+//! calls a single trait method (`redact_with`, `PolicyField::apply_field`, or the
+//! mapper's scalar hook), so fields of the same type share one instantiation
+//! instead of each adding helper functions or closures.
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, quote_spanned};
-use syn::{DataStruct, Fields, Result, spanned::Spanned};
+use syn::{DataStruct, Fields, Result, ext::IdentExt, spanned::Spanned};
 
 use crate::{
     DeriveOutput, crate_path,
     fresh_ident::FreshIdentAllocator,
     strategy::{Strategy, parse_field_strategy, parse_redactable_field_options},
-    transform::{DeriveContext, generate_field_transform},
+    transform::{DeriveContext, debug_field_value, generate_field_transform},
 };
 
 pub(crate) fn derive_struct(
@@ -41,6 +48,7 @@ pub(crate) fn derive_struct(
                 #formatter.write_str(stringify!(#name))
             },
             debug_unredacted_generics: Vec::new(),
+            redacted_fields: Vec::new(),
         }),
     }
 }
@@ -64,6 +72,7 @@ fn derive_named_struct(
     let mut debug_redacted_patterns = Vec::new();
     let mut debug_unredacted_fields = Vec::new();
     let mut debug_unredacted_generics = Vec::new();
+    let mut redacted_fields = Vec::new();
 
     let mut ctx = DeriveContext {
         container_path,
@@ -71,6 +80,7 @@ fn derive_named_struct(
         policy_predicates: &mut policy_applicable_generics,
         debug_unredacted_predicates: &mut debug_unredacted_generics,
         mapper,
+        shared_policy_fields: None,
     };
 
     for field in fields.named {
@@ -83,7 +93,10 @@ fn derive_named_struct(
         patterns.push(quote_spanned! { span => #ident: #binding });
         reconstructions.push(quote_spanned! { span => #ident: #binding });
 
-        let is_sensitive = matches!(&strategy, Strategy::Policy(_));
+        let is_sensitive = matches!(&strategy, Strategy::Policy(_) | Strategy::ProdOnlyPolicy(_));
+        if is_sensitive {
+            redacted_fields.push(ident.unraw().to_string());
+        }
         let transform = generate_field_transform(
             &mut ctx,
             ty,
@@ -91,7 +104,8 @@ fn derive_named_struct(
             span,
             &strategy,
             recursive_bound_override,
-        );
+        )?;
+        let debug_value = debug_field_value(&binding, span, &strategy);
         let debug_redacted_field = if is_sensitive {
             // Sensitive: use wildcard pattern to avoid unused binding
             debug_redacted_patterns.push(quote_spanned! { span => #ident: _ });
@@ -102,11 +116,11 @@ fn derive_named_struct(
             // Non-sensitive: normal binding, referenced in the field output
             debug_redacted_patterns.push(quote_spanned! { span => #ident: #binding });
             quote_spanned! { span =>
-                #debug.field(stringify!(#ident), #binding);
+                #debug.field(stringify!(#ident), #debug_value);
             }
         };
         let debug_unredacted_field = quote_spanned! { span =>
-            #debug.field(stringify!(#ident), #binding);
+            #debug.field(stringify!(#ident), #debug_value);
         };
 
         transforms.push(transform);
@@ -141,6 +155,7 @@ fn derive_named_struct(
             }
         },
         debug_unredacted_generics,
+        redacted_fields,
     })
 }
 
@@ -162,6 +177,7 @@ fn derive_unnamed_struct(
     let mut debug_redacted_patterns = Vec::new();
     let mut debug_unredacted_fields = Vec::new();
     let mut debug_unredacted_generics = Vec::new();
+    let mut redacted_fields = Vec::new();
 
     let mut ctx = DeriveContext {
         container_path,
@@ -169,6 +185,7 @@ fn derive_unnamed_struct(
         policy_predicates: &mut policy_applicable_generics,
         debug_unredacted_predicates: &mut debug_unredacted_generics,
         mapper,
+        shared_policy_fields: None,
     };
 
     for (index, field) in fields.unnamed.into_iter().enumerate() {
@@ -180,7 +197,10 @@ fn derive_unnamed_struct(
         let recursive_bound_override = parse_redactable_field_options(&field.attrs)?.recursive;
         bindings.push(ident);
 
-        let is_sensitive = matches!(&strategy, Strategy::Policy(_));
+        let is_sensitive = matches!(&strategy, Strategy::Policy(_) | Strategy::ProdOnlyPolicy(_));
+        if is_sensitive {
+            redacted_fields.push(index.to_string());
+        }
         let transform = generate_field_transform(
             &mut ctx,
             ty,
@@ -188,7 +208,8 @@ fn derive_unnamed_struct(
             span,
             &strategy,
             recursive_bound_override,
-        );
+        )?;
+        let debug_value = debug_field_value(&binding, span, &strategy);
         let debug_redacted_field = if is_sensitive {
             // Sensitive: use wildcard pattern to avoid unused binding
             debug_redacted_patterns.push(quote_spanned! { span => _ });
@@ -199,11 +220,11 @@ fn derive_unnamed_struct(
             // Non-sensitive: normal binding, referenced in the field output
             debug_redacted_patterns.push(quote_spanned! { span => #binding });
             quote_spanned! { span =>
-                #debug.field(#binding);
+                #debug.field(#debug_value);
             }
         };
         let debug_unredacted_field = quote_spanned! { span =>
-            #debug.field(#binding);
+            #debug.field(#debug_value);
         };
 
         transforms.push(transform);
@@ -238,5 +259,6 @@ fn derive_unnamed_struct(
             }
         },
         debug_unredacted_generics,
+        redacted_fields,
     })
 }
@@ -146,6 +146,10 @@ impl<'ast> Visit<'ast> for OwnerTypeParameterVisitor<'_> {
 }
 
 /// Adds deduplicated where predicates to a generic declaration.
+///
+/// The declaration keeps any type-parameter defaults (`T = String`);
+/// `split_for_impl` drops them from the impl position, so predicates only ever
+/// name the parameter itself.
 pub(crate) fn add_predicates(
     mut generics: Generics,
     predicates: &[WherePredicate],
@@ -362,6 +366,14 @@ pub(crate) fn push_display_predicate(predicates: &mut Vec<WherePredicate>, ty: &
     push_unique(predicates, parse_quote!(#ty: ::core::fmt::Display));
 }
 
+pub(crate) fn push_fmt_trait_predicate(
+    predicates: &mut Vec<WherePredicate>,
+    ty: &Type,
+    trait_ident: &Ident,
+) {
+    push_unique(predicates, parse_quote!(#ty: ::core::fmt::#trait_ident));
+}
+
 pub(crate) fn push_redacted_display_predicate(predicates: &mut Vec<WherePredicate>, ty: &Type) {
     let trait_path = crate_path("RedactableWithFormatter");
     push_unique(predicates, parse_quote!(#ty: #trait_path));
@@ -78,7 +78,10 @@ mod fresh_ident;
 mod generics;
 mod not_sensitive;
 mod redacted_display;
+mod redacted_type;
 mod sensitive;
+#[cfg(feature = "serde")]
+mod serialize_impl;
 mod strategy;
 mod transform;
 
@@ -105,6 +108,40 @@ use sensitive::{DeriveKind, expand, expand_with_mode};
 /// a type needs structural and display redaction together); those derives
 /// document what each option selects.
 ///
+/// `#[sensitive(serde_redact)]` (requires the `json` feature of `redactable`) also
+/// emits a `serde::Serialize` impl that serializes `self.clone().redact()`, so the
+/// type cannot be serialized with its unredacted values. Do not combine it with
+/// `#[derive(Serialize)]`. The generated impl uses serde's default representation
+/// (field names, externally tagged enums) and ignores `#[serde(...)]` attributes.
+/// On generic types it requires `Self: Clone` and `Serialize` for every field type.
+///
+/// `#[sensitive(opaque_variant)]` (enums only) redacts the whole value to the enum's
+/// `Default`, typically a dedicated `#[default] Redacted` variant, so the redacted
+/// form does not reveal which variant was set. The redacted `Debug` prints
+/// `Name(<redacted>)`. Field policies are not applied; every value collapses to the
+/// same marker. `SensitiveDisplay` and `SensitiveDual` reject it because their
+/// templates are chosen per variant.
+///
+/// `#[sensitive(gen_redacted_type)]` (structs only) also emits a sibling
+/// `{Name}Redacted` struct with the same fields and visibility. Each
+/// `#[sensitive(Policy)]` field becomes `redactable::Redacted<T>`, which has no public
+/// constructor, and `From<Name>` redacts the value to build it. The companion implements
+/// `Debug` only, printing `#[not_sensitive]` fields without `Debug` as `<opaque>`.
+/// `prod_only` fields are rejected, as are `SensitiveDisplay` and `SensitiveDual`.
+///
+/// `#[sensitive(slog_flatten)]` (named-field structs only, requires the `slog`
+/// feature of `redactable`) changes the generated `slog::Value` to emit each field
+/// under its own key instead of a single `[REDACTED]` placeholder. Policy fields
+/// (including `prod_only` ones) are emitted as their redacted string; other fields
+/// use their own `slog::Value` impl. The key the value was logged under is unused.
+///
+/// `#[sensitive(default_policy = Policy)]` applies `Policy` to every field that has
+/// no `#[sensitive(...)]` or `#[not_sensitive]` annotation and is spelled `String`,
+/// `Cow<str>`, or `Box<str>`, alone or inside `Option`, `Vec`, or `Box`
+/// (`Option<String>`, `Vec<String>`). Other unannotated fields, including maps and
+/// nested `Sensitive` types, keep the default traversal. `SensitiveDisplay` and
+/// `SensitiveDual` accept the option too, so both halves agree.
+///
 /// # Field Attributes
 ///
 /// - **No annotation**: The field is traversed by default. Scalars pass through unchanged; nested
@@ -117,9 +154,17 @@ use sensitive::{DeriveKind, expand, expand_with_mode};
 ///   values. Works for `String`, `Option<String>`, `Vec<String>`, `Box<String>`. Scalars can only
 ///   use `#[sensitive(Secret)]`.
 ///
+/// - `#[sensitive(Policy, prod_only)]`: Applies the policy only when the deriving crate is built
+///   without `debug_assertions`; debug builds treat the field as unannotated. This ties redaction
+///   to the build profile rather than the deployment, so a debug build running in production logs
+///   the raw value. Redacted `Debug` still prints `[REDACTED]`, and `SensitiveDisplay` and
+///   `SensitiveDual` reject the option.
+///
 /// - `#[not_sensitive]`: Explicit passthrough - the field is not transformed at all. Use this
 ///   for foreign types that don't implement `RedactableWithMapper`. This is equivalent to wrapping
 ///   the field type in `NotSensitiveValue<T>`, but without changing the type signature.
+///   The field needs no trait at all: a concrete type without `Debug`, such as
+///   `Box<dyn Fn()>`, prints `<opaque>` in the generated `Debug` output.
 ///
 /// Unions are rejected at compile time.
 ///
@@ -128,12 +173,17 @@ use sensitive::{DeriveKind, expand, expand_with_mode};
 /// - `RedactableWithMapper`: always generated.
 /// - `Redactable`: always generated. Provides `.redact()` and certifies the type for the
 ///   redacted-output extension traits (`RedactedOutputExt`, `RedactedJsonExt`, `SlogRedactedExt`).
+///   `Clone` is not generated: the borrowed `RedactedOutputExt` and `RedactedJsonExt` adapters
+///   clone before redacting and need `#[derive(Clone)]`, while the consuming adapters do not.
 /// - `Debug`: redacted by default; actual values in the consumer's `cfg(test)` builds or when
 ///   `redactable`'s `testing` feature is enabled.
 /// - `slog::Value` + `SlogRedacted` (requires `slog` feature): borrowed generated output is a
 ///   fixed fail-closed placeholder and never clones or serializes the raw reference. Owned values
 ///   can use `SlogRedactedExt::slog_redacted_json` for redact-then-serialize structured output.
 /// - `TracingRedacted` (requires `tracing` feature): marker trait.
+/// - `REDACTED_FIELDS`: an inherent `&'static [&'static str]` constant naming the
+///   `#[sensitive(Policy)]` fields in declaration order. Tuple fields use their index and enum
+///   fields are prefixed with their variant (`Variant.field`).
 #[proc_macro_derive(Sensitive, attributes(sensitive, not_sensitive, redactable))]
 pub fn derive_sensitive_container(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -147,7 +197,10 @@ pub fn derive_sensitive_container(input: proc_macro::TokenStream) -> proc_macro:
 ///
 /// Use this instead of combining `Sensitive` and `SensitiveDisplay` with the
 /// legacy `#[sensitive(dual)]` coordination attribute.
-#[proc_macro_derive(SensitiveDual, attributes(sensitive, not_sensitive, redactable, error))]
+#[proc_macro_derive(
+    SensitiveDual,
+    attributes(sensitive, not_sensitive, redactable, error, display)
+)]
 pub fn derive_sensitive_dual(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let sensitive = expand_with_mode(input.clone(), DeriveKind::Sensitive, true);
@@ -278,8 +331,10 @@ pub fn derive_not_sensitive_display(input: proc_macro::TokenStream) -> proc_macr
 /// - `#[sensitive(Policy)]`: Apply the policy's redaction rules
 /// - `#[not_sensitive]`: Render raw via `Display` (use for types without `RedactableWithFormatter`)
 ///
-/// The display template is taken from `#[error("...")]` (thiserror-style) or from
-/// doc comments (displaydoc-style). If neither is present, the derive fails.
+/// The display template is taken from `#[display("...")]`, `#[error("...")]`
+/// (thiserror-style), or doc comments (displaydoc-style), in that order of
+/// precedence. `#[display]` suits types that are not errors. If none is present,
+/// the derive fails.
 ///
 /// Fields are redacted by reference, so field types do not need `Clone`.
 /// A custom `PolicyApplicableRef` leaf nested inside a container can explicitly
@@ -310,7 +365,7 @@ pub fn derive_not_sensitive_display(input: proc_macro::TokenStream) -> proc_macr
 /// - `TracingRedacted`: marker trait (requires `tracing` feature).
 #[proc_macro_derive(
     SensitiveDisplay,
-    attributes(sensitive, not_sensitive, redactable, error)
+    attributes(sensitive, not_sensitive, redactable, error, display)
 )]
 pub fn derive_sensitive_display(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -1,19 +1,20 @@
 //! Generic-bound construction for redacted `Display` impls.
 //!
 //! Computes the `where` predicates of the generated implementation from each
-//! field's strategy: direct marker formatting bounds, legacy policy
-//! formatting bounds, generated policy formatting bounds, or plain
-//! `Display`/`Debug` bounds, with recursive containers routed through the
-//! recursive-policy-kinds. Bounds are chosen per field and deduplicated so
-//! the generated impl carries exactly the bounds its expansion needs — no
-//! more, no less.
+//! field's strategy: direct marker formatting bounds, legacy policy formatting
+//! bounds, generated policy formatting bounds, or plain `Display`/`Debug`
+//! bounds, with recursive containers routed through the recursive-policy-kinds.
+//! Numeric specifiers on `#[not_sensitive]` fields add the matching `core::fmt`
+//! trait bound (`LowerHex` for `{code:x}`). Bounds are chosen per field and
+//! deduplicated so the generated impl carries exactly the bounds its expansion
+//! needs — no more, no less.
 
 use crate::{
     generics::{
         OwnerTypeParameterUsage, owner_type_parameter_usage, policy_is_owner_type_parameter,
         push_debug_predicate, push_direct_marker_debug_formatting_predicates,
         push_direct_marker_display_formatting_predicates, push_display_predicate,
-        push_generated_policy_debug_formatting_predicate,
+        push_fmt_trait_predicate, push_generated_policy_debug_formatting_predicate,
         push_generated_policy_display_formatting_predicate,
         push_legacy_policy_debug_formatting_predicates,
         push_legacy_policy_display_formatting_predicates, push_policy_debug_formatting_predicate,
@@ -23,10 +24,38 @@ use crate::{
     strategy::Strategy,
 };
 
-use super::model::{FieldInfo, FormatMode};
+use super::model::{FieldFormats, FieldInfo, FormatMode};
 
-#[allow(clippy::too_many_lines)]
 pub(super) fn collect_bounds(
+    field: &FieldInfo<'_>,
+    formats: &FieldFormats,
+    generics: &syn::Generics,
+    display_generics: &mut Vec<syn::WherePredicate>,
+    debug_generics: &mut Vec<syn::WherePredicate>,
+    policy_ref_generics: &mut Vec<syn::WherePredicate>,
+    nested_generics: &mut Vec<syn::WherePredicate>,
+) {
+    if let Some(mode) = formats.text {
+        collect_text_bounds(
+            field,
+            mode,
+            generics,
+            display_generics,
+            debug_generics,
+            policy_ref_generics,
+            nested_generics,
+        );
+    }
+    // Codegen admits numeric specifiers only on `#[not_sensitive]` fields.
+    if !field.recursive_bound_override && matches!(field.strategy, Strategy::NotSensitive) {
+        for numeric in &formats.numeric {
+            push_fmt_trait_predicate(display_generics, field.ty, &numeric.trait_ident());
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn collect_text_bounds(
     field: &FieldInfo<'_>,
     mode: FormatMode,
     generics: &syn::Generics,
@@ -77,6 +106,9 @@ pub(super) fn collect_bounds(
         Strategy::WalkDefault => {
             push_redacted_display_predicate(nested_generics, field.ty);
         }
+        Strategy::ProdOnlyPolicy(_) => {
+            unreachable!("`prod_only` is rejected while building display fields")
+        }
         Strategy::NotSensitive => match mode {
             FormatMode::Display => push_display_predicate(display_generics, field.ty),
             FormatMode::Debug => push_debug_predicate(debug_generics, field.ty),
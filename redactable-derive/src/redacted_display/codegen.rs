@@ -23,9 +23,12 @@ use crate::{
 use super::{
     RedactedDisplayOutput,
     bounds::collect_bounds,
-    model::{FieldInfo, FormatArgsOutput, FormatMode, PlaceholderKey, build_fields_from_syn},
+    model::{
+        FieldFormats, FieldInfo, FormatArgsOutput, PlaceholderFormat, PlaceholderKey,
+        build_fields_from_syn,
+    },
     template::{
-        merge_mode, parse_placeholders, template_from_attrs, validate_positional_placeholders,
+        parse_placeholders, record_format, template_from_attrs, validate_positional_placeholders,
     },
 };
 
@@ -153,9 +156,9 @@ fn build_format_args(
 ) -> Result<FormatArgsOutput> {
     let placeholders = parse_placeholders(template)?;
     validate_positional_placeholders(&placeholders)?;
-    let mut named_args: BTreeMap<String, (Ident, Ident, &'_ FieldInfo<'_>, FormatMode)> =
+    let mut named_args: BTreeMap<String, (Ident, Ident, &'_ FieldInfo<'_>, FieldFormats)> =
         BTreeMap::new();
-    let mut positional_args: Vec<Option<(Ident, &'_ FieldInfo<'_>, FormatMode)>> = Vec::new();
+    let mut positional_args: Vec<Option<(Ident, &'_ FieldInfo<'_>, FieldFormats)>> = Vec::new();
     let mut display_generics = Vec::new();
     let mut debug_generics = Vec::new();
     let mut policy_ref_generics = Vec::new();
@@ -173,15 +176,20 @@ fn build_format_args(
                             format!("unknown field `{name}` in format string"),
                         )
                     })?;
+                reject_numeric_format_on_redacted_field(
+                    field,
+                    placeholder.format,
+                    placeholder.span,
+                )?;
                 let entry = named_args.entry(canonical_name(&name)).or_insert_with(|| {
                     (
                         fresh.fresh_with_ident("__redacted_", &field.ident),
                         field.ident.clone(),
                         field,
-                        placeholder.mode,
+                        FieldFormats::default(),
                     )
                 });
-                entry.3 = merge_mode(entry.3, placeholder.mode);
+                record_format(&mut entry.3, placeholder.format);
             }
             PlaceholderKey::Index(index) => {
                 if positional_args.len() <= index {
@@ -193,10 +201,15 @@ fn build_format_args(
                         format!("unknown positional field index {index} in format string"),
                     )
                 })?;
+                reject_numeric_format_on_redacted_field(
+                    field,
+                    placeholder.format,
+                    placeholder.span,
+                )?;
                 let arg_ident = fresh.fresh(&format!("__redacted_{index}"));
-                let entry =
-                    positional_args[index].get_or_insert((arg_ident, field, placeholder.mode));
-                entry.2 = merge_mode(entry.2, placeholder.mode);
+                let entry = positional_args[index]
+                    .get_or_insert_with(|| (arg_ident, field, FieldFormats::default()));
+                record_format(&mut entry.2, placeholder.format);
             }
         }
     }
@@ -205,11 +218,11 @@ fn build_format_args(
     let mut positional_idents = Vec::new();
     let mut named_pairs = Vec::new();
 
-    for (_, (arg_ident, name_ident, field, mode)) in named_args {
+    for (_, (arg_ident, name_ident, field, formats)) in named_args {
         let expr = redacted_expr_for_field(field);
         collect_bounds(
             field,
-            mode,
+            &formats,
             generics,
             &mut display_generics,
             &mut debug_generics,
@@ -222,11 +235,11 @@ fn build_format_args(
         named_pairs.push(quote! { #name_ident = #arg_ident });
     }
 
-    for (arg_ident, field, mode) in positional_args.into_iter().flatten() {
+    for (arg_ident, field, formats) in positional_args.into_iter().flatten() {
         let expr = redacted_expr_for_field(field);
         collect_bounds(
             field,
-            mode,
+            &formats,
             generics,
             &mut display_generics,
             &mut debug_generics,
@@ -248,9 +261,16 @@ fn build_format_args(
         }
     };
 
+    // An outer width or precision (`{:>40}`, `{:.10}`) applies to the whole
+    // rendered template, so the output is buffered and padded only when one is
+    // set.
     let prelude = quote! {
         #(#prelude_bindings)*
-        #formatter.write_fmt(#format_args)
+        if #formatter.width().is_some() || #formatter.precision().is_some() {
+            #formatter.pad(&::std::fmt::format(#format_args))
+        } else {
+            #formatter.write_fmt(#format_args)
+        }
     };
 
     Ok(FormatArgsOutput {
@@ -262,6 +282,30 @@ fn build_format_args(
     })
 }
 
+/// Rejects numeric specifiers (`{code:x}`) on fields that are redacted: a
+/// policy or walked field renders redacted text, which only `Display` and
+/// `Debug` can format. `#[not_sensitive]` fields pass the spec straight through.
+fn reject_numeric_format_on_redacted_field(
+    field: &FieldInfo<'_>,
+    format: PlaceholderFormat,
+    span: proc_macro2::Span,
+) -> Result<()> {
+    match format {
+        PlaceholderFormat::Numeric(numeric)
+            if !matches!(field.strategy, Strategy::NotSensitive) =>
+        {
+            Err(syn::Error::new(
+                span,
+                format!(
+                    "unsupported format specifier `{}` on a redacted field; only Display and Debug are supported unless the field is `#[not_sensitive]`",
+                    numeric.specifier()
+                ),
+            ))
+        }
+        PlaceholderFormat::Text(_) | PlaceholderFormat::Numeric(_) => Ok(()),
+    }
+}
+
 fn redacted_expr_for_field(field: &FieldInfo<'_>) -> TokenStream {
     let ident = &field.binding;
     let span = field.span;
@@ -275,6 +319,9 @@ fn redacted_expr_for_field(field: &FieldInfo<'_>) -> TokenStream {
         Strategy::NotSensitive => quote_spanned! { span =>
             #ident
         },
+        Strategy::ProdOnlyPolicy(_) => {
+            unreachable!("`prod_only` is rejected while building display fields")
+        }
         Strategy::Policy(policy) => {
             let policy = policy.clone();
             if field.legacy_formatting_override {
@@ -2,12 +2,17 @@
 //!
 //! Defines the shared vocabulary of the redacted-display pipeline:
 //! [`FormatMode`] (whether a placeholder renders via `Display`, `Debug`, or
-//! both), [`Placeholder`] / [`PlaceholderKey`] (parsed template holes),
+//! both), [`NumericFormat`] / [`PlaceholderFormat`] (numeric specifiers such
+//! as `{code:x}`, allowed only on `#[not_sensitive]` fields),
+//! [`FieldFormats`] (every format a field is rendered with),
+//! [`Placeholder`] / [`PlaceholderKey`] (parsed template holes),
 //! [`FieldInfo`] (a syn field paired with its strategy and parsed options),
 //! and [`FormatArgsOutput`] (the assembled format arguments). It also builds
 //! that field model from the syn input (`build_fields_from_syn`), which is
 //! where per-field strategy and option parsing is attached to each field.
 
+use std::collections::BTreeSet;
+
 use proc_macro2::{Ident, Span, TokenStream};
 use syn::{Fields, Result, spanned::Spanned};
 
@@ -23,6 +28,67 @@ pub(super) enum FormatMode {
     Both,
 }
 
+/// Numeric formatting trait selected by a specifier such as `x` or `b`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(super) enum NumericFormat {
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+    LowerExp,
+    UpperExp,
+}
+
+impl NumericFormat {
+    pub(super) fn from_specifier(specifier: char) -> Option<Self> {
+        match specifier {
+            'x' => Some(Self::LowerHex),
+            'X' => Some(Self::UpperHex),
+            'o' => Some(Self::Octal),
+            'b' => Some(Self::Binary),
+            'e' => Some(Self::LowerExp),
+            'E' => Some(Self::UpperExp),
+            _ => None,
+        }
+    }
+
+    pub(super) fn specifier(self) -> char {
+        match self {
+            Self::LowerHex => 'x',
+            Self::UpperHex => 'X',
+            Self::Octal => 'o',
+            Self::Binary => 'b',
+            Self::LowerExp => 'e',
+            Self::UpperExp => 'E',
+        }
+    }
+
+    pub(super) fn trait_ident(self) -> Ident {
+        let name = match self {
+            Self::LowerHex => "LowerHex",
+            Self::UpperHex => "UpperHex",
+            Self::Octal => "Octal",
+            Self::Binary => "Binary",
+            Self::LowerExp => "LowerExp",
+            Self::UpperExp => "UpperExp",
+        };
+        Ident::new(name, Span::call_site())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum PlaceholderFormat {
+    Text(FormatMode),
+    Numeric(NumericFormat),
+}
+
+/// Every format a field is rendered with across one template.
+#[derive(Debug, Default)]
+pub(super) struct FieldFormats {
+    pub(super) text: Option<FormatMode>,
+    pub(super) numeric: BTreeSet<NumericFormat>,
+}
+
 #[derive(Clone, Debug)]
 pub(super) enum PlaceholderKey {
     Named(Ident),
@@ -32,7 +98,7 @@ pub(super) enum PlaceholderKey {
 #[derive(Clone, Debug)]
 pub(super) struct Placeholder {
     pub(super) key: PlaceholderKey,
-    pub(super) mode: FormatMode,
+    pub(super) format: PlaceholderFormat,
     pub(super) span: Span,
 }
 
@@ -55,6 +121,18 @@ pub(super) struct FormatArgsOutput {
     pub(super) nested_generics: Vec<syn::WherePredicate>,
 }
 
+/// Rejects `prod_only`: redacted formatting always applies the policy, since a
+/// debug-only display path would format the raw value through arbitrary sinks.
+fn reject_prod_only(strategy: &Strategy, field: &syn::Field) -> Result<()> {
+    if matches!(strategy, Strategy::ProdOnlyPolicy(_)) {
+        return Err(syn::Error::new(
+            field.span(),
+            "`prod_only` is only supported by `#[derive(Sensitive)]`; redacted formatting always applies the policy",
+        ));
+    }
+    Ok(())
+}
+
 pub(super) fn build_fields_from_syn<'a>(
     fields: &'a Fields,
     fresh: &mut FreshIdentAllocator,
@@ -65,6 +143,7 @@ pub(super) fn build_fields_from_syn<'a>(
             .iter()
             .map(|field| {
                 let strategy = parse_field_strategy(&field.attrs)?;
+                reject_prod_only(&strategy, field)?;
                 let redactable_options = parse_redactable_field_options(&field.attrs)?;
                 if redactable_options.legacy_formatting
                     && !matches!(strategy, Strategy::Policy(_))
@@ -109,6 +188,7 @@ pub(super) fn build_fields_from_syn<'a>(
             let mut output = Vec::with_capacity(fields.unnamed.len());
             for (index, field) in fields.unnamed.iter().enumerate() {
                 let strategy = parse_field_strategy(&field.attrs)?;
+                reject_prod_only(&strategy, field)?;
                 let redactable_options = parse_redactable_field_options(&field.attrs)?;
                 if redactable_options.legacy_formatting
                     && !matches!(strategy, Strategy::Policy(_))
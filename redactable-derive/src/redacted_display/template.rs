@@ -1,19 +1,26 @@
 //! Template parsing for redacted `Display` output.
 //!
-//! Resolves the format template for a container or variant — an explicit
-//! `#[error("...")]` attribute wins over doc-comment lines — and parses it
-//! into [`Placeholder`]s. Parsing validates positional and named
-//! placeholders, resolves `{field}`, `{field:?}`, and explicit `?`
-//! specifiers into [`FormatMode`]s, and rejects unsupported debug format
-//! specs and dynamic width/precision. Errors are reported against the
-//! template literal's span so diagnostics point at the user's attribute.
+//! Resolves the format template for a container or variant — a dedicated
+//! `#[display("...")]` attribute wins over `#[error("...")]`, which wins over
+//! doc-comment lines — and parses it into [`Placeholder`]s. Parsing validates
+//! positional and named placeholders, resolves `{field}`, `{field:?}`, and
+//! explicit `?` specifiers into [`FormatMode`]s and numeric specifiers such as
+//! `x` into [`NumericFormat`]s, and rejects unsupported debug format specs and
+//! dynamic width/precision. Whether a numeric specifier suits its field is
+//! decided in codegen, once the field's strategy is known. Static fill,
+//! alignment, width, and precision need no model of their own: codegen re-emits
+//! the template literal verbatim, so `{code:>6}` pads the redacted value it
+//! formats. Errors are reported against the template literal's span so
+//! diagnostics point at the user's attribute.
 
 use std::collections::BTreeSet;
 
 use proc_macro2::{Ident, Span};
 use syn::{Attribute, LitStr, Result, spanned::Spanned};
 
-use super::model::{FormatMode, Placeholder, PlaceholderKey};
+use super::model::{
+    FieldFormats, FormatMode, NumericFormat, Placeholder, PlaceholderFormat, PlaceholderKey,
+};
 
 pub(super) fn validate_positional_placeholders(placeholders: &[Placeholder]) -> Result<()> {
     let indexes: BTreeSet<usize> = placeholders
@@ -47,7 +54,22 @@ pub(super) fn validate_positional_placeholders(placeholders: &[Placeholder]) ->
     Ok(())
 }
 
-pub(super) fn merge_mode(existing: FormatMode, next: FormatMode) -> FormatMode {
+pub(super) fn record_format(formats: &mut FieldFormats, format: PlaceholderFormat) {
+    match format {
+        PlaceholderFormat::Text(mode) => {
+            formats.text = Some(
+                formats
+                    .text
+                    .map_or(mode, |existing| merge_mode(existing, mode)),
+            );
+        }
+        PlaceholderFormat::Numeric(numeric) => {
+            formats.numeric.insert(numeric);
+        }
+    }
+}
+
+fn merge_mode(existing: FormatMode, next: FormatMode) -> FormatMode {
     match (existing, next) {
         (FormatMode::Both, _) | (_, FormatMode::Both) => FormatMode::Both,
         (FormatMode::Display, FormatMode::Debug) | (FormatMode::Debug, FormatMode::Display) => {
@@ -59,6 +81,9 @@ pub(super) fn merge_mode(existing: FormatMode, next: FormatMode) -> FormatMode {
 }
 
 pub(super) fn template_from_attrs(attrs: &[Attribute], span: Span) -> Result<LitStr> {
+    if let Some(display) = display_template_from_attrs(attrs)? {
+        return Ok(display);
+    }
     if let Some(error) = error_template_from_attrs(attrs)? {
         return Ok(error);
     }
@@ -67,10 +92,37 @@ pub(super) fn template_from_attrs(attrs: &[Attribute], span: Span) -> Result<Lit
     }
     Err(syn::Error::new(
         span,
-        "missing display template: add #[error(\"...\")] or a doc comment",
+        "missing display template: add #[display(\"...\")], #[error(\"...\")], or a doc comment",
     ))
 }
 
+/// Reads `#[display("...")]`, also accepting the `#[display(fmt = "...")]`
+/// spelling.
+fn display_template_from_attrs(attrs: &[Attribute]) -> Result<Option<LitStr>> {
+    for attr in attrs {
+        if !attr.path().is_ident("display") {
+            continue;
+        }
+        let invalid = || syn::Error::new(attr.span(), "expected #[display(\"...\")]");
+        let syn::Meta::List(list) = &attr.meta else {
+            return Err(invalid());
+        };
+        if let Ok(template) = syn::parse2::<LitStr>(list.tokens.clone()) {
+            return Ok(Some(template));
+        }
+        let name_value: syn::MetaNameValue =
+            syn::parse2(list.tokens.clone()).map_err(|_| invalid())?;
+        return match &name_value.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(template),
+                ..
+            }) if name_value.path.is_ident("fmt") => Ok(Some(template.clone())),
+            _ => Err(invalid()),
+        };
+    }
+    Ok(None)
+}
+
 fn error_template_from_attrs(attrs: &[Attribute]) -> Result<Option<LitStr>> {
     for attr in attrs {
         if !attr.path().is_ident("error") {
@@ -143,7 +195,7 @@ pub(super) fn parse_placeholders(template: &LitStr) -> Result<Vec<Placeholder>>
                 let mut parts = inside.splitn(2, ':');
                 let arg_part = parts.next().unwrap_or("").trim();
                 let spec_part = parts.next().unwrap_or("");
-                let mode = format_mode_from_spec(spec_part, template.span())?;
+                let format = format_mode_from_spec(spec_part, template.span())?;
                 let key = if arg_part.is_empty() {
                     let index = implicit_index;
                     implicit_index += 1;
@@ -158,7 +210,7 @@ pub(super) fn parse_placeholders(template: &LitStr) -> Result<Vec<Placeholder>>
                 };
                 placeholders.push(Placeholder {
                     key,
-                    mode,
+                    format,
                     span: template.span(),
                 });
             }
@@ -185,10 +237,10 @@ fn parse_placeholder_ident(value: &str, span: Span) -> Result<Ident> {
         .map_err(|_| syn::Error::new(span, format!("unsupported format placeholder `{value}`")))
 }
 
-fn format_mode_from_spec(spec_part: &str, span: Span) -> Result<FormatMode> {
+fn format_mode_from_spec(spec_part: &str, span: Span) -> Result<PlaceholderFormat> {
     let spec = spec_part.trim();
     if spec.is_empty() {
-        return Ok(FormatMode::Display);
+        return Ok(PlaceholderFormat::Text(FormatMode::Display));
     }
     if has_dynamic_width_or_precision(spec) {
         return Err(syn::Error::new(
@@ -203,13 +255,16 @@ fn format_mode_from_spec(spec_part: &str, span: Span) -> Result<FormatMode> {
         ));
     }
     let last = spec.chars().last().unwrap_or_default();
+    if let Some(numeric) = NumericFormat::from_specifier(last) {
+        return Ok(PlaceholderFormat::Numeric(numeric));
+    }
     match last {
-        '?' => Ok(FormatMode::Debug),
-        'x' | 'X' | 'o' | 'b' | 'p' | 'e' | 'E' => Err(syn::Error::new(
+        '?' => Ok(PlaceholderFormat::Text(FormatMode::Debug)),
+        'p' => Err(syn::Error::new(
             span,
             format!("unsupported format specifier `{spec}`; only Display and Debug are supported"),
         )),
-        _ => Ok(FormatMode::Display),
+        _ => Ok(PlaceholderFormat::Text(FormatMode::Display)),
     }
 }
 
@@ -249,11 +304,43 @@ mod tests {
     fn format_mode_allows_star_and_dollar_fill_chars() {
         assert_eq!(
             format_mode_from_spec("*>12", Span::call_site()).unwrap(),
-            FormatMode::Display
+            PlaceholderFormat::Text(FormatMode::Display)
         );
         assert_eq!(
             format_mode_from_spec("$<12", Span::call_site()).unwrap(),
-            FormatMode::Display
+            PlaceholderFormat::Text(FormatMode::Display)
+        );
+    }
+
+    #[test]
+    fn format_mode_accepts_static_alignment_and_width() {
+        assert_eq!(
+            format_mode_from_spec(">8", Span::call_site()).unwrap(),
+            PlaceholderFormat::Text(FormatMode::Display)
+        );
+        assert_eq!(
+            format_mode_from_spec("<10", Span::call_site()).unwrap(),
+            PlaceholderFormat::Text(FormatMode::Display)
+        );
+        assert_eq!(
+            format_mode_from_spec("-^12.4?", Span::call_site()).unwrap(),
+            PlaceholderFormat::Text(FormatMode::Debug)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn parse_placeholders_keeps_keys_for_padded_placeholders() {
+        let template = LitStr::new("{0:>8} {name:<10}", Span::call_site());
+        let placeholders = parse_placeholders(&template).unwrap();
+
+        assert!(matches!(placeholders[0].key, PlaceholderKey::Index(0)));
+        assert!(matches!(&placeholders[1].key, PlaceholderKey::Named(name) if name == "name"));
+        assert!(
+            placeholders
+                .iter()
+                .all(|placeholder| placeholder.format
+                    == PlaceholderFormat::Text(FormatMode::Display))
         );
     }
 
@@ -261,6 +348,21 @@ mod tests {
     fn format_mode_rejects_dynamic_width_and_precision() {
         assert!(format_mode_from_spec("width$", Span::call_site()).is_err());
         assert!(format_mode_from_spec(".*", Span::call_site()).is_err());
+        assert!(format_mode_from_spec(">0$", Span::call_site()).is_err());
+        assert!(format_mode_from_spec("<8.prec$", Span::call_site()).is_err());
+    }
+
+    #[test]
+    fn format_mode_maps_numeric_specifiers_to_their_traits() {
+        assert_eq!(
+            format_mode_from_spec("x", Span::call_site()).unwrap(),
+            PlaceholderFormat::Numeric(NumericFormat::LowerHex)
+        );
+        assert_eq!(
+            format_mode_from_spec("#010b", Span::call_site()).unwrap(),
+            PlaceholderFormat::Numeric(NumericFormat::Binary)
+        );
+        assert!(format_mode_from_spec("p", Span::call_site()).is_err());
     }
 
     #[test]
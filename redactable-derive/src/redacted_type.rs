@@ -0,0 +1,192 @@
+//! Companion `*Redacted` type generation for `#[sensitive(gen_redacted_type)]`.
+//!
+//! The companion struct mirrors the deriving struct field for field. Policy
+//! fields change type to `Redacted<T>`, which can only be constructed by
+//! redaction; every other field keeps its declared type and is traversed as
+//! usual. A `From` impl redacts the original and moves the fields across, and
+//! a generated `Debug` impl prints `#[not_sensitive]` fields through the same
+//! probe as the original type, so fields without `Debug` print `<opaque>`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{DataStruct, Fields, Result, ext::IdentExt, parse_quote, spanned::Spanned};
+
+use crate::{
+    fresh_ident::FreshIdentAllocator,
+    generics::{add_predicates, push_debug_predicate},
+    strategy::{Strategy, parse_field_strategy},
+    transform::debug_field_value,
+};
+
+/// The generated companion type, split by where each half must be emitted.
+pub(crate) struct RedactedType {
+    /// The struct definition. It must be emitted outside the isolating const
+    /// block so callers can name it.
+    pub(crate) definition: TokenStream,
+    /// The `Debug` impl and `From` conversion, which can live with the other
+    /// generated impls.
+    pub(crate) impls: TokenStream,
+}
+
+/// Builds the companion `{Name}Redacted` struct and its `Debug` and `From<Name>`
+/// impls.
+///
+/// `policy_generics` carries the bounds of the `Redactable` impl, which the
+/// conversion needs because it calls `redact()` on the original value.
+pub(crate) fn assemble_redacted_type(
+    ident: &Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    policy_generics: &syn::Generics,
+    data: &DataStruct,
+    crate_root: &TokenStream,
+    fresh: &mut FreshIdentAllocator,
+) -> Result<RedactedType> {
+    let redacted_ident = format_ident!("{}Redacted", ident);
+    let value = fresh.fresh("__redactable_value");
+    let doc = format!(
+        "Redacted form of [`{ident}`], generated by `#[sensitive(gen_redacted_type)]`.\n\n\
+         Build one with `{redacted_ident}::from(value)`; policy fields hold `Redacted` values."
+    );
+
+    let mut declarations = Vec::new();
+    let mut patterns = Vec::new();
+    let mut reconstructions = Vec::new();
+    let mut debug_values = Vec::new();
+    let mut debug_predicates = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let span = field.span();
+        let strategy = parse_field_strategy(&field.attrs)?;
+        let wrap = match &strategy {
+            Strategy::Policy(_) => true,
+            Strategy::ProdOnlyPolicy(_) => {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`prod_only` fields cannot appear in a `#[sensitive(gen_redacted_type)]` type; \
+                     debug builds would store the unredacted value in a `Redacted` field",
+                ));
+            }
+            Strategy::WalkDefault | Strategy::NotSensitive => false,
+        };
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let field_ty: syn::Type = if wrap {
+            parse_quote! { #crate_root::Redacted<#ty> }
+        } else {
+            ty.clone()
+        };
+        push_debug_predicate(&mut debug_predicates, &field_ty);
+        let field_ty = quote_spanned! { span => #field_ty };
+        let binding = fresh.fresh(&format!("__redactable_field_{index}"));
+        let converted = if wrap {
+            quote_spanned! { span => #crate_root::__private::redacted(#binding) }
+        } else {
+            quote! { #binding }
+        };
+        debug_values.push(debug_field_value(&binding, span, &strategy));
+        if let Some(name) = &field.ident {
+            declarations.push(quote_spanned! { span => #(#docs)* #field_vis #name: #field_ty });
+            patterns.push(quote_spanned! { span => #name: #binding });
+            reconstructions.push(quote_spanned! { span => #name: #converted });
+        } else {
+            let member = syn::Index::from(index);
+            declarations.push(quote_spanned! { span => #(#docs)* #field_vis #field_ty });
+            patterns.push(quote_spanned! { span => #member: #binding });
+            reconstructions.push(quote_spanned! { span => #member: #converted });
+        }
+    }
+
+    let params = &generics.params;
+    let decl_where_clause = &generics.where_clause;
+    let definition = match &data.fields {
+        Fields::Named(_) => quote! {
+            #[doc = #doc]
+            #vis struct #redacted_ident <#params> #decl_where_clause {
+                #(#declarations),*
+            }
+        },
+        Fields::Unnamed(_) => quote! {
+            #[doc = #doc]
+            #vis struct #redacted_ident <#params> (#(#declarations),*) #decl_where_clause;
+        },
+        Fields::Unit => quote! {
+            #[doc = #doc]
+            #vis struct #redacted_ident <#params> #decl_where_clause;
+        },
+    };
+
+    let debug_impl = assemble_debug_impl(
+        &redacted_ident,
+        generics,
+        &data.fields,
+        &patterns,
+        &debug_values,
+        &debug_predicates,
+        fresh,
+    );
+
+    let (impl_generics, ty_generics, where_clause) = policy_generics.split_for_impl();
+    let impls = quote! {
+        #debug_impl
+
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for #redacted_ident #ty_generics #where_clause {
+            fn from(#value: #ident #ty_generics) -> Self {
+                let #ident { #(#patterns),* } = #crate_root::Redactable::redact(#value);
+                #redacted_ident { #(#reconstructions),* }
+            }
+        }
+    };
+
+    Ok(RedactedType { definition, impls })
+}
+
+/// Builds the companion type's `Debug` impl.
+///
+/// `values` come from [`debug_field_value`], so `#[not_sensitive]` fields
+/// without `Debug` print `<opaque>` as they do on the original type.
+fn assemble_debug_impl(
+    redacted_ident: &Ident,
+    generics: &syn::Generics,
+    fields: &Fields,
+    patterns: &[TokenStream],
+    values: &[TokenStream],
+    predicates: &[syn::WherePredicate],
+    fresh: &mut FreshIdentAllocator,
+) -> TokenStream {
+    let formatter = fresh.fresh("__redactable_formatter");
+    let debug = fresh.fresh("__redactable_debug");
+    let name = redacted_ident.to_string();
+    let body = match fields {
+        Fields::Named(_) => {
+            let labels = fields
+                .iter()
+                .filter_map(|field| field.ident.as_ref().map(|ident| ident.unraw().to_string()));
+            quote! {
+                let #redacted_ident { #(#patterns),* } = self;
+                let mut #debug = #formatter.debug_struct(#name);
+                #(#debug.field(#labels, #values);)*
+                #debug.finish()
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            let #redacted_ident { #(#patterns),* } = self;
+            let mut #debug = #formatter.debug_tuple(#name);
+            #(#debug.field(#values);)*
+            #debug.finish()
+        },
+        Fields::Unit => quote! { #formatter.write_str(#name) },
+    };
+    let generics = add_predicates(generics.clone(), predicates, redacted_ident);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::core::fmt::Debug for #redacted_ident #ty_generics #where_clause {
+            fn fmt(&self, #formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #body
+            }
+        }
+    }
+}
@@ -7,11 +7,18 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 #[cfg(feature = "slog")]
-use syn::parse_quote;
+use quote::quote_spanned;
 use syn::{Data, DeriveInput, Fields, Result, spanned::Spanned};
+#[cfg(feature = "slog")]
+use syn::{DataStruct, ext::IdentExt, parse_quote};
 
+#[cfg(feature = "serde")]
+use crate::serialize_impl::assemble_redacted_serialize_impl;
 use crate::{
-    container::{ContainerOptions, parse_container_options, reject_field_only_container_attrs},
+    container::{
+        ContainerOptions, apply_default_policy, parse_container_options,
+        reject_field_only_container_attrs,
+    },
     crate_paths::{crate_root, isolate_generated_items},
     debug_impl::derive_unredacted_debug,
     derive_enum::derive_enum,
@@ -19,8 +26,14 @@ use crate::{
     fresh_ident::FreshIdentAllocator,
     generics::add_predicates,
     redacted_display::derive_redacted_display,
+    redacted_type::assemble_redacted_type,
     strategy::parse_redactable_field_options,
 };
+#[cfg(feature = "slog")]
+use crate::{
+    generics::push_generated_policy_display_formatting_predicate,
+    strategy::{Strategy, parse_field_strategy},
+};
 
 /// Output produced by struct/enum derive logic for `Sensitive`.
 ///
@@ -32,6 +45,11 @@ pub(crate) struct DeriveOutput {
     pub(crate) debug_redacted_body: TokenStream,
     pub(crate) debug_unredacted_body: TokenStream,
     pub(crate) debug_unredacted_generics: Vec<syn::WherePredicate>,
+    /// Names of `#[sensitive(Policy)]` fields, in declaration order.
+    ///
+    /// Struct fields use the field name (or tuple index); enum fields are
+    /// prefixed with their variant, e.g. `Variant.field` or `Variant.0`.
+    pub(crate) redacted_fields: Vec<String>,
 }
 
 /// Which derive macro invoked `expand()`.
@@ -58,16 +76,17 @@ pub(crate) fn expand_with_mode(
     let DeriveInput {
         ident,
         generics,
-        data,
+        mut data,
         attrs,
-        ..
+        vis,
     } = input;
 
     reject_field_only_container_attrs(&attrs)?;
-    let ContainerOptions {
-        dual: requested_dual,
-    } = parse_container_options(&attrs)?;
-    if requested_dual && !authenticated_dual {
+    let options = parse_container_options(&attrs)?;
+    if let Some(policy) = &options.default_policy {
+        apply_default_policy(&mut data, policy);
+    }
+    if options.dual && !authenticated_dual {
         return Err(syn::Error::new(
             ident.span(),
             "`#[sensitive(dual)]` is no longer accepted on `Sensitive` or `SensitiveDisplay`; use `#[derive(SensitiveDual)]` instead",
@@ -76,6 +95,50 @@ pub(crate) fn expand_with_mode(
     if matches!(&kind, DeriveKind::Sensitive) && !authenticated_dual {
         reject_display_only_field_options(&data)?;
     }
+    if options.serde_redact && matches!(&kind, DeriveKind::SensitiveDisplay) && !authenticated_dual
+    {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`#[sensitive(serde_redact)]` requires `Sensitive` or `SensitiveDual`; `SensitiveDisplay` types have no structural redaction to serialize",
+        ));
+    }
+    if options.serde_redact && cfg!(not(feature = "serde")) {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`#[sensitive(serde_redact)]` requires the `json` feature of `redactable`",
+        ));
+    }
+    if options.opaque_variant {
+        if authenticated_dual || matches!(&kind, DeriveKind::SensitiveDisplay) {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`#[sensitive(opaque_variant)]` requires `Sensitive`; display templates name the variant they format",
+            ));
+        }
+        if !matches!(&data, Data::Enum(_)) {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`#[sensitive(opaque_variant)]` is only supported on enums",
+            ));
+        }
+    }
+    if options.gen_redacted_type {
+        if authenticated_dual || matches!(&kind, DeriveKind::SensitiveDisplay) {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`#[sensitive(gen_redacted_type)]` requires `Sensitive`; display templates format the original type",
+            ));
+        }
+        if !matches!(&data, Data::Struct(_)) {
+            return Err(syn::Error::new(
+                ident.span(),
+                "`#[sensitive(gen_redacted_type)]` is only supported on structs",
+            ));
+        }
+    }
+    if options.slog_flatten {
+        validate_slog_flatten(&ident, &kind, authenticated_dual, &data)?;
+    }
     let dual = authenticated_dual;
     let formatter = fresh.fresh("__redactable_f");
     let mapper = fresh.fresh("__redactable_mapper");
@@ -86,15 +149,44 @@ pub(crate) fn expand_with_mode(
     }
 
     // Only DeriveKind::Sensitive reaches this point (SensitiveDisplay returns early above).
-    expand_sensitive(
+    let input = SensitiveInput {
         ident,
+        vis: &vis,
         generics,
-        data,
-        &mut fresh,
-        dual,
         formatter,
-        (mapper, mapper_type),
-    )
+        mapper,
+        mapper_type,
+    };
+    expand_sensitive(input, data, &mut fresh, dual, &options)
+}
+
+/// Rejects `#[sensitive(slog_flatten)]` where there are no named fields to emit
+/// or the `slog` feature is off.
+fn validate_slog_flatten(
+    ident: &Ident,
+    kind: &DeriveKind,
+    authenticated_dual: bool,
+    data: &Data,
+) -> Result<()> {
+    if matches!(kind, DeriveKind::SensitiveDisplay) && !authenticated_dual {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`#[sensitive(slog_flatten)]` requires `Sensitive` or `SensitiveDual`; `SensitiveDisplay` logs its rendered template",
+        ));
+    }
+    if !matches!(data, Data::Struct(data) if matches!(data.fields, Fields::Named(_))) {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`#[sensitive(slog_flatten)]` is only supported on structs with named fields",
+        ));
+    }
+    if cfg!(not(feature = "slog")) {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`#[sensitive(slog_flatten)]` requires the `slog` feature of `redactable`",
+        ));
+    }
+    Ok(())
 }
 
 /// Assembles the impls emitted by `SensitiveDisplay`: `RedactableWithFormatter`,
@@ -220,112 +312,342 @@ fn expand_sensitive_display(
     Ok(isolate_generated_items(generated, fresh))
 }
 
-/// Assembles the impls emitted by `Sensitive`: `RedactableWithMapper`, `Redactable`,
-/// the merged redacted/unredacted `Debug`, and the slog/tracing integration impls.
-fn expand_sensitive(
+/// Inputs to `expand_sensitive` that name the deriving type and the idents
+/// used inside its generated impls.
+struct SensitiveInput<'a> {
     ident: Ident,
+    vis: &'a syn::Visibility,
     generics: syn::Generics,
+    /// Formatter parameter of the generated `Debug` impl.
+    formatter: Ident,
+    /// Mapper parameter of the generated `redact_with`.
+    mapper: Ident,
+    /// Mapper type parameter of the generated `redact_with`.
+    mapper_type: Ident,
+}
+
+/// Assembles the impls emitted by `Sensitive`: `RedactableWithMapper`, `Redactable`,
+/// the merged redacted/unredacted `Debug`, the slog/tracing integration impls,
+/// and the inherent `REDACTED_FIELDS` constant.
+fn expand_sensitive(
+    input: SensitiveInput<'_>,
     data: Data,
     fresh: &mut FreshIdentAllocator,
     dual: bool,
-    formatter: Ident,
-    mapper_idents: (Ident, Ident),
+    options: &ContainerOptions,
 ) -> Result<TokenStream> {
+    let SensitiveInput {
+        ident,
+        vis,
+        generics,
+        formatter,
+        mapper,
+        mapper_type,
+    } = input;
     let crate_root = crate_root();
-    let (mapper, mapper_type) = mapper_idents;
-
-    let derive_output = match data {
-        Data::Struct(data) => derive_struct(&ident, data, &generics, &formatter, &mapper, fresh)?,
-        Data::Enum(data) => derive_enum(&ident, data, &generics, &formatter, &mapper, fresh)?,
-        Data::Union(u) => {
-            return Err(syn::Error::new(
-                u.union_token.span(),
-                "`Sensitive` cannot be derived for unions",
-            ));
-        }
+    // The serializer walks the same fields as the redaction body, so keep a copy
+    // of the input before `derive_struct`/`derive_enum` consume it.
+    #[cfg(feature = "serde")]
+    let serde_data = options.serde_redact.then(|| data.clone());
+    // The generated `Serialize` redacts through this local trait, so nested
+    // `serde_redact` values can defer to their own `Serialize`.
+    let serialize_redaction = options
+        .serde_redact
+        .then(|| fresh.fresh("__RedactableSerializeRedaction"));
+    // Flattened slog output reads the named fields directly from `&self`.
+    #[cfg(feature = "slog")]
+    let slog_impl = assemble_sensitive_slog_impls(
+        fresh,
+        generics.clone(),
+        &ident,
+        &data,
+        options.slog_flatten,
+        &crate_root,
+    )?;
+    #[cfg(not(feature = "slog"))]
+    let slog_impl = quote! {};
+    let redacted_type_data = match &data {
+        Data::Struct(data) if options.gen_redacted_type => Some(data.clone()),
+        _ => None,
     };
 
-    let policy_generics = add_predicates(generics.clone(), &derive_output.used_generics, &ident);
-    let policy_generics = add_predicates(
-        policy_generics,
-        &derive_output.policy_applicable_generics,
+    let derive_output =
+        derive_sensitive_output(&ident, data, &generics, &formatter, &mapper, fresh)?;
+
+    let redacted_fields_impl =
+        assemble_redacted_fields_impl(vis, &generics, &ident, &derive_output.redacted_fields);
+    let policy_generics =
+        sensitive_policy_generics(&generics, &ident, &derive_output, options.opaque_variant);
+    let (redacted_type_definition, redacted_type_impls) = assemble_sensitive_redacted_type(
+        &ident,
+        vis,
+        &generics,
+        &policy_generics,
+        redacted_type_data,
+        &crate_root,
+        fresh,
+    )?;
+    #[cfg(feature = "serde")]
+    let serialize_impl = serde_data
+        .zip(serialize_redaction.as_ref())
+        .map(|(data, redaction)| {
+            assemble_redacted_serialize_impl(
+                &ident,
+                &data,
+                policy_generics.clone(),
+                redaction,
+                &crate_root,
+                fresh,
+            )
+        });
+    #[cfg(not(feature = "serde"))]
+    let serialize_impl = quote! {};
+    let mapper_impls = assemble_sensitive_mapper_impls(
         &ident,
+        &policy_generics,
+        &derive_output,
+        options.opaque_variant,
+        (&mapper, &mapper_type),
+        serialize_redaction.as_ref(),
+        &crate_root,
     );
-    let (impl_generics, ty_generics, where_clause) = policy_generics.split_for_impl();
-    #[cfg(feature = "slog")]
-    let slog_base_generics = generics.clone();
-    // The merged Debug impl uses the unredacted bounds (a superset of the
-    // redacted bounds) because both bodies share one impl.
-    let debug_unredacted_generics =
-        add_predicates(generics, &derive_output.debug_unredacted_generics, &ident);
-    let (
-        debug_unredacted_impl_generics,
-        debug_unredacted_ty_generics,
-        debug_unredacted_where_clause,
-    ) = debug_unredacted_generics.split_for_impl();
-    let redaction_body = &derive_output.redaction_body;
-    let debug_redacted_body = &derive_output.debug_redacted_body;
-    let debug_unredacted_body = &derive_output.debug_unredacted_body;
     // In dual mode, SensitiveDisplay provides Debug — skip it here.
-    //
-    // A single impl branches at runtime on `cfg!(test) || redactable::__TESTING`
-    // rather than emitting two `#[cfg]`-gated impls. The `feature = "testing"`
-    // check must resolve against `redactable`'s own feature, not the consumer's,
-    // so it is routed through the `__TESTING` constant. The where-clause uses the
-    // unredacted bounds (a superset of the redacted bounds) because both bodies
-    // live in the same impl.
     let debug_impl = if dual {
         quote! {}
     } else {
-        quote! {
-            impl #debug_unredacted_impl_generics ::core::fmt::Debug for #ident #debug_unredacted_ty_generics #debug_unredacted_where_clause {
-                fn fmt(&self, #formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    if ::core::cfg!(test) || #crate_root::__TESTING {
-                        #debug_unredacted_body
-                    } else {
-                        #debug_redacted_body
-                    }
-                }
-            }
-        }
+        assemble_sensitive_debug_impl(
+            &ident,
+            generics,
+            &derive_output,
+            options.opaque_variant,
+            &formatter,
+            &crate_root,
+        )
     };
 
-    #[cfg(feature = "slog")]
-    let slog_impl = assemble_sensitive_slog_impl(fresh, slog_base_generics, &ident, &crate_root);
-
-    #[cfg(not(feature = "slog"))]
-    let slog_impl = quote! {};
-
     #[cfg(feature = "tracing")]
-    let tracing_impl = assemble_sensitive_tracing_impl(
-        &impl_generics,
-        &ty_generics,
-        where_clause,
-        &ident,
-        &crate_root,
-    );
+    let tracing_impl = assemble_sensitive_tracing_impl(&policy_generics, &ident, &crate_root);
 
     #[cfg(not(feature = "tracing"))]
     let tracing_impl = quote! {};
 
     let trait_impl = quote! {
-        impl #impl_generics #crate_root::RedactableWithMapper for #ident #ty_generics #where_clause {
-            fn redact_with<#mapper_type: #crate_root::RedactableMapper>(self, #mapper: &#mapper_type) -> Self {
-                use #crate_root::RedactableWithMapper as _;
-                #redaction_body
+        #mapper_impls
+
+        #debug_impl
+
+        #slog_impl
+
+        #tracing_impl
+
+        #serialize_impl
+
+        #redacted_fields_impl
+
+        #redacted_type_impls
+    };
+    let isolated = isolate_generated_items(trait_impl, fresh);
+    // The companion type must stay nameable, so it sits outside the const block.
+    Ok(quote! {
+        #redacted_type_definition
+        #isolated
+    })
+}
+
+/// Assembles the `RedactableWithMapper` and `Redactable` impls emitted by `Sensitive`.
+///
+/// With `#[sensitive(serde_redact)]`, the redaction body moves into a local
+/// `serialize_redaction` trait that the generated `Serialize` calls directly,
+/// and `redact_with` returns the value untouched for a mapper that defers to
+/// that `Serialize`.
+fn assemble_sensitive_mapper_impls(
+    ident: &Ident,
+    policy_generics: &syn::Generics,
+    derive_output: &DeriveOutput,
+    opaque_variant: bool,
+    mapper_idents: (&Ident, &Ident),
+    serialize_redaction: Option<&Ident>,
+    crate_root: &TokenStream,
+) -> TokenStream {
+    let (mapper, mapper_type) = mapper_idents;
+    let (impl_generics, ty_generics, where_clause) = policy_generics.split_for_impl();
+    // An opaque enum redacts to its `Default` value instead of traversing the
+    // variant, so the redacted form carries no trace of the original variant.
+    let redaction_body = if opaque_variant {
+        quote! {
+            let _ = (self, #mapper);
+            <Self as ::core::default::Default>::default()
+        }
+    } else {
+        derive_output.redaction_body.clone()
+    };
+    let redact_with = if let Some(serialize_redaction) = serialize_redaction {
+        quote! {
+            trait #serialize_redaction: ::core::marker::Sized {
+                fn redact_fields<#mapper_type: #crate_root::RedactableMapper>(self, #mapper: &#mapper_type) -> Self;
+            }
+
+            impl #impl_generics #serialize_redaction for #ident #ty_generics #where_clause {
+                fn redact_fields<#mapper_type: #crate_root::RedactableMapper>(self, #mapper: &#mapper_type) -> Self {
+                    use #crate_root::RedactableWithMapper as _;
+                    #redaction_body
+                }
+            }
+
+            impl #impl_generics #crate_root::RedactableWithMapper for #ident #ty_generics #where_clause {
+                fn redact_with<#mapper_type: #crate_root::RedactableMapper>(self, #mapper: &#mapper_type) -> Self {
+                    if #crate_root::RedactableMapper::defers_serialize_redaction(#mapper) {
+                        return self;
+                    }
+                    <Self as #serialize_redaction>::redact_fields(self, #mapper)
+                }
             }
         }
+    } else {
+        quote! {
+            impl #impl_generics #crate_root::RedactableWithMapper for #ident #ty_generics #where_clause {
+                fn redact_with<#mapper_type: #crate_root::RedactableMapper>(self, #mapper: &#mapper_type) -> Self {
+                    use #crate_root::RedactableWithMapper as _;
+                    #redaction_body
+                }
+            }
+        }
+    };
+    quote! {
+        #redact_with
 
         impl #impl_generics #crate_root::Redactable for #ident #ty_generics #where_clause {}
+    }
+}
 
-        #debug_impl
+/// Assembles the `#[sensitive(gen_redacted_type)]` companion type, if requested,
+/// split into its definition and its impls.
+fn assemble_sensitive_redacted_type(
+    ident: &Ident,
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    policy_generics: &syn::Generics,
+    data: Option<syn::DataStruct>,
+    crate_root: &TokenStream,
+    fresh: &mut FreshIdentAllocator,
+) -> Result<(Option<TokenStream>, Option<TokenStream>)> {
+    let redacted_type = data
+        .map(|data| {
+            assemble_redacted_type(
+                ident,
+                vis,
+                generics,
+                policy_generics,
+                &data,
+                crate_root,
+                fresh,
+            )
+        })
+        .transpose()?;
+    Ok(redacted_type
+        .map(|redacted| (redacted.definition, redacted.impls))
+        .unzip())
+}
 
-        #slog_impl
+/// Runs the struct or enum derive logic for `Sensitive`, rejecting unions.
+fn derive_sensitive_output(
+    ident: &Ident,
+    data: Data,
+    generics: &syn::Generics,
+    formatter: &Ident,
+    mapper: &Ident,
+    fresh: &mut FreshIdentAllocator,
+) -> Result<DeriveOutput> {
+    match data {
+        Data::Struct(data) => derive_struct(ident, data, generics, formatter, mapper, fresh),
+        Data::Enum(data) => derive_enum(ident, data, generics, formatter, mapper, fresh),
+        Data::Union(u) => Err(syn::Error::new(
+            u.union_token.span(),
+            "`Sensitive` cannot be derived for unions",
+        )),
+    }
+}
 
-        #tracing_impl
+/// Builds the generics of the `RedactableWithMapper` impl: the traversal bounds,
+/// the policy bounds, and `Default` on the type under `opaque_variant`.
+fn sensitive_policy_generics(
+    generics: &syn::Generics,
+    ident: &Ident,
+    derive_output: &DeriveOutput,
+    opaque_variant: bool,
+) -> syn::Generics {
+    let policy_generics = add_predicates(generics.clone(), &derive_output.used_generics, ident);
+    let mut policy_generics = add_predicates(
+        policy_generics,
+        &derive_output.policy_applicable_generics,
+        ident,
+    );
+    if opaque_variant && !generics.params.is_empty() {
+        let (_, ty_generics, _) = generics.split_for_impl();
+        policy_generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(#ident #ty_generics: ::core::default::Default));
+    }
+    policy_generics
+}
 
+/// Assembles the merged redacted/unredacted `Debug` impl emitted by `Sensitive`.
+///
+/// A single impl branches at runtime on `cfg!(test) || redactable::__TESTING`
+/// rather than emitting two `#[cfg]`-gated impls. The `feature = "testing"`
+/// check must resolve against `redactable`'s own feature, not the consumer's,
+/// so it is routed through the `__TESTING` constant. The where-clause uses the
+/// unredacted bounds (a superset of the redacted bounds) because both bodies
+/// live in the same impl.
+fn assemble_sensitive_debug_impl(
+    ident: &Ident,
+    generics: syn::Generics,
+    derive_output: &DeriveOutput,
+    opaque_variant: bool,
+    formatter: &Ident,
+    crate_root: &TokenStream,
+) -> TokenStream {
+    let debug_unredacted_generics =
+        add_predicates(generics, &derive_output.debug_unredacted_generics, ident);
+    let (impl_generics, ty_generics, where_clause) = debug_unredacted_generics.split_for_impl();
+    let debug_redacted_body = if opaque_variant {
+        let opaque_debug = format!("{ident}(<redacted>)");
+        quote! { #formatter.write_str(#opaque_debug) }
+    } else {
+        derive_output.debug_redacted_body.clone()
     };
-    Ok(isolate_generated_items(trait_impl, fresh))
+    let debug_unredacted_body = &derive_output.debug_unredacted_body;
+    quote! {
+        impl #impl_generics ::core::fmt::Debug for #ident #ty_generics #where_clause {
+            fn fmt(&self, #formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                if ::core::cfg!(test) || #crate_root::__TESTING {
+                    #debug_unredacted_body
+                } else {
+                    #debug_redacted_body
+                }
+            }
+        }
+    }
+}
+
+/// Assembles the inherent `REDACTED_FIELDS` constant emitted by `Sensitive`.
+///
+/// The constant uses the type's declared generics without the traversal bounds,
+/// so it is available even where the type does not implement `Redactable`.
+fn assemble_redacted_fields_impl(
+    vis: &syn::Visibility,
+    generics: &syn::Generics,
+    ident: &Ident,
+    redacted_fields: &[String],
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Names of the fields redacted by a `#[sensitive(...)]` policy, in declaration order.
+            #vis const REDACTED_FIELDS: &'static [&'static str] = &[#(#redacted_fields),*];
+        }
+    }
 }
 
 /// Rejects formatting-only field options when no display derive consumes them.
@@ -370,6 +692,37 @@ fn reject_display_only_field_options(data: &Data) -> Result<()> {
     }
 }
 
+/// Assembles the slog impls emitted by `Sensitive`, emitting each named field
+/// as its own key under `#[sensitive(slog_flatten)]`.
+#[cfg(feature = "slog")]
+fn assemble_sensitive_slog_impls(
+    fresh: &mut FreshIdentAllocator,
+    slog_base_generics: syn::Generics,
+    ident: &Ident,
+    data: &Data,
+    slog_flatten: bool,
+    crate_root: &TokenStream,
+) -> Result<TokenStream> {
+    match data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) if slog_flatten => assemble_sensitive_slog_flatten_impl(
+            fresh,
+            slog_base_generics,
+            ident,
+            fields,
+            crate_root,
+        ),
+        _ => Ok(assemble_sensitive_slog_impl(
+            fresh,
+            slog_base_generics,
+            ident,
+            crate_root,
+        )),
+    }
+}
+
 /// Assembles the `slog::Value` and `SlogRedacted` impls emitted by `SensitiveDisplay`.
 #[cfg(feature = "slog")]
 fn assemble_display_slog_impl(
@@ -465,15 +818,90 @@ fn assemble_sensitive_slog_impl(
     }
 }
 
+/// Assembles the `slog::Value` impl emitted for `#[sensitive(slog_flatten)]`.
+///
+/// Each named field is emitted under its own key, read from `&self` without
+/// cloning: policy fields through the generated policy formatter and every
+/// other field through its own `slog::Value` impl. The caller's key is unused.
+#[cfg(feature = "slog")]
+fn assemble_sensitive_slog_flatten_impl(
+    fresh: &mut FreshIdentAllocator,
+    slog_base_generics: syn::Generics,
+    ident: &Ident,
+    fields: &syn::FieldsNamed,
+    crate_root: &TokenStream,
+) -> Result<TokenStream> {
+    let record = fresh.fresh("__redactable_record");
+    let key = fresh.fresh("key");
+    let serializer = fresh.fresh("serializer");
+    let slog_crate = quote! { #crate_root::__private::slog };
+    let mut predicates = Vec::new();
+    let mut emits = Vec::new();
+    for field in &fields.named {
+        let span = field.span();
+        let field_ident = field
+            .ident
+            .as_ref()
+            .expect("named field should have an identifier");
+        let name = field_ident.unraw().to_string();
+        let ty = &field.ty;
+        // Call-site span: `Key` is `&'static str` unless slog's `dynamic-keys` is on.
+        let field_key = quote! {
+            <#slog_crate::Key as ::core::convert::From<&'static str>>::from(#name)
+        };
+        match parse_field_strategy(&field.attrs)? {
+            Strategy::Policy(policy) | Strategy::ProdOnlyPolicy(policy) => {
+                push_generated_policy_display_formatting_predicate(&mut predicates, ty, &policy);
+                emits.push(quote_spanned! { span =>
+                    {
+                        use #crate_root::__private::PolicyFormattingDispatch as _;
+                        #serializer.emit_arguments(
+                            #field_key,
+                            &::core::format_args!(
+                                "{}",
+                                #crate_root::__private::policy_formatting_probe(&self.#field_ident)
+                                    .redactable_policy_formatting::<#policy>()
+                            ),
+                        )?;
+                    }
+                });
+            }
+            Strategy::WalkDefault | Strategy::NotSensitive => {
+                predicates.push(parse_quote!(#ty: #slog_crate::Value));
+                emits.push(quote_spanned! { span =>
+                    #slog_crate::Value::serialize(&self.#field_ident, #record, #field_key, #serializer)?;
+                });
+            }
+        }
+    }
+    let slog_generics = add_predicates(slog_base_generics, &predicates, ident);
+    let (slog_impl_generics, slog_ty_generics, slog_where_clause) = slog_generics.split_for_impl();
+    Ok(quote! {
+        impl #slog_impl_generics #slog_crate::Value for #ident #slog_ty_generics #slog_where_clause {
+            fn serialize(
+                &self,
+                #record: &#slog_crate::Record<'_>,
+                #key: #slog_crate::Key,
+                #serializer: &mut dyn #slog_crate::Serializer,
+            ) -> #slog_crate::Result {
+                let _ = #key;
+                #(#emits)*
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl #slog_impl_generics #crate_root::slog::SlogRedacted for #ident #slog_ty_generics #slog_where_clause {}
+    })
+}
+
 /// Assembles the `TracingRedacted` marker impl emitted by `Sensitive`.
 #[cfg(feature = "tracing")]
 fn assemble_sensitive_tracing_impl(
-    impl_generics: &syn::ImplGenerics<'_>,
-    ty_generics: &syn::TypeGenerics<'_>,
-    where_clause: Option<&syn::WhereClause>,
+    generics: &syn::Generics,
     ident: &Ident,
     crate_root: &TokenStream,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
         impl #impl_generics #crate_root::tracing::TracingRedacted for #ident #ty_generics #where_clause {}
     }
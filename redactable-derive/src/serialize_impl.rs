@@ -0,0 +1,223 @@
+//! Redacting `serde::Serialize` generation for `#[sensitive(serde_redact)]`.
+//!
+//! The generated impl clones the value, redacts the clone, and serializes its
+//! fields one by one. Serializing field-by-field (rather than delegating to a
+//! derived `Serialize`) is what allows the type's only `Serialize` impl to be
+//! the redacting one. `#[serde(...)]` attributes are not interpreted.
+//!
+//! The clone is redacted with `SerializeMapper`, which leaves every nested
+//! `serde_redact` value untouched. Each of those redacts itself when its own
+//! `Serialize` runs, so every policy is applied exactly once.
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use syn::{Data, Fields, Generics, WherePredicate, ext::IdentExt, parse_quote};
+
+use crate::{fresh_ident::FreshIdentAllocator, generics::add_predicates};
+
+/// Assembles the redacting `Serialize` impl emitted by `Sensitive`.
+///
+/// `generics` must already carry the `Redactable` bounds; this adds `Self: Clone`
+/// and a `Serialize` bound for every field type. `serialize_redaction` names the
+/// local trait holding the type's redaction body.
+pub(crate) fn assemble_redacted_serialize_impl(
+    ident: &Ident,
+    data: &Data,
+    generics: Generics,
+    serialize_redaction: &Ident,
+    crate_root: &TokenStream,
+    fresh: &mut FreshIdentAllocator,
+) -> TokenStream {
+    let serde = quote! { #crate_root::__private::serde };
+    let serializer = fresh.fresh("__redactable_serializer");
+    let serializer_type = fresh.fresh("__RedactableSerializer");
+    let redacted = fresh.fresh("__redactable_redacted");
+    let state = fresh.fresh("__redactable_state");
+
+    let mut predicates: Vec<WherePredicate> = Vec::new();
+    let body = match data {
+        Data::Struct(data) => {
+            collect_field_predicates(&mut predicates, &data.fields, &serde);
+            let (pattern, bindings) = bind_fields(&data.fields, fresh);
+            let serialize =
+                serialize_struct(ident, &data.fields, &bindings, &serializer, &state, &serde);
+            quote! {
+                let #ident #pattern = &#redacted;
+                #serialize
+            }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::new();
+            for (index, variant) in data.variants.iter().enumerate() {
+                collect_field_predicates(&mut predicates, &variant.fields, &serde);
+                let variant_ident = &variant.ident;
+                let (pattern, bindings) = bind_fields(&variant.fields, fresh);
+                let serialize = serialize_variant(
+                    ident,
+                    variant_ident,
+                    u32::try_from(index).expect("enum variant count fits in u32"),
+                    &variant.fields,
+                    &bindings,
+                    &serializer,
+                    &state,
+                    &serde,
+                );
+                arms.push(quote! { #ident::#variant_ident #pattern => { #serialize } });
+            }
+            if arms.is_empty() {
+                quote! { match #redacted {} }
+            } else {
+                quote! {
+                    match &#redacted {
+                        #(#arms)*
+                    }
+                }
+            }
+        }
+        Data::Union(_) => unreachable!("unions are rejected before serialize generation"),
+    };
+
+    let mut generics = add_predicates(generics, &predicates, ident);
+    if !generics.params.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(Self: ::core::clone::Clone));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics #serde::Serialize for #ident #ty_generics #where_clause {
+            fn serialize<#serializer_type: #serde::Serializer>(
+                &self,
+                #serializer: #serializer_type,
+            ) -> ::core::result::Result<#serializer_type::Ok, #serializer_type::Error> {
+                let #redacted = <Self as #serialize_redaction>::redact_fields(
+                    ::core::clone::Clone::clone(self),
+                    &#crate_root::__private::SerializeMapper,
+                );
+                #body
+            }
+        }
+    }
+}
+
+fn collect_field_predicates(
+    predicates: &mut Vec<WherePredicate>,
+    fields: &Fields,
+    serde: &TokenStream,
+) {
+    for field in fields {
+        let ty = &field.ty;
+        predicates.push(parse_quote!(#ty: #serde::Serialize));
+    }
+}
+
+/// Builds a by-reference destructuring pattern and returns the bound names.
+fn bind_fields(fields: &Fields, fresh: &mut FreshIdentAllocator) -> (TokenStream, Vec<Ident>) {
+    match fields {
+        Fields::Named(named) => {
+            let mut patterns = Vec::new();
+            let mut bindings = Vec::new();
+            for field in &named.named {
+                let ident = field.ident.as_ref().expect("named field has an identifier");
+                let binding = fresh.fresh_with_ident("__redactable_field_", ident);
+                patterns.push(quote! { #ident: #binding });
+                bindings.push(binding);
+            }
+            (quote! { { #(#patterns),* } }, bindings)
+        }
+        Fields::Unnamed(unnamed) => {
+            let bindings: Vec<Ident> = (0..unnamed.unnamed.len())
+                .map(|index| fresh.fresh(&format!("__redactable_field_{index}")))
+                .collect();
+            (quote! { ( #(#bindings),* ) }, bindings)
+        }
+        Fields::Unit => (TokenStream::new(), Vec::new()),
+    }
+}
+
+fn field_names(fields: &Fields) -> Vec<String> {
+    fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(|ident| ident.unraw().to_string()))
+        .collect()
+}
+
+fn serialize_struct(
+    ident: &Ident,
+    fields: &Fields,
+    bindings: &[Ident],
+    serializer: &Ident,
+    state: &Ident,
+    serde: &TokenStream,
+) -> TokenStream {
+    let name = ident.unraw().to_string();
+    let len = bindings.len();
+    match fields {
+        Fields::Named(_) => {
+            let names = field_names(fields);
+            quote! {
+                let mut #state = #serde::Serializer::serialize_struct(#serializer, #name, #len)?;
+                #(#serde::ser::SerializeStruct::serialize_field(&mut #state, #names, #bindings)?;)*
+                #serde::ser::SerializeStruct::end(#state)
+            }
+        }
+        Fields::Unnamed(_) if len == 1 => {
+            let binding = &bindings[0];
+            quote! { #serde::Serializer::serialize_newtype_struct(#serializer, #name, #binding) }
+        }
+        Fields::Unnamed(_) => quote! {
+            let mut #state = #serde::Serializer::serialize_tuple_struct(#serializer, #name, #len)?;
+            #(#serde::ser::SerializeTupleStruct::serialize_field(&mut #state, #bindings)?;)*
+            #serde::ser::SerializeTupleStruct::end(#state)
+        },
+        Fields::Unit => quote! { #serde::Serializer::serialize_unit_struct(#serializer, #name) },
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn serialize_variant(
+    ident: &Ident,
+    variant_ident: &Ident,
+    index: u32,
+    fields: &Fields,
+    bindings: &[Ident],
+    serializer: &Ident,
+    state: &Ident,
+    serde: &TokenStream,
+) -> TokenStream {
+    let name = ident.unraw().to_string();
+    let variant = variant_ident.unraw().to_string();
+    let index = Literal::u32_suffixed(index);
+    let len = bindings.len();
+    match fields {
+        Fields::Named(_) => {
+            let names = field_names(fields);
+            quote! {
+                let mut #state = #serde::Serializer::serialize_struct_variant(
+                    #serializer, #name, #index, #variant, #len,
+                )?;
+                #(#serde::ser::SerializeStructVariant::serialize_field(&mut #state, #names, #bindings)?;)*
+                #serde::ser::SerializeStructVariant::end(#state)
+            }
+        }
+        Fields::Unnamed(_) if len == 1 => {
+            let binding = &bindings[0];
+            quote! {
+                #serde::Serializer::serialize_newtype_variant(
+                    #serializer, #name, #index, #variant, #binding,
+                )
+            }
+        }
+        Fields::Unnamed(_) => quote! {
+            let mut #state = #serde::Serializer::serialize_tuple_variant(
+                #serializer, #name, #index, #variant, #len,
+            )?;
+            #(#serde::ser::SerializeTupleVariant::serialize_field(&mut #state, #bindings)?;)*
+            #serde::ser::SerializeTupleVariant::end(#state)
+        },
+        Fields::Unit => quote! {
+            #serde::Serializer::serialize_unit_variant(#serializer, #name, #index, #variant)
+        },
+    }
+}
@@ -80,6 +80,7 @@ pub(crate) fn parse_redactable_field_options(
 /// |------------------------|-----------------------|---------------------------------------|
 /// | None                   | `WalkDefault`         | Walk containers; scalars pass through |
 /// | `#[sensitive(Policy)]` | `Policy(policy_path)` | Apply redaction policy                |
+/// | `#[sensitive(Policy, prod_only)]` | `ProdOnlyPolicy(policy_path)` | Apply policy without `debug_assertions` |
 /// | `#[not_sensitive]`     | `NotSensitive`        | Explicit passthrough (no traversal)      |
 #[derive(Clone, Debug)]
 pub(crate) enum Strategy {
@@ -90,6 +91,9 @@ pub(crate) enum Strategy {
     /// The policy type (e.g., `Secret`, `Token`, `Pii`) determines how
     /// the value is redacted via `RedactionPolicy`.
     Policy(syn::Path),
+    /// `#[sensitive(Policy, prod_only)]`: apply the policy only in builds without
+    /// `debug_assertions`; debug builds treat the field as unannotated.
+    ProdOnlyPolicy(syn::Path),
     /// `#[not_sensitive]`: explicit passthrough, no traversal or transformation.
     NotSensitive,
 }
@@ -168,18 +172,25 @@ pub(crate) fn parse_field_strategy(attrs: &[Attribute]) -> Result<Strategy> {
                 ));
             }
             Meta::List(list) => {
-                // Parse as a policy path (e.g., #[sensitive(Secret)])
-                match syn::parse2::<syn::Path>(list.tokens.clone()) {
-                    Ok(path) => {
-                        set_strategy(&mut strategy, Strategy::Policy(path), attr.span())?;
-                    }
-                    Err(_) => {
+                // Parse as a policy path (e.g., #[sensitive(Secret)]), optionally
+                // followed by `prod_only`.
+                let Ok((path, option)) = list.parse_args_with(parse_policy_and_option) else {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "expected a policy type (e.g., #[sensitive(Secret)])",
+                    ));
+                };
+                let next = match option {
+                    None => Strategy::Policy(path),
+                    Some(option) if option == "prod_only" => Strategy::ProdOnlyPolicy(path),
+                    Some(option) => {
                         return Err(syn::Error::new(
-                            attr.span(),
-                            "expected a policy type (e.g., #[sensitive(Secret)])",
+                            option.span(),
+                            format!("unknown sensitive option `{option}`; expected `prod_only`"),
                         ));
                     }
-                }
+                };
+                set_strategy(&mut strategy, next, attr.span())?;
             }
             Meta::NameValue(_) => {
                 return Err(syn::Error::new(
@@ -195,6 +206,22 @@ pub(crate) fn parse_field_strategy(attrs: &[Attribute]) -> Result<Strategy> {
     Ok(strategy.unwrap_or(Strategy::WalkDefault))
 }
 
+/// Parses `Policy` or `Policy, option` inside `#[sensitive(...)]`.
+fn parse_policy_and_option(
+    input: syn::parse::ParseStream<'_>,
+) -> Result<(syn::Path, Option<syn::Ident>)> {
+    let path = input.parse()?;
+    if input.is_empty() {
+        return Ok((path, None));
+    }
+    input.parse::<syn::Token![,]>()?;
+    let option = input.parse()?;
+    if input.peek(syn::Token![,]) {
+        input.parse::<syn::Token![,]>()?;
+    }
+    Ok((path, Some(option)))
+}
+
 #[cfg(test)]
 mod tests {
     use quote::quote;
@@ -250,6 +277,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn sensitive_with_prod_only_returns_prod_only_policy() {
+        let attrs = parse_attrs(quote! { #[sensitive(Secret, prod_only)] });
+        let strategy = parse_field_strategy(&attrs).unwrap();
+        match strategy {
+            Strategy::ProdOnlyPolicy(path) => {
+                assert!(path.is_ident("Secret"));
+            }
+            _ => panic!("expected ProdOnlyPolicy"),
+        }
+    }
+
+    #[test]
+    fn unknown_sensitive_option_error() {
+        let attrs = parse_attrs(quote! { #[sensitive(Secret, dev_only)] });
+        let result = parse_field_strategy(&attrs);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("unknown sensitive option `dev_only`")
+        );
+    }
+
     #[test]
     fn multiple_sensitive_attributes_error() {
         let attrs = parse_attrs(quote! {
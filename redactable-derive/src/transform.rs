@@ -4,12 +4,14 @@
 //! which was previously duplicated between `derive_struct` and `derive_enum`.
 
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote_spanned;
+use quote::{ToTokens, quote_spanned};
+use syn::spanned::Spanned;
 
 use crate::{
     crate_root,
+    fresh_ident::FreshIdentAllocator,
     generics::{push_container_predicate, push_debug_predicate, push_policy_predicate},
-    strategy::Strategy,
+    strategy::{Strategy, parse_field_strategy},
 };
 
 /// Accumulated state during field processing.
@@ -22,6 +24,124 @@ pub(crate) struct DeriveContext<'a> {
     pub(crate) policy_predicates: &'a mut Vec<syn::WherePredicate>,
     pub(crate) debug_unredacted_predicates: &'a mut Vec<syn::WherePredicate>,
     pub(crate) mapper: &'a Ident,
+    pub(crate) shared_policy_fields: Option<&'a SharedPolicyFields>,
+}
+
+/// Policy applications shared by repeated `(type, policy)` field shapes.
+///
+/// Enums often repeat one sensitive field shape, such as
+/// `#[sensitive(Secret)] token: String`, across many variants. Each repeated
+/// shape is applied through one closure bound ahead of the `match`, so the
+/// arms call it instead of each spelling out the fully qualified
+/// `PolicyField` call. Shapes that occur once are emitted inline as before.
+pub(crate) struct SharedPolicyFields {
+    helpers: Vec<(String, Ident)>,
+    definitions: TokenStream,
+}
+
+impl SharedPolicyFields {
+    /// Collects the policy field shapes that occur more than once.
+    ///
+    /// Attribute errors are skipped here and reported by the main field pass.
+    pub(crate) fn collect<'f>(
+        fields: impl IntoIterator<Item = &'f syn::Field>,
+        mapper: &Ident,
+        fresh: &mut FreshIdentAllocator,
+    ) -> Self {
+        let mut shapes: Vec<(String, &syn::Type, syn::Path, Span, usize)> = Vec::new();
+        for field in fields {
+            let Ok(Strategy::Policy(policy)) = parse_field_strategy(&field.attrs) else {
+                continue;
+            };
+            let key = shape_key(&field.ty, &policy);
+            if let Some(shape) = shapes.iter_mut().find(|shape| shape.0 == key) {
+                shape.4 += 1;
+            } else {
+                shapes.push((key, &field.ty, policy, field.ty.span(), 1));
+            }
+        }
+
+        let crate_root = crate_root();
+        let mut helpers = Vec::new();
+        let mut definitions = TokenStream::new();
+        for (key, ty, policy, span, count) in shapes {
+            if count < 2 {
+                continue;
+            }
+            let helper = fresh.fresh("__redactable_apply");
+            let value = fresh.fresh("__redactable_value");
+            definitions.extend(quote_spanned! { span =>
+                let #helper = |#value: #ty| {
+                    use #crate_root::__private::PolicyFieldDispatch as _;
+                    (&&#crate_root::__private::PolicyFieldProbe::<#policy, #ty>::new())
+                        .redactable_policy_route()
+                        .apply(#value, #mapper)
+                };
+            });
+            helpers.push((key, helper));
+        }
+        Self {
+            helpers,
+            definitions,
+        }
+    }
+
+    /// Closure bindings to emit before the `match` that uses them.
+    pub(crate) fn definitions(&self) -> &TokenStream {
+        &self.definitions
+    }
+
+    fn helper(&self, ty: &syn::Type, policy: &syn::Path) -> Option<&Ident> {
+        let key = shape_key(ty, policy);
+        self.helpers
+            .iter()
+            .find(|(existing, _)| *existing == key)
+            .map(|(_, helper)| helper)
+    }
+}
+
+/// Rejects a policy on a borrowed field type, which cannot hold redacted output.
+///
+/// Redaction rebuilds the value with owned policy output, so a `&str` field has
+/// nowhere to store it. Without this check the derive still expands and the
+/// failure only surfaces at `.redact()` as an unsatisfied `PolicyField` bound.
+fn reject_borrowed_policy_field(ty: &syn::Type, policy: &syn::Path) -> syn::Result<()> {
+    let syn::Type::Reference(reference) = ty else {
+        return Ok(());
+    };
+    let lifetime = reference
+        .lifetime
+        .as_ref()
+        .map_or_else(|| "'_".to_owned(), ToString::to_string);
+    let mutability = if reference.mutability.is_some() {
+        "mut "
+    } else {
+        ""
+    };
+    let referent = reference.elem.to_token_stream().to_string();
+    let owned = match &*reference.elem {
+        syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident("str") => {
+            format!("`String` or `Cow<{lifetime}, str>`")
+        }
+        syn::Type::Slice(slice) if matches!(&*slice.elem, syn::Type::Path(path) if path.path.is_ident("u8")) =>
+        {
+            format!("`Vec<u8>` or `Cow<{lifetime}, [u8]>`")
+        }
+        _ => format!("`{referent}`"),
+    };
+    Err(syn::Error::new_spanned(
+        ty,
+        format!(
+            "`#[sensitive({policy})]` cannot redact the borrowed field type \
+             `&{lifetime} {mutability}{referent}`; use an owned type such as {owned}, \
+             or `#[not_sensitive]` if the value is safe to keep",
+            policy = policy.to_token_stream().to_string().replace(' ', ""),
+        ),
+    ))
+}
+
+fn shape_key(ty: &syn::Type, policy: &syn::Path) -> String {
+    format!("{} as {}", ty.to_token_stream(), policy.to_token_stream())
 }
 
 /// Generates the transform token stream for a single field.
@@ -33,6 +153,7 @@ pub(crate) struct DeriveContext<'a> {
 /// | None                    | Walk containers, scalars pass through                |
 /// | `#[sensitive(Secret)]`  | Scalars redact to default; strings to "[REDACTED]"   |
 /// | `#[sensitive(Policy)]`  | Apply policy recursively through wrappers            |
+/// | `#[sensitive(Policy, prod_only)]` | Policy without `debug_assertions`, else walk |
 /// | `#[not_sensitive]`      | Explicit passthrough (no transformation)             |
 pub(crate) fn generate_field_transform(
     ctx: &mut DeriveContext<'_>,
@@ -41,7 +162,7 @@ pub(crate) fn generate_field_transform(
     span: Span,
     strategy: &Strategy,
     recursive_bound_override: bool,
-) -> TokenStream {
+) -> syn::Result<TokenStream> {
     let container_path = ctx.container_path;
     let mapper = ctx.mapper;
 
@@ -51,9 +172,14 @@ pub(crate) fn generate_field_transform(
                 push_container_predicate(ctx.container_predicates, ty);
                 push_debug_predicate(ctx.debug_unredacted_predicates, ty);
             }
-            quote_spanned! { span =>
+            // Primitive scalars are deliberately not moved directly: their
+            // traversal is the `map_unannotated_scalar` call `RedactEverything`
+            // relies on, it inlines to a move for every other mapper, and a
+            // name-based check would misfire on local types named like a
+            // primitive.
+            Ok(quote_spanned! { span =>
                 let #binding = #container_path::redact_with(#binding, #mapper);
-            }
+            })
         }
         Strategy::NotSensitive => {
             // Explicit opt-out: no transformation, passthrough unchanged.
@@ -63,21 +189,231 @@ pub(crate) fn generate_field_transform(
             if !recursive_bound_override {
                 push_debug_predicate(ctx.debug_unredacted_predicates, ty);
             }
-            TokenStream::new()
+            Ok(TokenStream::new())
         }
         Strategy::Policy(policy_path) => {
+            reject_borrowed_policy_field(ty, policy_path)?;
             if !recursive_bound_override {
                 push_policy_predicate(ctx.policy_predicates, ty, policy_path);
                 push_debug_predicate(ctx.debug_unredacted_predicates, ty);
             }
+            if let Some(helper) = ctx
+                .shared_policy_fields
+                .and_then(|shared| shared.helper(ty, policy_path))
+            {
+                return Ok(quote_spanned! { span =>
+                    let #binding = #helper(#binding);
+                });
+            }
             let policy = policy_path.clone();
             let crate_root = crate_root();
-            quote_spanned! { span =>
-                let #binding = <#ty as #crate_root::__private::PolicyField<#policy>>::apply_field(
-                    #binding,
-                    #mapper,
-                );
-            }
+            // Spanned on the type so an unsupported shape is reported there.
+            let apply = quote_spanned! { ty.span() =>
+                (&&#crate_root::__private::PolicyFieldProbe::<#policy, #ty>::new())
+                    .redactable_policy_route()
+                    .apply(#binding, #mapper)
+            };
+            Ok(quote_spanned! { span =>
+                let #binding = {
+                    use #crate_root::__private::PolicyFieldDispatch as _;
+                    #apply
+                };
+            })
         }
+        Strategy::ProdOnlyPolicy(policy_path) => {
+            let walk = generate_field_transform(
+                ctx,
+                ty,
+                binding,
+                span,
+                &Strategy::WalkDefault,
+                recursive_bound_override,
+            )?;
+            let policy = generate_field_transform(
+                ctx,
+                ty,
+                binding,
+                span,
+                &Strategy::Policy(policy_path.clone()),
+                recursive_bound_override,
+            )?;
+            // `cfg!` expands in the deriving crate, so its profile decides. Debug
+            // builds fall back to the unannotated route, which still lets
+            // `RedactEverything` redact the field.
+            Ok(quote_spanned! { span =>
+                let #binding = if ::core::cfg!(debug_assertions) {
+                    #walk
+                    #binding
+                } else {
+                    #policy
+                    #binding
+                };
+            })
+        }
+    }
+}
+
+/// Returns the value a generated `Debug` impl passes to its builder for a
+/// non-sensitive field.
+///
+/// `#[not_sensitive]` fields go through a probe so a type without `Debug`
+/// (`fn` wrappers, `Box<dyn Fn()>`) prints `<opaque>` instead of failing to
+/// compile; every other field formats with its own `Debug`.
+pub(crate) fn debug_field_value(binding: &Ident, span: Span, strategy: &Strategy) -> TokenStream {
+    if !matches!(strategy, Strategy::NotSensitive) {
+        return quote_spanned! { span => #binding };
+    }
+    let crate_root = crate_root();
+    quote_spanned! { span =>
+        {
+            #[allow(unused_imports)]
+            use #crate_root::__private::{
+                NotSensitiveDebugDispatch as _, NotSensitiveDebugFallback as _,
+            };
+            (&#crate_root::__private::NotSensitiveDebugProbe(#binding))
+                .redactable_not_sensitive_debug()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::DeriveInput;
+
+    use crate::sensitive::{DeriveKind, expand};
+
+    const WALK_CALL: &str = "RedactableWithMapper :: redact_with (";
+
+    fn expand_sensitive(tokens: proc_macro2::TokenStream) -> String {
+        let input: DeriveInput = syn::parse2(tokens).expect("should parse as DeriveInput");
+        expand(input, DeriveKind::Sensitive)
+            .expect("derive should expand")
+            .to_string()
+    }
+
+    #[test]
+    fn primitive_fields_walk_like_other_unannotated_fields() {
+        let expanded = expand_sensitive(quote! {
+            struct Mixed {
+                hits: u64,
+                label: String,
+                values: Vec<u32>,
+            }
+        });
+
+        // Primitives reach `RedactEverything` through their own traversal, so
+        // they are not special-cased.
+        assert_eq!(expanded.matches(WALK_CALL).count(), 3, "{expanded}");
+        assert!(!expanded.contains(":: core :: primitive"), "{expanded}");
+    }
+
+    #[test]
+    fn borrowed_policy_fields_suggest_the_owned_referent() {
+        let input: DeriveInput = syn::parse2(quote! {
+            struct Attempt<'a> {
+                #[sensitive(Secret)]
+                count: &'a u32,
+            }
+        })
+        .expect("should parse as DeriveInput");
+        let error = expand(input, DeriveKind::Sensitive)
+            .expect_err("borrowed policy fields are rejected")
+            .to_string();
+
+        assert!(error.contains("use an owned type such as `u32`"), "{error}");
+    }
+
+    #[test]
+    fn prod_only_policy_branches_on_debug_assertions() {
+        let expanded = expand_sensitive(quote! {
+            struct Session {
+                #[sensitive(Secret, prod_only)]
+                user: String,
+            }
+        });
+
+        assert!(
+            expanded.contains(":: core :: cfg ! (debug_assertions)"),
+            "{expanded}"
+        );
+        assert_eq!(expanded.matches(WALK_CALL).count(), 1, "{expanded}");
+        assert!(
+            expanded.contains("PolicyFieldProbe :: < Secret , String >"),
+            "{expanded}"
+        );
+    }
+
+    #[test]
+    fn wide_structs_destructure_and_rebuild_once() {
+        let fields = (0..60_usize).map(|index| {
+            let plain = quote::format_ident!("plain_{}", index);
+            if index % 3 == 0 {
+                quote! { #[sensitive(Secret)] #plain: String }
+            } else {
+                quote! { #plain: String }
+            }
+        });
+        let expanded = expand_sensitive(quote! {
+            struct Wide { #(#fields),* }
+        });
+
+        assert_eq!(expanded.matches("let Self {").count(), 1, "{expanded}");
+        assert_eq!(expanded.matches(WALK_CALL).count(), 40, "{expanded}");
+        assert_eq!(
+            expanded
+                .matches("PolicyFieldProbe :: < Secret , String >")
+                .count(),
+            20,
+            "{expanded}"
+        );
+    }
+
+    #[test]
+    fn repeated_enum_policy_shapes_share_one_application() {
+        let variants = (0..20_usize).map(|index| {
+            let variant = quote::format_ident!("Variant{}", index);
+            quote! { #variant { #[sensitive(Secret)] token: String, id: u32 } }
+        });
+        let expanded = expand_sensitive(quote! {
+            enum Event {
+                #(#variants,)*
+                Refreshed(#[sensitive(Secret)] String, #[sensitive(Token)] String),
+            }
+        });
+
+        // The 21 `Secret` fields share one closure; the lone `Token` field is
+        // applied inline.
+        assert_eq!(
+            expanded
+                .matches("PolicyFieldProbe :: < Secret , String >")
+                .count(),
+            1,
+            "{expanded}"
+        );
+        assert_eq!(
+            expanded
+                .matches("PolicyFieldProbe :: < Token , String >")
+                .count(),
+            1,
+            "{expanded}"
+        );
+    }
+
+    #[test]
+    fn policy_on_borrowed_field_is_rejected_with_owned_alternative() {
+        let input: DeriveInput = syn::parse2(quote! {
+            struct Request<'a> {
+                #[sensitive(Secret)]
+                token: &'a str,
+            }
+        })
+        .expect("should parse as DeriveInput");
+        let error = expand(input, DeriveKind::Sensitive)
+            .expect_err("borrowed policy field should be rejected")
+            .to_string();
+
+        assert!(error.contains("borrowed field type `&'a str`"), "{error}");
+        assert!(error.contains("`Cow<'a, str>`"), "{error}");
     }
 }
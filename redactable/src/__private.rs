@@ -15,6 +15,9 @@ pub use crate::redaction::{IpPolicyApplicable, IpPolicyApplicableRef};
 /// Fail-closed JSON serialization used by redacted logging adapters.
 #[cfg(feature = "json")]
 pub use crate::redaction::serialize_redacted_json;
+/// Logging facade used by the `redacted_log!` macro.
+#[cfg(feature = "log")]
+pub use log;
 /// Serialization support used by derive-generated slog implementations.
 #[cfg(feature = "json")]
 pub use serde;
@@ -26,15 +29,26 @@ pub use serde_json;
 pub use slog;
 
 /// Default mapper used by generated private field operations.
-pub use crate::redaction::{PolicyFormattingMapper, PolicyMapper};
+pub use crate::redaction::{PolicyFormattingMapper, PolicyMapper, SerializeMapper};
+
+/// Wraps a policy field that generated `*Redacted` conversions just redacted.
+///
+/// Only derive output may call this; it skips redaction and exists so the
+/// generated companion types can build `Redacted` fields.
+#[must_use]
+pub fn redacted<T>(value: T) -> crate::Redacted<T> {
+    crate::Redacted::from_redacted(value)
+}
 
 pub use field::{
     PolicyApplicableRefForFormatting, PolicyApplicableRefForGeneratedFormatting, PolicyField,
-    PolicyFieldRef, PolicyFieldRefForFormatting, PolicyKindField, PolicyKindFieldRef,
-    PolicyKindFieldRefForFormatting, RecursivePolicyField,
+    PolicyFieldDispatch, PolicyFieldProbe, PolicyFieldRef, PolicyFieldRefForFormatting,
+    PolicyFieldRoute, PolicyKindField, PolicyKindFieldRef, PolicyKindFieldRefForFormatting,
+    PolicyLeafRoute, RecursivePolicyField,
 };
 pub use formatting::{
     ExplicitLegacyPolicyFormattingRef, GeneratedPolicyFormattingRef, LegacyPolicyFormattingRef,
+    NotSensitiveDebugDispatch, NotSensitiveDebugFallback, NotSensitiveDebugProbe,
     PolicyFormattingDispatch, PolicyFormattingProbe, PolicyFormattingRef,
     legacy_policy_formatting_ref, policy_formatting_probe, policy_formatting_ref,
 };
@@ -44,6 +58,32 @@ pub use kinds::{
 };
 pub use output::{PolicyFormattingOutput, PolicyRefCellOutput};
 
+/// Reports whether a `redacted_log!` format string captures an identifier
+/// inline (`{user}`), which `log` would format without redaction.
+///
+/// Escaped braces (`{{`) are skipped; positional (`{}`, `{0}`) and width or
+/// precision arguments (`{:>width$}`) are not captures of a logged value.
+#[cfg(feature = "log")]
+#[must_use]
+pub const fn captures_inline_argument(fmt: &str) -> bool {
+    let bytes = fmt.as_bytes();
+    let mut index = 0;
+    while index + 1 < bytes.len() {
+        if bytes[index] == b'{' {
+            let next = bytes[index + 1];
+            if next == b'{' {
+                index += 2;
+                continue;
+            }
+            if next == b'_' || next.is_ascii_alphabetic() || !next.is_ascii() {
+                return true;
+            }
+        }
+        index += 1;
+    }
+    false
+}
+
 /// Constructs generated borrowed slog output without exposing internal constructors.
 #[cfg(feature = "slog")]
 #[doc(hidden)]
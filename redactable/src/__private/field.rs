@@ -1,8 +1,10 @@
 //! Field-level policy dispatch for owned and borrowed generated field shapes.
 
+use std::marker::PhantomData;
+
 use crate::{
-    IpAddressPolicyKind, RedactableMapper, RedactableWithFormatter, RedactionPolicy,
-    ScalarRedaction, SecretPolicyKind, TextPolicyKind,
+    IpAddressPolicyKind, REDACTED_PLACEHOLDER, RedactableMapper, RedactableWithFormatter,
+    RedactionPolicy, ScalarRedaction, SecretPolicyKind, SensitiveWithPolicy, TextPolicyKind,
     policy::RecursivePolicyKind,
     redaction::{
         IpPolicyApplicable, IpPolicyApplicableRef, PolicyApplicable, PolicyApplicableRef,
@@ -175,6 +177,97 @@ where
     }
 }
 
+/// Dispatch probe that picks the route for one annotated owned field.
+///
+/// Generated traversal calls `(&&probe).redactable_policy_route()`, so rustc
+/// tries the receivers in order: a type with a [`PolicyField`] implementation
+/// keeps that route; otherwise a leaf implementing [`SensitiveWithPolicy<P>`]
+/// (such as a derived container that also has a manual leaf impl) is redacted as
+/// one leaf. The unconstrained last level reports the ordinary `PolicyField`
+/// error for anything else.
+#[doc(hidden)]
+pub struct PolicyFieldProbe<P, T>(PhantomData<fn(T, P) -> T>);
+
+impl<P, T> PolicyFieldProbe<P, T> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P, T> Default for PolicyFieldProbe<P, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Route selection for [`PolicyFieldProbe`].
+#[doc(hidden)]
+pub trait PolicyFieldDispatch {
+    /// The selected route.
+    type Route;
+
+    /// Selects the route without touching the field value.
+    fn redactable_policy_route(self) -> Self::Route;
+}
+
+impl<P, T> PolicyFieldDispatch for &&PolicyFieldProbe<P, T>
+where
+    P: RedactionPolicy,
+    T: PolicyField<P>,
+{
+    type Route = PolicyFieldRoute<P, T>;
+
+    fn redactable_policy_route(self) -> Self::Route {
+        PolicyFieldRoute(PhantomData)
+    }
+}
+
+impl<P, T> PolicyFieldDispatch for &PolicyFieldProbe<P, T>
+where
+    P: RedactionPolicy,
+    T: SensitiveWithPolicy<P>,
+{
+    type Route = PolicyLeafRoute<P, T>;
+
+    fn redactable_policy_route(self) -> Self::Route {
+        PolicyLeafRoute(PhantomData)
+    }
+}
+
+impl<P, T> PolicyFieldDispatch for PolicyFieldProbe<P, T> {
+    type Route = PolicyFieldRoute<P, T>;
+
+    fn redactable_policy_route(self) -> Self::Route {
+        PolicyFieldRoute(PhantomData)
+    }
+}
+
+/// Route that applies [`PolicyField`].
+#[doc(hidden)]
+pub struct PolicyFieldRoute<P, T>(PhantomData<fn(T, P) -> T>);
+
+impl<P: RedactionPolicy, T> PolicyFieldRoute<P, T> {
+    /// Applies `P` through the field's [`PolicyField`] implementation.
+    pub fn apply<M: RedactableMapper>(self, value: T, mapper: &M) -> T
+    where
+        T: PolicyField<P>,
+    {
+        value.apply_field(mapper)
+    }
+}
+
+/// Route that redacts the field as one [`SensitiveWithPolicy<P>`] leaf.
+#[doc(hidden)]
+pub struct PolicyLeafRoute<P, T>(PhantomData<fn(T, P) -> T>);
+
+impl<P: RedactionPolicy, T: SensitiveWithPolicy<P>> PolicyLeafRoute<P, T> {
+    /// Hands the field to the mapper as a sensitive leaf.
+    pub fn apply<M: RedactableMapper>(self, value: T, mapper: &M) -> T {
+        mapper.map_sensitive::<T, P>(value)
+    }
+}
+
 /// Kind-level reference dispatch behind the single generated field trait impl.
 #[doc(hidden)]
 pub trait PolicyKindFieldRef<P: RedactionPolicy, T: ?Sized> {
@@ -349,3 +442,86 @@ macro_rules! impl_secret_scalar {
 impl_secret_scalar!(
     i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char,
 );
+
+// Secret byte arrays (keys, nonces, digests) are redacted as a whole: owned
+// redaction zeroes every byte and formatting renders the placeholder instead
+// of one scalar per byte.
+impl<P, const N: usize> PolicyKindField<P, [u8; N]> for SecretPolicyKind
+where
+    P: RedactionPolicy<Kind = SecretPolicyKind>,
+{
+    fn apply_kind<M: RedactableMapper>(_value: [u8; N], _mapper: &M) -> [u8; N] {
+        [0; N]
+    }
+}
+
+impl<P, const N: usize> PolicyKindFieldRef<P, [u8; N]> for SecretPolicyKind
+where
+    P: RedactionPolicy<Kind = SecretPolicyKind>,
+{
+    type Output = &'static str;
+
+    fn apply_kind_ref<M: RedactableMapper>(_value: &[u8; N], _mapper: &M) -> Self::Output {
+        REDACTED_PLACEHOLDER
+    }
+}
+
+impl<P, const N: usize> PolicyKindFieldRefForFormatting<P, [u8; N]> for SecretPolicyKind
+where
+    P: RedactionPolicy<Kind = SecretPolicyKind>,
+{
+    type FormattingOutput = &'static str;
+
+    fn apply_kind_ref_for_formatting<M: RedactableMapper>(
+        _value: &[u8; N],
+        _mapper: &M,
+    ) -> PolicyFormattingOutput<&'static str> {
+        PolicyFormattingOutput::Value(REDACTED_PLACEHOLDER)
+    }
+}
+
+// Secret byte buffers (payloads, ciphertexts) are cleared rather than zeroed
+// so the redacted value does not even reveal the payload length; formatting
+// renders the placeholder like secret byte arrays.
+macro_rules! impl_secret_byte_buffer {
+    ($( [$($lt:lifetime)?] $ty:ty => $empty:expr ),+ $(,)?) => {$ (
+        impl<$($lt,)? P> PolicyKindField<P, $ty> for SecretPolicyKind
+        where
+            P: RedactionPolicy<Kind = SecretPolicyKind>,
+        {
+            fn apply_kind<M: RedactableMapper>(_value: $ty, _mapper: &M) -> $ty {
+                $empty
+            }
+        }
+
+        impl<$($lt,)? P> PolicyKindFieldRef<P, $ty> for SecretPolicyKind
+        where
+            P: RedactionPolicy<Kind = SecretPolicyKind>,
+        {
+            type Output = &'static str;
+
+            fn apply_kind_ref<M: RedactableMapper>(_value: &$ty, _mapper: &M) -> Self::Output {
+                REDACTED_PLACEHOLDER
+            }
+        }
+
+        impl<$($lt,)? P> PolicyKindFieldRefForFormatting<P, $ty> for SecretPolicyKind
+        where
+            P: RedactionPolicy<Kind = SecretPolicyKind>,
+        {
+            type FormattingOutput = &'static str;
+
+            fn apply_kind_ref_for_formatting<M: RedactableMapper>(
+                _value: &$ty,
+                _mapper: &M,
+            ) -> PolicyFormattingOutput<&'static str> {
+                PolicyFormattingOutput::Value(REDACTED_PLACEHOLDER)
+            }
+        }
+    )+ };
+}
+
+impl_secret_byte_buffer!(
+    [] Vec<u8> => Vec::new(),
+    ['a] std::borrow::Cow<'a, [u8]> => std::borrow::Cow::Borrowed(&[]),
+);
@@ -214,3 +214,46 @@ where
         )
     }
 }
+
+/// Nominal dispatch probe for the `Debug` output of `#[not_sensitive]` fields.
+///
+/// A `#[not_sensitive]` field is a pure move, so its type need not implement
+/// `Debug` (function-pointer wrappers, `Box<dyn Fn()>`). Types that do format
+/// through their own `Debug`; the double-reference fallback writes `<opaque>`.
+#[doc(hidden)]
+pub struct NotSensitiveDebugProbe<'a, T>(pub &'a T);
+
+/// Placeholder printed for `#[not_sensitive]` fields whose type lacks `Debug`.
+struct OpaqueDebug;
+
+impl std::fmt::Debug for OpaqueDebug {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("<opaque>")
+    }
+}
+
+/// Debug route for `#[not_sensitive]` fields whose type implements `Debug`.
+#[doc(hidden)]
+pub trait NotSensitiveDebugDispatch<'a> {
+    /// Returns the value to pass to the generated debug builder.
+    fn redactable_not_sensitive_debug(&self) -> &'a dyn std::fmt::Debug;
+}
+
+impl<'a, T: std::fmt::Debug> NotSensitiveDebugDispatch<'a> for NotSensitiveDebugProbe<'a, T> {
+    fn redactable_not_sensitive_debug(&self) -> &'a dyn std::fmt::Debug {
+        self.0
+    }
+}
+
+/// Fallback debug route for `#[not_sensitive]` fields without `Debug`.
+#[doc(hidden)]
+pub trait NotSensitiveDebugFallback<'a> {
+    /// Returns the placeholder to pass to the generated debug builder.
+    fn redactable_not_sensitive_debug(&self) -> &'a dyn std::fmt::Debug;
+}
+
+impl<'a, T> NotSensitiveDebugFallback<'a> for &NotSensitiveDebugProbe<'a, T> {
+    fn redactable_not_sensitive_debug(&self) -> &'a dyn std::fmt::Debug {
+        &OpaqueDebug
+    }
+}
@@ -309,6 +309,63 @@ impl_secret_scalar_formatting!(
     i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char,
 );
 
+impl<const N: usize> PolicyApplicableRefForFormatting for [u8; N] {}
+
+impl<P, const N: usize> PolicyKindDisplayFormatting<P, [u8; N]> for SecretPolicyKind
+where
+    P: RedactionPolicy<Kind = SecretPolicyKind>,
+{
+    fn fmt_display(value: &[u8; N], formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        <[u8; N] as PolicyFieldRef<P>>::apply_field_ref(value, &PolicyMapper)
+            .fmt_redacted(formatter)
+    }
+}
+
+impl<P, const N: usize> PolicyKindDebugFormatting<P, [u8; N]> for SecretPolicyKind
+where
+    P: RedactionPolicy<Kind = SecretPolicyKind>,
+{
+    fn fmt_debug(value: &[u8; N], formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(
+            &<[u8; N] as PolicyFieldRef<P>>::apply_field_ref(value, &PolicyMapper),
+            formatter,
+        )
+    }
+}
+
+macro_rules! impl_secret_byte_buffer_formatting {
+    ($( [$($lt:lifetime)?] $ty:ty ),+ $(,)?) => {$ (
+        impl<$($lt)?> PolicyApplicableRefForFormatting for $ty {}
+
+        impl<$($lt,)? P> PolicyKindDisplayFormatting<P, $ty> for SecretPolicyKind
+        where
+            P: RedactionPolicy<Kind = SecretPolicyKind>,
+        {
+            fn fmt_display(value: &$ty, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                <$ty as PolicyFieldRef<P>>::apply_field_ref(value, &PolicyMapper)
+                    .fmt_redacted(formatter)
+            }
+        }
+
+        impl<$($lt,)? P> PolicyKindDebugFormatting<P, $ty> for SecretPolicyKind
+        where
+            P: RedactionPolicy<Kind = SecretPolicyKind>,
+        {
+            fn fmt_debug(value: &$ty, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Debug::fmt(
+                    &<$ty as PolicyFieldRef<P>>::apply_field_ref(value, &PolicyMapper),
+                    formatter,
+                )
+            }
+        }
+    )+ };
+}
+
+impl_secret_byte_buffer_formatting!(
+    [] Vec<u8>,
+    ['a] std::borrow::Cow<'a, [u8]>,
+);
+
 #[cfg(feature = "ip-address")]
 macro_rules! impl_root_ip_field {
     ($($ty:ty),+ $(,)?) => {$ (
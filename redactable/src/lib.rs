@@ -90,6 +90,8 @@ extern crate self as redactable;
 #[doc(hidden)]
 #[cfg(feature = "redaction")]
 pub mod __private;
+#[cfg(feature = "log")]
+pub mod log;
 #[cfg(feature = "policy")]
 pub mod policy;
 #[cfg(feature = "redaction")]
@@ -100,24 +102,38 @@ pub mod slog;
 pub mod tracing;
 
 // Re-exports from policy module
+#[cfg(feature = "hash")]
+pub use policy::HashConfig;
+#[cfg(feature = "regex")]
+pub use policy::RegexConfig;
 #[cfg(feature = "policy")]
 pub use policy::{
-    BlockchainAddress, CreditCard, Email, EmailConfig, IpAddress, IpAddressPolicyKind, KeepConfig,
-    MASK_CHAR, MaskConfig, PhoneNumber, Pii, PolicyKind, REDACTED_PLACEHOLDER, RedactionPolicy,
-    Secret, SecretPolicyKind, TextPolicyKind, TextRedactionPolicy, Token,
+    BlockchainAddress, CheckDigit, CreditCard, DateConfig, DateOfBirth, DelimiterConfig, Email,
+    EmailConfig, FullRedact, IpAddress, IpAddressPolicyKind, IpConfig, Jwt, KeepConfig, KeepWindow,
+    MASK_CHAR, MaskConfig, PhoneConfig, PhoneNumber, Pii, PolicyKind, REDACTED_PLACEHOLDER,
+    RedactionError, RedactionPolicy, Secret, SecretPolicyKind, SegmentConfig, Ssn, TextPolicyKind,
+    TextRedactionPolicy, Token, TruncateConfig,
 };
+#[cfg(feature = "json")]
+pub use policy::{JsonString, JsonStringConfig};
 // Re-exports from redaction module: public API
+#[cfg(feature = "redaction")]
+pub use redaction::{
+    DisplayKeys, IntoRedactedOutputExt, NotSensitive, NotSensitiveBytes, NotSensitiveBytesExt,
+    NotSensitiveDebug, NotSensitiveDebugExt, NotSensitiveDisplay, NotSensitiveDisplayExt,
+    NotSensitiveExt, NotSensitiveValue, RedactKeys, Redactable, RedactableWithFormatter, Redacted,
+    RedactedFormatterRef, RedactedOutput, RedactedOutputExt, RedactedOutputRef, RedactedRecord,
+    SensitiveText, SensitiveValue, SensitiveWithPolicy, ToRedactedOutput, TryRedactableWithPolicy,
+};
 #[cfg(feature = "json")]
 pub use redaction::{
-    IntoRedactedJsonExt, NotSensitiveJson, NotSensitiveJsonExt, RedactedJson, RedactedJsonExt,
-    RedactedJsonRef,
+    IntoRedactedJsonExt, NotSensitiveJson, NotSensitiveJsonExt, RedactSerialize,
+    RedactSerializeExt, RedactedJson, RedactedJsonExt, RedactedJsonRef,
 };
 #[cfg(feature = "redaction")]
 pub use redaction::{
-    IntoRedactedOutputExt, NotSensitive, NotSensitiveDebug, NotSensitiveDebugExt,
-    NotSensitiveDisplay, NotSensitiveDisplayExt, NotSensitiveExt, NotSensitiveValue, Redactable,
-    RedactableWithFormatter, RedactedFormatterRef, RedactedOutput, RedactedOutputExt,
-    RedactedOutputRef, SensitiveValue, SensitiveWithPolicy, ToRedactedOutput,
+    PassthroughRedaction, RedactEverything, RedactableMapper, RedactableWithMapper,
+    ScalarRedaction, redact_everything, redact_with,
 };
 // Re-exports from redaction module: internal machinery (used by derive-generated code)
 #[doc(hidden)]
@@ -126,8 +142,7 @@ pub use redaction::PolicyRedactedFormatterRef;
 #[doc(hidden)]
 #[cfg(feature = "redaction")]
 pub use redaction::{
-    PolicyApplicable, PolicyApplicableRef, RedactableMapper, RedactableWithMapper, ScalarRedaction,
-    apply_policy, apply_policy_ref, redact,
+    PolicyApplicable, PolicyApplicableRef, apply_policy, apply_policy_ref, redact,
 };
 #[cfg(feature = "slog")]
 pub use slog::{RedactedDisplayValue, SlogRedactedDisplayExt, SlogRedactedExt};
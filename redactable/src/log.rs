@@ -0,0 +1,108 @@
+//! Adapters for emitting redacted values through the `log` facade.
+//!
+//! `log` records are formatted text, so this module renders values through
+//! their redacted display:
+//!
+//! - **[`LogRedactedExt`]**: wraps a value deriving `SensitiveDisplay`,
+//!   `SensitiveDual`, or `NotSensitiveDisplay` so `{}` formats its redacted
+//!   display.
+//!
+//! - **[`redacted_log!`](crate::redacted_log)**: forwards to `log::log!` after
+//!   wrapping every positional argument with [`LogRedactedExt::as_log`].
+//!
+//! # Example
+//!
+//! ```
+//! # #![allow(hidden_glob_reexports)]
+//! # pub use redactable::*;
+//! use redactable::{Secret, SensitiveDisplay};
+//! use redactable::log::LogRedactedExt;
+//!
+//! #[derive(SensitiveDisplay)]
+//! #[error("login failed for {user}")]
+//! struct LoginError {
+//!     #[sensitive(Secret)]
+//!     user: String,
+//! }
+//!
+//! # fn main() {
+//! let error = LoginError { user: "alice".to_owned() };
+//! ::log::info!("request rejected: {}", error.as_log());
+//! assert_eq!(error.as_log().to_string(), "login failed for [REDACTED]");
+//! # }
+//! ```
+
+use crate::redaction::{RedactableWithFormatter, RedactedFormatterRef, ToRedactedOutput};
+
+/// Extension trait for logging `RedactableWithFormatter` types through `log`.
+///
+/// Requires [`ToRedactedOutput`]: scalar formatter passthroughs like `String`
+/// format unchanged, so they would reach the log record without any
+/// transformation. The display derives generate `ToRedactedOutput`; raw values
+/// never implement it.
+///
+/// ```compile_fail
+/// use redactable::log::LogRedactedExt;
+///
+/// let password = String::from("hunter2");
+/// log::info!("{}", password.as_log());
+/// ```
+pub trait LogRedactedExt: RedactableWithFormatter {
+    /// Wraps `&self` so `Display` formats its redacted representation.
+    fn as_log(&self) -> RedactedFormatterRef<'_, Self>
+    where
+        Self: Sized,
+    {
+        self.redacted_display()
+    }
+}
+
+impl<T> LogRedactedExt for T where T: RedactableWithFormatter + ToRedactedOutput {}
+
+/// Logs through `log::log!`, formatting every positional argument with
+/// [`LogRedactedExt::as_log`].
+///
+/// The first argument is the [`log::Level`]; the rest are a format string and
+/// its arguments, each of which must implement [`LogRedactedExt`]. Pass values
+/// positionally: `log` would format an identifier captured inline by the
+/// format string (`{user}`) directly, so such format strings are rejected at
+/// compile time.
+///
+/// ```compile_fail
+/// let password = String::from("hunter2");
+/// redactable::redacted_log!(::log::Level::Warn, "rejected {password}");
+/// ```
+///
+/// ```
+/// # #![allow(hidden_glob_reexports)]
+/// # pub use redactable::*;
+/// use redactable::{Secret, SensitiveDisplay};
+///
+/// #[derive(SensitiveDisplay)]
+/// #[error("token {token}")]
+/// struct Credential {
+///     #[sensitive(Secret)]
+///     token: String,
+/// }
+///
+/// # fn main() {
+/// let credential = Credential { token: "sk-live-1234".to_owned() };
+/// redactable::redacted_log!(::log::Level::Warn, "rejected {}", credential);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! redacted_log {
+    ($level:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {{
+        // An item const, unlike an inline `const {}` block, is also evaluated
+        // by `cargo check`.
+        const _: () = assert!(
+            !$crate::__private::captures_inline_argument($fmt),
+            "`redacted_log!` cannot redact identifiers captured inline by the format string; pass each value as a positional argument",
+        );
+        $crate::__private::log::log!(
+            $level,
+            $fmt
+            $(, $crate::log::LogRedactedExt::as_log(&$arg))*
+        )
+    }};
+}
@@ -6,8 +6,15 @@
 //!   that identify what kind of sensitive data a field contains, along with the
 //!   [`RedactionPolicy`] trait and built-in implementations.
 //!
-//! - **Text policies** (`text`): The [`TextRedactionPolicy`] enum and its configuration
-//!   types (`KeepConfig`, `MaskConfig`, `EmailConfig`) for transforming strings.
+//! - **Text policies** (`text`): The [`TextRedactionPolicy`] enum and its
+//!   configuration types for transforming strings: `KeepConfig` (with its
+//!   `KeepWindow`), `MaskConfig`, `EmailConfig`, `DelimiterConfig`,
+//!   `SegmentConfig`, `TruncateConfig`, `PhoneConfig`, `IpConfig`, and
+//!   `DateConfig`, plus the feature-gated `JsonStringConfig` (`json`),
+//!   `RegexConfig` (`regex`), and `HashConfig` (`hash`).
+//!
+//! - **Presets** (`presets`): Ready-made text policies for fields named by
+//!   PCI DSS, GDPR, and HIPAA.
 //!
 //! # Example
 //!
@@ -24,14 +31,25 @@
 //! ```
 
 pub mod policies;
+pub mod presets;
 pub mod text;
 
 // Re-export everything at the module level for convenience
+#[cfg(feature = "json")]
+pub use policies::JsonString;
 pub use policies::{
-    BlockchainAddress, CreditCard, Email, IpAddress, IpAddressPolicyKind, PhoneNumber, Pii,
-    PolicyKind, RecursivePolicyKind, RedactionPolicy, Secret, SecretPolicyKind, TextPolicyKind,
-    Token,
+    BlockchainAddress, CheckDigit, CreditCard, DateOfBirth, Email, FullRedact, IpAddress,
+    IpAddressPolicyKind, Jwt, PhoneNumber, Pii, PolicyKind, RecursivePolicyKind, RedactionPolicy,
+    Secret, SecretPolicyKind, Ssn, TextPolicyKind, Token,
 };
+#[cfg(feature = "hash")]
+pub use text::HashConfig;
+#[cfg(feature = "json")]
+pub use text::JsonStringConfig;
+#[cfg(feature = "regex")]
+pub use text::RegexConfig;
 pub use text::{
-    EmailConfig, KeepConfig, MASK_CHAR, MaskConfig, REDACTED_PLACEHOLDER, TextRedactionPolicy,
+    DateConfig, DelimiterConfig, EmailConfig, IpConfig, KeepConfig, KeepWindow, MASK_CHAR,
+    MaskConfig, PhoneConfig, REDACTED_PLACEHOLDER, RedactionError, SegmentConfig,
+    TextRedactionPolicy, TruncateConfig,
 };
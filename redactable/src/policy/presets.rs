@@ -0,0 +1,213 @@
+//! Ready-made policies for fields named by common compliance regimes.
+//!
+//! Each preset is a [`TextRedactionPolicy`] built from the existing
+//! configuration types, grouped by regime so the right starting point is easy
+//! to find:
+//!
+//! - **PCI DSS**: [`pci_card`], [`pci_cvv`]
+//! - **GDPR**: [`gdpr_email`], [`gdpr_ip`], [`gdpr_phone`], [`gdpr_name`]
+//! - **HIPAA Safe Harbor**: [`hipaa_name`], [`hipaa_date`], [`hipaa_zip`], [`hipaa_ssn`]
+//!
+//! Presets are a convenience, not a compliance guarantee: review each one
+//! against your own obligations. They compose like any other policy, so a
+//! preset can be tightened with [`TextRedactionPolicy::then`] or
+//! [`TextRedactionPolicy::with_mask_char`]. To use one on a field, return it
+//! from a [`RedactionPolicy`](super::RedactionPolicy) marker:
+//!
+//! ```rust
+//! use redactable::policy::presets;
+//! use redactable::{RedactionPolicy, TextPolicyKind, TextRedactionPolicy};
+//!
+//! #[derive(Clone, Copy)]
+//! struct GdprEmail;
+//!
+//! impl RedactionPolicy for GdprEmail {
+//!     type Kind = TextPolicyKind;
+//!
+//!     fn policy() -> TextRedactionPolicy {
+//!         presets::gdpr_email()
+//!     }
+//! }
+//!
+//! assert_eq!(GdprEmail::policy().apply_to("alice@example.com"), "*****@example.com");
+//! ```
+
+use super::text::{KeepConfig, TextRedactionPolicy};
+
+// =============================================================================
+// PCI DSS
+// =============================================================================
+
+/// Primary account number: keeps the last 4 digits and any separators.
+///
+/// PCI DSS allows at most the first 6 and last 4 digits to be displayed; this
+/// preset shows only the last 4 (e.g., `"4111 1111 1111 1234"` →
+/// `"**** **** **** 1234"`).
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::pci_card();
+/// assert_eq!(policy.apply_to("4111 1111 1111 1234"), "**** **** **** 1234");
+/// assert_eq!(policy.apply_to("4111111111111234"), "************1234");
+/// ```
+#[must_use]
+pub fn pci_card() -> TextRedactionPolicy {
+    TextRedactionPolicy::keep_with(KeepConfig::last(4).with_preserved_separators())
+}
+
+/// Card verification code: always replaced by the redaction placeholder.
+///
+/// Masking would still reveal the code's length, so the whole value becomes
+/// `"[REDACTED]"`.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::pci_cvv();
+/// assert_eq!(policy.apply_to("123"), "[REDACTED]");
+/// assert_eq!(policy.apply_to("1234"), "[REDACTED]");
+/// ```
+#[must_use]
+pub fn pci_cvv() -> TextRedactionPolicy {
+    TextRedactionPolicy::default_full()
+}
+
+// =============================================================================
+// GDPR
+// =============================================================================
+
+/// Email address: masks the local part and keeps the domain.
+///
+/// The domain stays available for aggregate analysis while the mailbox that
+/// identifies the person is hidden.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::gdpr_email();
+/// assert_eq!(policy.apply_to("alice@example.com"), "*****@example.com");
+/// ```
+#[must_use]
+pub fn gdpr_email() -> TextRedactionPolicy {
+    TextRedactionPolicy::email_domain_only()
+}
+
+/// IP address: masks the host part, keeping the network prefix.
+///
+/// Masks the last IPv4 octet and the last 80 bits (5 hextets) of an IPv6
+/// address, the usual anonymization for analytics. Values that are not IP
+/// addresses are fully redacted.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::gdpr_ip();
+/// assert_eq!(policy.apply_to("192.168.1.42"), "192.168.1.*");
+/// assert_eq!(policy.apply_to("2001:db8:85a3::8a2e:370:7334"), "2001:db8:85a3:*:*:*:*:*");
+/// ```
+#[must_use]
+pub fn gdpr_ip() -> TextRedactionPolicy {
+    TextRedactionPolicy::ip_network(1, 5)
+}
+
+/// Phone number: keeps the country code and the last 2 digits.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::gdpr_phone();
+/// assert_eq!(policy.apply_to("+44 20 7946 0958"), "+44***********58");
+/// ```
+#[must_use]
+pub fn gdpr_phone() -> TextRedactionPolicy {
+    TextRedactionPolicy::phone(2)
+}
+
+/// Personal name: keeps the first character and masks the rest.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::gdpr_name();
+/// assert_eq!(policy.apply_to("Alice Smith"), "A**********");
+/// ```
+#[must_use]
+pub fn gdpr_name() -> TextRedactionPolicy {
+    TextRedactionPolicy::keep_first(1)
+}
+
+// =============================================================================
+// HIPAA Safe Harbor
+// =============================================================================
+
+/// Patient name: fully masked, preserving only the length.
+///
+/// Safe Harbor de-identification removes names entirely.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::hipaa_name();
+/// assert_eq!(policy.apply_to("Alice Smith"), "***********");
+/// ```
+#[must_use]
+pub fn hipaa_name() -> TextRedactionPolicy {
+    TextRedactionPolicy::full_masked()
+}
+
+/// Date related to an individual: keeps the year only.
+///
+/// Accepts `YYYY-MM-DD` and `MM/DD/YYYY`; other values are fully redacted.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::hipaa_date();
+/// assert_eq!(policy.apply_to("1990-07-14"), "1990-**-**");
+/// assert_eq!(policy.apply_to("07/14/1990"), "**/**/1990");
+/// ```
+#[must_use]
+pub fn hipaa_date() -> TextRedactionPolicy {
+    TextRedactionPolicy::keep_year()
+}
+
+/// ZIP code: keeps the first 3 digits.
+///
+/// Safe Harbor permits the initial three digits of a ZIP code where the
+/// corresponding area is populous enough; check that condition for your data.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::hipaa_zip();
+/// assert_eq!(policy.apply_to("94103"), "941**");
+/// assert_eq!(policy.apply_to("94103-1234"), "941**-****");
+/// ```
+#[must_use]
+pub fn hipaa_zip() -> TextRedactionPolicy {
+    TextRedactionPolicy::keep_with(KeepConfig::first(3).with_preserved_separators())
+}
+
+/// Social Security number: always replaced by the redaction placeholder.
+///
+/// # Example
+/// ```
+/// use redactable::policy::presets;
+///
+/// let policy = presets::hipaa_ssn();
+/// assert_eq!(policy.apply_to("123-45-6789"), "[REDACTED]");
+/// ```
+#[must_use]
+pub fn hipaa_ssn() -> TextRedactionPolicy {
+    TextRedactionPolicy::default_full()
+}
@@ -5,6 +5,9 @@
 //! that do not traverse structures or make runtime decisions about sensitivity.
 
 use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "regex")]
+use std::sync::Arc;
 
 /// Default placeholder used for full redaction.
 pub const REDACTED_PLACEHOLDER: &str = "[REDACTED]";
@@ -12,42 +15,243 @@ pub const REDACTED_PLACEHOLDER: &str = "[REDACTED]";
 /// Default character used to mask sensitive characters.
 pub const MASK_CHAR: char = '*';
 
+/// Ellipsis marking shortened output: a mask run capped by
+/// [`KeepConfig::with_max_mask`], or a value cut by [`TruncateConfig`] or
+/// `RedactedOutput::truncated`.
+pub(crate) const ELLIPSIS: char = '\u{2026}';
+
+/// Text written in place of masked scalar values.
+///
+/// Usually a single character; [`KeepConfig::with_mask_str`] and
+/// [`MaskConfig::with_mask_str`] allow longer strings such as `"XX"`, and
+/// [`KeepConfig::with_mask_pattern`] and [`MaskConfig::with_mask_pattern`]
+/// tile a pattern across each masked run.
+#[derive(Clone, Debug)]
+enum MaskText {
+    /// Written once per masked scalar value.
+    Repeated(Cow<'static, str>),
+    /// Tiled across each masked run, one pattern scalar value per position.
+    Tiled(Cow<'static, str>),
+}
+
+impl MaskText {
+    const DEFAULT: Self = Self::Repeated(Cow::Borrowed("*"));
+
+    fn from_char(mask_char: char) -> Self {
+        if mask_char == MASK_CHAR {
+            Self::DEFAULT
+        } else {
+            Self::Repeated(Cow::Owned(mask_char.to_string()))
+        }
+    }
+
+    /// Repeats `mask`, falling back to the default mask when it is empty.
+    fn repeated(mask: Cow<'static, str>) -> Self {
+        if mask.is_empty() {
+            Self::DEFAULT
+        } else {
+            Self::Repeated(mask)
+        }
+    }
+
+    /// Tiles `pattern`, falling back to the default mask when it is empty.
+    fn tiled(pattern: Cow<'static, str>) -> Self {
+        if pattern.is_empty() {
+            Self::DEFAULT
+        } else {
+            Self::Tiled(pattern)
+        }
+    }
+
+    /// Returns the mask character when each position is masked by one repeated
+    /// scalar value.
+    fn as_char(&self) -> Option<char> {
+        let Self::Repeated(text) = self else {
+            return None;
+        };
+        let mut chars = text.chars();
+        let mask_char = chars.next()?;
+        chars.next().is_none().then_some(mask_char)
+    }
+
+    /// Returns the text written once per masked position, unless tiled.
+    fn as_repeated(&self) -> Option<&str> {
+        match self {
+            Self::Repeated(text) => Some(text),
+            Self::Tiled(_) => None,
+        }
+    }
+
+    /// Returns the tiled pattern, if any.
+    fn as_pattern(&self) -> Option<&str> {
+        match self {
+            Self::Tiled(pattern) => Some(pattern),
+            Self::Repeated(_) => None,
+        }
+    }
+
+    /// Appends the mask for `count` masked scalar values.
+    ///
+    /// Tiled patterns restart at their first scalar value for every run, so
+    /// the output depends only on the run length.
+    fn push_repeated(&self, result: &mut String, count: usize) {
+        match self {
+            Self::Tiled(pattern) => result.extend(pattern.chars().cycle().take(count)),
+            Self::Repeated(text) => match text.chars().next() {
+                Some(mask_char) if text.len() == mask_char.len_utf8() => {
+                    result.extend(std::iter::repeat_n(mask_char, count));
+                }
+                _ => {
+                    for _ in 0..count {
+                        result.push_str(text);
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// Resolves a middle keep window to the visible `start..end` unit range.
+///
+/// Fails closed like edge windows: a window that would leave nothing masked
+/// resolves to an empty range.
+fn middle_bounds(skip_prefix: usize, visible_len: usize, total: usize) -> (usize, usize) {
+    let start = skip_prefix.min(total);
+    let end = start.saturating_add(visible_len).min(total);
+    if start == 0 && end == total {
+        (0, 0)
+    } else {
+        (start, end)
+    }
+}
+
+/// Splits `value` into the units masking counts: Unicode scalar values, or
+/// extended grapheme clusters when `graphemes` is set.
+fn mask_units(value: &str, graphemes: bool) -> Vec<&str> {
+    #[cfg(feature = "unicode-segmentation")]
+    if graphemes {
+        return unicode_segmentation::UnicodeSegmentation::graphemes(value, true).collect();
+    }
+    #[cfg(not(feature = "unicode-segmentation"))]
+    debug_assert!(!graphemes, "grapheme mode requires `unicode-segmentation`");
+
+    value
+        .char_indices()
+        .map(|(index, ch)| &value[index..index + ch.len_utf8()])
+        .collect()
+}
+
 /// Configuration that keeps selected segments visible while masking the remainder.
 ///
 /// The policy operates on Unicode scalar values and fails closed: values at or
-/// below the keep window length are fully masked rather than revealed.
+/// below the keep window length are fully masked rather than revealed. An
+/// astral-plane character such as `😀` is one scalar value, but a glyph built
+/// from several (flag or ZWJ emoji, combining accents) counts once per scalar,
+/// so a keep window can split it. With the `unicode-segmentation` feature,
+/// `KeepConfig::with_graphemes` counts grapheme clusters instead.
 ///
 /// Use the constructor methods [`KeepConfig::first`] and [`KeepConfig::last`]
 /// to create instances.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct KeepConfig {
-    /// Number of leading characters to keep visible.
-    visible_prefix: usize,
-    /// Number of trailing characters to keep visible.
-    visible_suffix: usize,
-    /// Symbol used to mask the middle.
-    mask_char: char,
+    /// Segments kept visible.
+    window: KeepWindow,
+    /// Text used to mask each hidden scalar value.
+    mask: MaskText,
+    /// Longest mask run emitted before it is shortened with an ellipsis.
+    max_mask: Option<usize>,
+    /// Whether non-alphanumeric separators stay in place and are not counted.
+    preserve_separators: bool,
+    /// Literal prefix that stays visible and is excluded from the keep window.
+    preserved_prefix: Option<Cow<'static, str>>,
+    /// Whether grapheme clusters, rather than scalar values, are counted.
+    graphemes: bool,
+    /// Shortest value, in counted units, that keeps the full window.
+    full_window_len: usize,
+}
+
+/// Which segments a [`KeepConfig`] keeps visible, as reported by
+/// [`KeepConfig::window`].
+///
+/// Marked `#[non_exhaustive]` so new window shapes can be added; downstream
+/// matches must carry a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum KeepWindow {
+    /// Keeps leading and trailing segments and masks the middle.
+    Edges {
+        /// Leading scalar values kept visible.
+        visible_prefix: usize,
+        /// Trailing scalar values kept visible.
+        visible_suffix: usize,
+    },
+    /// Masks `skip_prefix` leading scalar values, keeps the next `visible_len`,
+    /// and masks the remainder.
+    Middle {
+        /// Leading scalar values masked before the visible segment.
+        skip_prefix: usize,
+        /// Scalar values kept visible after the skipped prefix.
+        visible_len: usize,
+    },
+    /// Keeps a fraction of the value, resolved to [`KeepWindow::Edges`] from
+    /// the scalar length at call time.
+    Ratio {
+        /// Fraction of the value kept visible, as configured.
+        ratio: f32,
+    },
+}
+
+impl KeepWindow {
+    /// Resolves ratio windows to fixed edges for a `total`-length value.
+    ///
+    /// The visible count is `floor(total * ratio)`, capped so at least one
+    /// scalar value stays masked; the suffix receives the odd character.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )] // The product is clamped to `0..=total` before the cast back.
+    fn resolve(self, total: usize) -> Self {
+        let Self::Ratio { ratio } = self else {
+            return self;
+        };
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+        let visible = ((total as f32 * ratio).floor() as usize).min(total.saturating_sub(1));
+        let visible_prefix = visible / 2;
+        Self::Edges {
+            visible_prefix,
+            visible_suffix: visible - visible_prefix,
+        }
+    }
 }
 
 impl KeepConfig {
+    fn with_window(window: KeepWindow) -> Self {
+        Self {
+            window,
+            mask: MaskText::DEFAULT,
+            max_mask: None,
+            preserve_separators: false,
+            preserved_prefix: None,
+            graphemes: false,
+            full_window_len: 0,
+        }
+    }
+
     /// Constructs a configuration that keeps only the first `visible_prefix` scalar values.
     #[must_use]
     pub fn first(visible_prefix: usize) -> Self {
-        Self {
-            visible_prefix,
-            visible_suffix: 0,
-            mask_char: MASK_CHAR,
-        }
+        Self::both(visible_prefix, 0)
     }
 
     /// Constructs a configuration that keeps only the last `visible_suffix` scalar values.
     #[must_use]
     pub fn last(visible_suffix: usize) -> Self {
-        Self {
-            visible_prefix: 0,
-            visible_suffix,
-            mask_char: MASK_CHAR,
-        }
+        Self::both(0, visible_suffix)
     }
 
     /// Constructs a configuration that keeps both leading and trailing characters visible.
@@ -57,23 +261,317 @@ impl KeepConfig {
     /// reveal it completely.
     #[must_use]
     pub fn both(visible_prefix: usize, visible_suffix: usize) -> Self {
-        Self {
+        Self::with_window(KeepWindow::Edges {
             visible_prefix,
             visible_suffix,
-            mask_char: MASK_CHAR,
-        }
+        })
+    }
+
+    /// Constructs a configuration that keeps an inner segment visible.
+    ///
+    /// The first `skip_prefix` scalar values are masked, the next `visible_len`
+    /// stay visible, and the remainder is masked. If `skip_prefix >= total_length`
+    /// the entire value is masked; if `skip_prefix + visible_len >= total_length`
+    /// only the prefix is masked. A window that would mask nothing (`skip_prefix`
+    /// of 0 and `visible_len >= total_length`) masks the entire value instead.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::middle(6, 4));
+    /// assert_eq!(policy.apply_to("order-2024-XYZ-batch"), "******2024**********");
+    /// ```
+    #[must_use]
+    pub fn middle(skip_prefix: usize, visible_len: usize) -> Self {
+        Self::with_window(KeepWindow::Middle {
+            skip_prefix,
+            visible_len,
+        })
+    }
+
+    /// Constructs a configuration that keeps `ratio` of the value visible.
+    ///
+    /// The visible count is `floor(len * ratio)` scalar values, computed per
+    /// value and split between a prefix and a suffix (the suffix receives the
+    /// odd one). At least one scalar value is always masked, so single
+    /// characters are fully masked. `ratio` is clamped to `0.0..=1.0`; NaN
+    /// keeps nothing.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::ratio(0.5));
+    /// assert_eq!(policy.apply_to("abcdefgh"), "ab****gh");
+    /// ```
+    #[must_use]
+    pub fn ratio(ratio: f32) -> Self {
+        Self::with_window(KeepWindow::Ratio { ratio })
     }
 
     /// Uses a specific masking character.
     #[must_use]
     pub fn with_mask_char(mut self, mask_char: char) -> Self {
-        self.mask_char = mask_char;
+        self.set_mask_char(mask_char);
+        self
+    }
+
+    /// Uses a mask string, written once per hidden scalar value. An empty string
+    /// keeps the default `*` mask.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::last(2).with_mask_str("XX"));
+    /// assert_eq!(policy.apply_to("abcd"), "XXXXcd");
+    /// ```
+    #[must_use]
+    pub fn with_mask_str(mut self, mask: impl Into<Cow<'static, str>>) -> Self {
+        self.set_mask_str(mask.into());
+        self
+    }
+
+    /// Tiles `pattern` across each masked run, one pattern scalar value per
+    /// hidden position, so masked output keeps the value's length and shape.
+    ///
+    /// Tiling restarts for every run and is deterministic. An empty pattern
+    /// keeps the default mask.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::last(2).with_mask_pattern("Xx9"));
+    /// assert_eq!(policy.apply_to("sk_live_42"), "Xx9Xx9Xx42");
+    /// ```
+    #[must_use]
+    pub fn with_mask_pattern(mut self, pattern: impl Into<Cow<'static, str>>) -> Self {
+        self.mask = MaskText::tiled(pattern.into());
+        self
+    }
+
+    /// Caps the mask run at `max_mask` characters followed by an ellipsis (`…`).
+    ///
+    /// Long values otherwise produce one mask character per hidden scalar
+    /// value. With a cap, a run longer than `max_mask` is shortened, which
+    /// also stops the output from revealing the hidden length.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2).with_max_mask(3));
+    /// assert_eq!(policy.apply_to("ab0123456789ef"), "ab***\u{2026}ef");
+    /// assert_eq!(policy.apply_to("ab01ef"), "ab**ef");
+    /// ```
+    #[must_use]
+    pub fn with_max_mask(mut self, max_mask: usize) -> Self {
+        self.max_mask = Some(max_mask);
+        self
+    }
+
+    /// Keeps separators (non-alphanumeric scalar values such as `-` or spaces)
+    /// in their original positions.
+    ///
+    /// Only alphanumeric scalar values are counted against the keep window and
+    /// masked, so formatted identifiers keep their shape. The output always has
+    /// one mask per hidden scalar value; [`KeepConfig::with_max_mask`] does not
+    /// apply in this mode.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy =
+    ///     TextRedactionPolicy::keep_with(KeepConfig::last(4).with_preserved_separators());
+    /// assert_eq!(policy.apply_to("4111-1111-1111-1111"), "****-****-****-1111");
+    /// ```
+    #[must_use]
+    pub fn with_preserved_separators(mut self) -> Self {
+        self.preserve_separators = true;
         self
     }
 
+    /// Keeps a literal `prefix` such as `0x` visible whenever the value starts
+    /// with it.
+    ///
+    /// The prefix is matched exactly, is not counted against the keep window,
+    /// and is never masked; the window applies to the remainder. A value
+    /// consisting of the prefix alone keeps it, followed by a single mask
+    /// character. Values without the prefix are redacted as if no prefix were
+    /// configured.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::first(2).with_preserved_prefix("0x"));
+    /// assert_eq!(policy.apply_to("0xabcdef"), "0xab****");
+    /// assert_eq!(policy.apply_to("0x"), "0x*");
+    /// assert_eq!(policy.apply_to("abcdef"), "ab****");
+    /// ```
+    #[must_use]
+    pub fn with_preserved_prefix(mut self, prefix: impl Into<Cow<'static, str>>) -> Self {
+        self.preserved_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Scales the keep window down for values shorter than `full_window_len`
+    /// scalar values, so they keep the same share visible instead of the full
+    /// counts.
+    ///
+    /// Fixed keep windows reveal a larger share of shorter values; a value just
+    /// long enough to leave one character masked would otherwise be almost
+    /// fully visible. Each count is multiplied by `total / full_window_len` and
+    /// rounded down. The length is counted after any preserved prefix, and
+    /// separators kept by [`KeepConfig::with_preserved_separators`] are not
+    /// counted. Ratio windows already scale and are unaffected.
+    ///
+    /// ```
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::both(4, 4).with_full_window_len(16));
+    /// assert_eq!(policy.apply_to("abcdefghijklmnop"), "abcd********mnop");
+    /// assert_eq!(policy.apply_to("abcdefgh"), "ab****gh");
+    /// assert_eq!(policy.apply_to("abc"), "***");
+    /// ```
+    #[must_use]
+    pub fn with_full_window_len(mut self, full_window_len: usize) -> Self {
+        self.full_window_len = full_window_len;
+        self
+    }
+
+    /// Counts and masks extended grapheme clusters instead of scalar values
+    /// (requires the `unicode-segmentation` feature).
+    ///
+    /// A visible glyph built from several scalar values (flag and ZWJ emoji,
+    /// combining accents) is then kept or masked whole, and each masked
+    /// cluster produces one mask.
+    ///
+    /// ```
+    /// # #[cfg(feature = "unicode-segmentation")]
+    /// # {
+    /// use redactable::{KeepConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::keep_with(KeepConfig::first(1).with_graphemes());
+    /// assert_eq!(policy.apply_to("\u{1F1F3}\u{1F1FF} NZ"), "\u{1F1F3}\u{1F1FF}***");
+    /// # }
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn with_graphemes(mut self) -> Self {
+        self.graphemes = true;
+        self
+    }
+
+    /// Returns the segments kept visible.
+    ///
+    /// ```
+    /// use redactable::{KeepWindow, RedactionPolicy, TextRedactionPolicy, Token};
+    ///
+    /// let TextRedactionPolicy::Keep(config) = Token::policy() else {
+    ///     panic!("Token keeps a suffix visible");
+    /// };
+    /// let KeepWindow::Edges { visible_suffix, .. } = config.window() else {
+    ///     panic!("Token keeps fixed edges");
+    /// };
+    /// let mask = config.mask_char().unwrap_or('?');
+    /// assert_eq!(
+    ///     format!("keeps last {visible_suffix}, mask '{mask}'"),
+    ///     "keeps last 4, mask '*'"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn window(&self) -> KeepWindow {
+        self.window
+    }
+
+    /// Returns the masking character, or `None` when the mask is a longer
+    /// string or a tiled pattern.
+    #[must_use]
+    pub fn mask_char(&self) -> Option<char> {
+        self.mask.as_char()
+    }
+
+    /// Returns the text written once per hidden scalar value, or `None` when
+    /// the mask is a tiled pattern.
+    #[must_use]
+    pub fn mask_str(&self) -> Option<&str> {
+        self.mask.as_repeated()
+    }
+
+    /// Returns the tiled mask pattern set by [`KeepConfig::with_mask_pattern`].
+    #[must_use]
+    pub fn mask_pattern(&self) -> Option<&str> {
+        self.mask.as_pattern()
+    }
+
+    /// Returns the mask run cap set by [`KeepConfig::with_max_mask`].
+    #[must_use]
+    pub fn max_mask(&self) -> Option<usize> {
+        self.max_mask
+    }
+
+    /// Returns whether separators stay in place, as set by
+    /// [`KeepConfig::with_preserved_separators`].
+    #[must_use]
+    pub fn preserves_separators(&self) -> bool {
+        self.preserve_separators
+    }
+
+    /// Returns the literal prefix set by [`KeepConfig::with_preserved_prefix`].
+    #[must_use]
+    pub fn preserved_prefix(&self) -> Option<&str> {
+        self.preserved_prefix.as_deref()
+    }
+
+    /// Returns whether grapheme clusters, rather than scalar values, are counted.
+    #[must_use]
+    pub fn counts_graphemes(&self) -> bool {
+        self.graphemes
+    }
+
+    /// Returns the length set by [`KeepConfig::with_full_window_len`].
+    #[must_use]
+    pub fn full_window_len(&self) -> usize {
+        self.full_window_len
+    }
+
+    /// Resolves the keep window for a `total`-length value, scaling fixed
+    /// counts down below [`KeepConfig::with_full_window_len`].
+    fn resolved_window(&self, total: usize) -> KeepWindow {
+        let window = self.window.resolve(total);
+        if total >= self.full_window_len {
+            return window;
+        }
+        // Widen before multiplying so huge counts cannot overflow; the scaled
+        // count is below `count` because `total < full_window_len`.
+        let scale = |count: usize| {
+            let scaled = count as u128 * total as u128 / self.full_window_len as u128;
+            usize::try_from(scaled).unwrap_or(count)
+        };
+        match window {
+            KeepWindow::Edges {
+                visible_prefix,
+                visible_suffix,
+            } => KeepWindow::Edges {
+                visible_prefix: scale(visible_prefix),
+                visible_suffix: scale(visible_suffix),
+            },
+            KeepWindow::Middle {
+                skip_prefix,
+                visible_len,
+            } => KeepWindow::Middle {
+                skip_prefix: scale(skip_prefix),
+                visible_len: scale(visible_len),
+            },
+            KeepWindow::Ratio { .. } => window,
+        }
+    }
+
     /// Sets the masking character in place.
     pub(crate) fn set_mask_char(&mut self, mask_char: char) {
-        self.mask_char = mask_char;
+        self.mask = MaskText::from_char(mask_char);
+    }
+
+    /// Sets the mask string in place.
+    pub(crate) fn set_mask_str(&mut self, mask: Cow<'static, str>) {
+        self.mask = MaskText::repeated(mask);
     }
 
     /// Applies the policy to a string value.
@@ -83,25 +581,129 @@ impl KeepConfig {
     /// If `visible_prefix + visible_suffix >= total_length`, the entire value
     /// is masked. Keep policies fail closed: a keep window that covers the
     /// whole value (e.g. a 4-character token under a keep-last-4 policy) would
-    /// otherwise reveal it completely.
+    /// otherwise reveal it completely. Middle windows follow the rules
+    /// documented on [`KeepConfig::middle`].
     pub(crate) fn apply_to(&self, value: &str) -> String {
-        let mut chars: Vec<char> = value.chars().collect();
-        let total = chars.len();
+        if let Some(prefix) = self.preserved_prefix.as_deref()
+            && !prefix.is_empty()
+            && let Some(rest) = value.strip_prefix(prefix)
+        {
+            let mut result = prefix.to_string();
+            if rest.is_empty() {
+                // Mask a bare prefix like a one-character remainder, so it
+                // still reads as redacted and looks like any short value.
+                self.push_mask(&mut result, 1);
+            } else {
+                result.push_str(&self.apply_window(rest));
+            }
+            return result;
+        }
+        self.apply_window(value)
+    }
+
+    /// Applies the keep window to `value`, ignoring any preserved prefix.
+    fn apply_window(&self, value: &str) -> String {
+        let units = mask_units(value, self.graphemes);
+        let total = units.len();
         if total == 0 {
             return REDACTED_PLACEHOLDER.to_string();
         }
+        if self.preserve_separators {
+            return self.apply_preserving_separators(&units);
+        }
+
+        let mut result = String::with_capacity(value.len());
+        match self.resolved_window(total) {
+            KeepWindow::Edges {
+                visible_prefix,
+                visible_suffix,
+            } => {
+                // Fail closed: if keep spans cover or exceed the total length, mask everything
+                let (prefix, suffix) = if visible_prefix.saturating_add(visible_suffix) >= total {
+                    (0, 0)
+                } else {
+                    (visible_prefix, visible_suffix)
+                };
+                result.extend(units[..prefix].iter().copied());
+                self.push_mask(&mut result, total - prefix - suffix);
+                result.extend(units[total - suffix..].iter().copied());
+            }
+            KeepWindow::Middle {
+                skip_prefix,
+                visible_len,
+            } => {
+                let (start, end) = middle_bounds(skip_prefix, visible_len, total);
+                self.push_mask(&mut result, start);
+                result.extend(units[start..end].iter().copied());
+                if end < total {
+                    self.push_mask(&mut result, total - end);
+                }
+            }
+            KeepWindow::Ratio { .. } => unreachable!("ratio windows resolve to edges"),
+        }
+        result
+    }
+
+    /// Returns the two ranges of a `total`-length value that stay visible.
+    ///
+    /// Applies the same fail-closed rules as the contiguous path.
+    fn visible_ranges(&self, total: usize) -> [std::ops::Range<usize>; 2] {
+        match self.resolved_window(total) {
+            KeepWindow::Edges {
+                visible_prefix,
+                visible_suffix,
+            } => {
+                if visible_prefix.saturating_add(visible_suffix) >= total {
+                    [0..0, total..total]
+                } else {
+                    [0..visible_prefix, total - visible_suffix..total]
+                }
+            }
+            KeepWindow::Middle {
+                skip_prefix,
+                visible_len,
+            } => {
+                let (start, end) = middle_bounds(skip_prefix, visible_len, total);
+                [start..end, total..total]
+            }
+            KeepWindow::Ratio { .. } => unreachable!("ratio windows resolve to edges"),
+        }
+    }
 
-        // Fail closed: if keep spans cover or exceed the total length, mask everything
-        if self.visible_prefix.saturating_add(self.visible_suffix) >= total {
-            chars.fill(self.mask_char);
-            return chars.into_iter().collect();
+    /// Masks alphanumeric units outside the keep window, leaving separators in
+    /// place. A grapheme cluster counts as alphanumeric when its base scalar
+    /// value is.
+    fn apply_preserving_separators(&self, units: &[&str]) -> String {
+        let is_alphanumeric = |unit: &str| unit.chars().next().is_some_and(char::is_alphanumeric);
+        let counted = units.iter().filter(|unit| is_alphanumeric(unit)).count();
+        let [first, second] = self.visible_ranges(counted);
+
+        let mut result = String::with_capacity(units.len());
+        let mut index = 0;
+        for &unit in units {
+            if !is_alphanumeric(unit) {
+                result.push_str(unit);
+                continue;
+            }
+            if first.contains(&index) || second.contains(&index) {
+                result.push_str(unit);
+            } else {
+                self.mask.push_repeated(&mut result, 1);
+            }
+            index += 1;
         }
+        result
+    }
 
-        // Mask the middle portion
-        for ch in &mut chars[self.visible_prefix..(total - self.visible_suffix)] {
-            *ch = self.mask_char;
+    /// Appends the mask run for `hidden` scalar values, honoring the cap.
+    fn push_mask(&self, result: &mut String, hidden: usize) {
+        match self.max_mask {
+            Some(max_mask) if hidden > max_mask => {
+                self.mask.push_repeated(result, max_mask);
+                result.push(ELLIPSIS);
+            }
+            _ => self.mask.push_repeated(result, hidden),
         }
-        chars.into_iter().collect()
     }
 }
 
@@ -112,15 +714,17 @@ impl KeepConfig {
 ///
 /// Use the constructor methods [`MaskConfig::first`] and [`MaskConfig::last`]
 /// to create instances.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 #[allow(clippy::struct_field_names)] // Field names are descriptive for internal use
 pub struct MaskConfig {
     /// Number of leading characters to mask.
     mask_prefix: usize,
     /// Number of trailing characters to mask.
     mask_suffix: usize,
-    /// Symbol used to mask the selected segments.
-    mask_char: char,
+    /// Text used to mask each selected scalar value.
+    mask: MaskText,
+    /// Whether grapheme clusters, rather than scalar values, are counted.
+    graphemes: bool,
 }
 
 impl MaskConfig {
@@ -130,7 +734,8 @@ impl MaskConfig {
         Self {
             mask_prefix,
             mask_suffix: 0,
-            mask_char: MASK_CHAR,
+            mask: MaskText::DEFAULT,
+            graphemes: false,
         }
     }
 
@@ -140,10 +745,17 @@ impl MaskConfig {
         Self {
             mask_prefix: 0,
             mask_suffix,
-            mask_char: MASK_CHAR,
+            mask: MaskText::DEFAULT,
+            graphemes: false,
         }
     }
 
+    /// Masks every character, preserving the value's length in scalar values.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::first(usize::MAX)
+    }
+
     /// Masks both leading and trailing characters.
     ///
     /// If `mask_prefix + mask_suffix >= total_length`, the entire value
@@ -153,55 +765,133 @@ impl MaskConfig {
         Self {
             mask_prefix,
             mask_suffix,
-            mask_char: MASK_CHAR,
+            mask: MaskText::DEFAULT,
+            graphemes: false,
         }
     }
 
     /// Uses a specific masking character.
     #[must_use]
     pub fn with_mask_char(mut self, mask_char: char) -> Self {
-        self.mask_char = mask_char;
+        self.set_mask_char(mask_char);
         self
     }
 
-    /// Sets the masking character in place.
-    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
-        self.mask_char = mask_char;
+    /// Uses a mask string, written once per masked scalar value. An empty string
+    /// keeps the default `*` mask.
+    ///
+    /// ```
+    /// use redactable::{MaskConfig, TextRedactionPolicy};
+    ///
+    /// let policy = TextRedactionPolicy::mask_with(MaskConfig::first(2).with_mask_str("##"));
+    /// assert_eq!(policy.apply_to("abcdef"), "####cdef");
+    /// ```
+    #[must_use]
+    pub fn with_mask_str(mut self, mask: impl Into<Cow<'static, str>>) -> Self {
+        self.set_mask_str(mask.into());
+        self
     }
 
-    /// Applies the policy to a string value.
+    /// Tiles `pattern` across each masked run, one pattern scalar value per
+    /// masked position.
     ///
-    /// Empty strings are fully redacted using [`REDACTED_PLACEHOLDER`].
+    /// Tiling restarts for the prefix and suffix runs and is deterministic. An
+    /// empty pattern keeps the default mask.
+    #[must_use]
+    pub fn with_mask_pattern(mut self, pattern: impl Into<Cow<'static, str>>) -> Self {
+        self.mask = MaskText::tiled(pattern.into());
+        self
+    }
+
+    /// Counts and masks extended grapheme clusters instead of scalar values
+    /// (requires the `unicode-segmentation` feature).
     ///
-    /// If `mask_prefix + mask_suffix >= total_length`, the entire value
-    /// is masked.
-    pub(crate) fn apply_to(&self, value: &str) -> String {
-        let mut chars: Vec<char> = value.chars().collect();
-        let total = chars.len();
-        if total == 0 {
-            return REDACTED_PLACEHOLDER.to_string();
-        }
+    /// Each masked cluster produces one mask, so multi-scalar glyphs are never
+    /// split between the masked and visible segments.
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn with_graphemes(mut self) -> Self {
+        self.graphemes = true;
+        self
+    }
 
-        // If mask spans cover or exceed total length, mask everything
-        if self.mask_prefix.saturating_add(self.mask_suffix) >= total {
-            chars.fill(self.mask_char);
-            return chars.into_iter().collect();
-        }
+    /// Returns the number of leading characters masked.
+    ///
+    /// [`MaskConfig::all`] reports `usize::MAX`.
+    #[must_use]
+    pub fn mask_prefix(&self) -> usize {
+        self.mask_prefix
+    }
 
-        // Mask the prefix portion
-        for ch in &mut chars[..self.mask_prefix] {
-            *ch = self.mask_char;
-        }
+    /// Returns the number of trailing characters masked.
+    #[must_use]
+    pub fn mask_suffix(&self) -> usize {
+        self.mask_suffix
+    }
 
-        // Mask the suffix portion
-        if self.mask_suffix > 0 {
-            let start = total - self.mask_suffix;
-            for ch in &mut chars[start..] {
-                *ch = self.mask_char;
-            }
+    /// Returns the masking character, or `None` when the mask is a longer
+    /// string or a tiled pattern.
+    #[must_use]
+    pub fn mask_char(&self) -> Option<char> {
+        self.mask.as_char()
+    }
+
+    /// Returns the text written once per masked scalar value, or `None` when
+    /// the mask is a tiled pattern.
+    #[must_use]
+    pub fn mask_str(&self) -> Option<&str> {
+        self.mask.as_repeated()
+    }
+
+    /// Returns the tiled mask pattern set by [`MaskConfig::with_mask_pattern`].
+    #[must_use]
+    pub fn mask_pattern(&self) -> Option<&str> {
+        self.mask.as_pattern()
+    }
+
+    /// Returns whether grapheme clusters, rather than scalar values, are counted.
+    #[must_use]
+    pub fn counts_graphemes(&self) -> bool {
+        self.graphemes
+    }
+
+    /// Sets the masking character in place.
+    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
+        self.mask = MaskText::from_char(mask_char);
+    }
+
+    /// Sets the mask string in place.
+    pub(crate) fn set_mask_str(&mut self, mask: Cow<'static, str>) {
+        self.mask = MaskText::repeated(mask);
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Empty strings are fully redacted using [`REDACTED_PLACEHOLDER`].
+    ///
+    /// If `mask_prefix + mask_suffix >= total_length`, the entire value
+    /// is masked.
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        let units = mask_units(value, self.graphemes);
+        let total = units.len();
+        if total == 0 {
+            return REDACTED_PLACEHOLDER.to_string();
+        }
+
+        let mut result = String::with_capacity(value.len());
+
+        // If mask spans cover or exceed total length, mask everything
+        if self.mask_prefix.saturating_add(self.mask_suffix) >= total {
+            self.mask.push_repeated(&mut result, total);
+            return result;
         }
 
-        chars.into_iter().collect()
+        // Mask the prefix and suffix portions, keeping the middle unchanged
+        let suffix_start = total - self.mask_suffix;
+        self.mask.push_repeated(&mut result, self.mask_prefix);
+        result.extend(units[self.mask_prefix..suffix_start].iter().copied());
+        self.mask.push_repeated(&mut result, self.mask_suffix);
+        result
     }
 }
 
@@ -210,12 +900,19 @@ impl MaskConfig {
 /// Masks the local part (before `@`) while preserving the domain. If no `@` is
 /// present, the input is masked like a prefix-keep policy. Fails closed: local
 /// parts at or below the keep length are fully masked rather than revealed.
+///
+/// [`EmailConfig::mask_domain_labels`] additionally masks leading domain
+/// labels, always keeping the top-level domain.
 #[derive(Clone, Copy, Debug)]
 pub struct EmailConfig {
     /// Number of leading characters of the local part to keep visible.
     visible_prefix: usize,
-    /// Symbol used to mask the local part.
+    /// Number of leading domain labels to mask.
+    masked_domain_labels: usize,
+    /// Symbol used to mask the local part and domain labels.
     mask_char: char,
+    /// Whether grapheme clusters, rather than scalar values, are counted.
+    graphemes: bool,
 }
 
 impl EmailConfig {
@@ -224,10 +921,35 @@ impl EmailConfig {
     pub fn new(visible_prefix: usize) -> Self {
         Self {
             visible_prefix,
+            masked_domain_labels: 0,
             mask_char: MASK_CHAR,
+            graphemes: false,
         }
     }
 
+    /// Masks the first `labels` dot-separated labels of the domain.
+    ///
+    /// The top-level domain is never masked, so `alice@mail.corp.com` with
+    /// `labels = 1` becomes `al***@****.corp.com`, and any larger count masks
+    /// every label before it. A domain without a dot is masked entirely when
+    /// `labels > 0`. Defaults to `0`, which keeps the whole domain.
+    #[must_use]
+    pub fn mask_domain_labels(mut self, labels: usize) -> Self {
+        self.masked_domain_labels = labels;
+        self
+    }
+
+    /// Counts and masks extended grapheme clusters instead of scalar values
+    /// (requires the `unicode-segmentation` feature).
+    ///
+    /// Applies to the local part and masked domain labels alike.
+    #[cfg(feature = "unicode-segmentation")]
+    #[must_use]
+    pub fn with_graphemes(mut self) -> Self {
+        self.graphemes = true;
+        self
+    }
+
     /// Uses a specific masking character.
     #[must_use]
     pub fn with_mask_char(mut self, mask_char: char) -> Self {
@@ -235,425 +957,2816 @@ impl EmailConfig {
         self
     }
 
+    /// Returns the number of leading local-part characters kept visible.
+    #[must_use]
+    pub fn visible_prefix(&self) -> usize {
+        self.visible_prefix
+    }
+
+    /// Returns the number of leading domain labels masked.
+    #[must_use]
+    pub fn masked_domain_labels(&self) -> usize {
+        self.masked_domain_labels
+    }
+
+    /// Returns the masking character.
+    #[must_use]
+    pub fn mask_char(&self) -> char {
+        self.mask_char
+    }
+
+    /// Returns whether grapheme clusters, rather than scalar values, are counted.
+    #[must_use]
+    pub fn counts_graphemes(&self) -> bool {
+        self.graphemes
+    }
+
     /// Sets the masking character in place.
     pub(crate) fn set_mask_char(&mut self, mask_char: char) {
         self.mask_char = mask_char;
     }
 
+    /// Masks the configured leading labels of `domain` (without the `@`).
+    fn mask_domain(&self, domain: &str) -> String {
+        let mask = |label: &str| -> String {
+            std::iter::repeat_n(self.mask_char, mask_units(label, self.graphemes).len()).collect()
+        };
+        let Some(tld_start) = domain.rfind('.') else {
+            return mask(domain);
+        };
+
+        let (labels, tld) = domain.split_at(tld_start);
+        let masked: Vec<String> = labels
+            .split('.')
+            .enumerate()
+            .map(|(index, label)| {
+                if index < self.masked_domain_labels {
+                    mask(label)
+                } else {
+                    label.to_string()
+                }
+            })
+            .collect();
+        format!("{}{tld}", masked.join("."))
+    }
+
     /// Applies the policy to an email address.
     ///
     /// If there's no `@`, the value is masked like a prefix-keep policy.
     pub(crate) fn apply_to(&self, value: &str) -> String {
-        let chars: Vec<char> = value.chars().collect();
-        let total = chars.len();
-        if total == 0 {
+        if value.is_empty() {
             return REDACTED_PLACEHOLDER.to_string();
         }
 
         // Split on the LAST '@': quoted local parts may contain '@' (e.g.
         // `"a@b"@example.com`), and the domain never does, so rfind keeps the
         // whole local part inside the masked segment.
-        if let Some(at_pos) = value.rfind('@') {
-            let local = &value[..at_pos];
-            let domain = &value[at_pos..]; // includes the @
-
-            let local_chars: Vec<char> = local.chars().collect();
-            let local_len = local_chars.len();
-
-            // Fail closed: a keep window covering the whole local part would
-            // otherwise reveal it completely.
-            if self.visible_prefix >= local_len {
-                let masked: String = std::iter::repeat_n(self.mask_char, local_len).collect();
-                return format!("{masked}{domain}");
+        let (local, domain) = match value.rfind('@') {
+            Some(at_pos) if self.masked_domain_labels == 0 => {
+                (&value[..at_pos], value[at_pos..].to_string()) // includes the @
             }
+            Some(at_pos) => (
+                &value[..at_pos],
+                format!("@{}", self.mask_domain(&value[at_pos + 1..])),
+            ),
+            None => (value, String::new()),
+        };
+
+        let units = mask_units(local, self.graphemes);
+        // Fail closed: a keep window covering the whole local part (or the
+        // whole value, without an `@`) would otherwise reveal it completely.
+        let visible = if self.visible_prefix >= units.len() {
+            0
+        } else {
+            self.visible_prefix
+        };
+
+        let mut result = String::with_capacity(value.len());
+        result.extend(units[..visible].iter().copied());
+        result.extend(std::iter::repeat_n(self.mask_char, units.len() - visible));
+        result.push_str(&domain);
+        result
+    }
+}
 
-            let visible: String = local_chars[..self.visible_prefix].iter().collect();
-            let masked_count = local_len - self.visible_prefix;
-            let masked: String = std::iter::repeat_n(self.mask_char, masked_count).collect();
+/// Which side of the delimiter a [`DelimiterConfig`] keeps visible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DelimiterSide {
+    /// Keep the last delimiter and everything after it.
+    AfterLast,
+    /// Keep everything up to and including the first delimiter.
+    BeforeFirst,
+}
 
-            format!("{visible}{masked}{domain}")
-        } else {
-            let mut result = chars;
-            // Fail closed: mask everything when the keep window covers the value.
-            if self.visible_prefix >= total {
-                result.fill(self.mask_char);
-                return result.into_iter().collect();
-            }
+/// Configuration that keeps the non-secret side of a delimiter visible.
+///
+/// This generalizes the email local-part split to other `prefix<delim>suffix`
+/// shapes such as `user:pass`, URL paths, or `tenant/key` identifiers. The
+/// delimiter itself always stays visible; the other side is masked one mask
+/// character per Unicode scalar value.
+///
+/// Fails closed: if the delimiter is absent, the entire value is masked.
+///
+/// Use the constructor methods [`DelimiterConfig::after_last`] and
+/// [`DelimiterConfig::before_first`] to create instances.
+#[derive(Clone, Copy, Debug)]
+pub struct DelimiterConfig {
+    /// Delimiter separating the visible and masked segments.
+    delimiter: char,
+    /// Which side of the delimiter remains visible.
+    side: DelimiterSide,
+    /// Symbol used to mask the hidden segment.
+    mask_char: char,
+}
 
-            for ch in &mut result[self.visible_prefix..] {
-                *ch = self.mask_char;
-            }
-            result.into_iter().collect()
+impl DelimiterConfig {
+    /// Keeps the last `delimiter` and everything after it, masking what precedes it.
+    #[must_use]
+    pub fn after_last(delimiter: char) -> Self {
+        Self {
+            delimiter,
+            side: DelimiterSide::AfterLast,
+            mask_char: MASK_CHAR,
+        }
+    }
+
+    /// Keeps everything up to and including the first `delimiter`, masking what follows it.
+    #[must_use]
+    pub fn before_first(delimiter: char) -> Self {
+        Self {
+            delimiter,
+            side: DelimiterSide::BeforeFirst,
+            mask_char: MASK_CHAR,
+        }
+    }
+
+    /// Uses a specific masking character.
+    #[must_use]
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = mask_char;
+        self
+    }
+
+    /// Sets the masking character in place.
+    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
+        self.mask_char = mask_char;
+    }
+
+    /// Applies the policy to a string value.
+    ///
+    /// Empty strings are fully redacted using [`REDACTED_PLACEHOLDER`]. If the
+    /// delimiter is absent, every scalar value is masked.
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        if value.is_empty() {
+            return REDACTED_PLACEHOLDER.to_string();
+        }
+
+        let mask = |segment: &str| -> String {
+            std::iter::repeat_n(self.mask_char, segment.chars().count()).collect()
+        };
+
+        // The split index falls just before the kept delimiter (after-last) or
+        // just after it (before-first), so the delimiter is always visible.
+        let split = match self.side {
+            DelimiterSide::AfterLast => value.rfind(self.delimiter),
+            DelimiterSide::BeforeFirst => value
+                .find(self.delimiter)
+                .map(|pos| pos + self.delimiter.len_utf8()),
+        };
+        let Some(split) = split else {
+            return mask(value);
+        };
+
+        let (head, tail) = value.split_at(split);
+        match self.side {
+            DelimiterSide::AfterLast => format!("{}{tail}", mask(head)),
+            DelimiterSide::BeforeFirst => format!("{head}{}", mask(tail)),
         }
     }
 }
 
-/// A redaction strategy for string-like values.
+/// Configuration that replaces the last delimiter-separated segment.
 ///
-/// All strategies operate on Unicode scalar values and return an owned `String`.
-// Use `Cow` so callers can provide borrowed or owned placeholders.
+/// Suited to structured tokens whose leading segments are safe to show while
+/// the trailing one is secret, such as a JWT's `header.payload.signature`.
+/// The leading segments and delimiters stay untouched; the last segment is
+/// replaced with a placeholder.
+///
+/// Fails closed: values without exactly the expected number of segments are
+/// fully redacted with the placeholder.
 #[derive(Clone, Debug)]
-pub enum TextRedactionPolicy {
-    /// Replace the entire value with a fixed placeholder.
-    Full {
-        /// The placeholder text to use.
-        placeholder: Cow<'static, str>,
-    },
-    /// Keep configured segments visible while masking everything else.
-    Keep(KeepConfig),
-    /// Mask configured segments while leaving the remainder untouched.
-    Mask(MaskConfig),
-    /// Email-specific: mask local part while preserving domain.
-    Email(EmailConfig),
+pub struct SegmentConfig {
+    /// Delimiter separating the segments.
+    delimiter: char,
+    /// Exact number of segments a value must have to be partially kept.
+    segments: usize,
+    /// Replacement for the last segment, and for malformed values.
+    placeholder: Cow<'static, str>,
 }
 
-impl TextRedactionPolicy {
-    /// Constructs [`TextRedactionPolicy::Full`] using [`REDACTED_PLACEHOLDER`].
+impl SegmentConfig {
+    /// Replaces the last of exactly `segments` `delimiter`-separated segments.
     #[must_use]
-    pub fn default_full() -> Self {
-        Self::Full {
+    pub fn last_of(delimiter: char, segments: usize) -> Self {
+        Self {
+            delimiter,
+            segments,
             placeholder: Cow::Borrowed(REDACTED_PLACEHOLDER),
         }
     }
 
-    /// Constructs [`TextRedactionPolicy::Full`] using a custom placeholder.
+    /// Uses a specific placeholder instead of [`REDACTED_PLACEHOLDER`].
     #[must_use]
-    pub fn full_with<P>(placeholder: P) -> Self
-    where
-        P: Into<Cow<'static, str>>,
-    {
-        Self::Full {
-            placeholder: placeholder.into(),
-        }
+    pub fn with_placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = placeholder.into();
+        self
     }
 
-    /// Constructs [`TextRedactionPolicy::Keep`] from an explicit configuration.
-    #[must_use]
-    pub fn keep_with(config: KeepConfig) -> Self {
-        Self::Keep(config)
+    /// Applies the policy to a string value.
+    ///
+    /// Values whose segment count differs from the configured one, including
+    /// empty strings, become the placeholder.
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        self.try_apply_to(value)
+            .unwrap_or_else(|_| self.placeholder.clone().into_owned())
     }
 
-    /// Keeps only the first `visible_prefix` scalar values in clear text.
-    #[must_use]
-    pub fn keep_first(visible_prefix: usize) -> Self {
-        Self::keep_with(KeepConfig::first(visible_prefix))
+    /// Applies the policy, rejecting values with the wrong segment count.
+    pub(crate) fn try_apply_to(&self, value: &str) -> Result<String, RedactionError> {
+        if value.is_empty() || value.split(self.delimiter).count() != self.segments {
+            return Err(RedactionError::new("unexpected number of segments"));
+        }
+
+        // The count check guarantees a delimiter when more than one segment is
+        // expected; a single-segment value is replaced whole.
+        let kept = value
+            .rfind(self.delimiter)
+            .map_or("", |split| &value[..split + self.delimiter.len_utf8()]);
+        Ok(format!("{kept}{}", self.placeholder))
     }
+}
 
-    /// Keeps only the last `visible_suffix` scalar values in clear text.
+/// Configuration that caps a value's length without masking it.
+///
+/// Meant for noisy rather than secret text (user agents, URLs): the first
+/// `max_len` Unicode scalar values stay visible and an ellipsis (`…`) marks
+/// that the rest was dropped. Values of at most `max_len` scalar values are
+/// returned unchanged.
+#[derive(Clone, Copy, Debug)]
+pub struct TruncateConfig {
+    /// Number of leading scalar values kept before the ellipsis.
+    max_len: usize,
+}
+
+impl TruncateConfig {
+    /// Keeps at most `max_len` scalar values.
     #[must_use]
-    pub fn keep_last(visible_suffix: usize) -> Self {
-        Self::keep_with(KeepConfig::last(visible_suffix))
+    pub fn new(max_len: usize) -> Self {
+        Self { max_len }
     }
 
-    /// Masks segments using the provided configuration.
-    #[must_use]
-    pub fn mask_with(config: MaskConfig) -> Self {
-        Self::Mask(config)
+    /// Byte offset where the value is cut, or `None` if it fits the cap.
+    fn cut_at(self, value: &str) -> Option<usize> {
+        value
+            .char_indices()
+            .nth(self.max_len)
+            .map(|(index, _)| index)
     }
 
-    /// Masks the first `mask_prefix` scalar values.
+    /// Applies the policy to a string value.
+    pub(crate) fn apply_to(self, value: &str) -> String {
+        let Some(index) = self.cut_at(value) else {
+            return value.to_string();
+        };
+        format!("{}{ELLIPSIS}", &value[..index])
+    }
+}
+
+/// Longest country calling code (ITU-T E.164 codes have 1 to 3 digits).
+const MAX_COUNTRY_CODE_DIGITS: usize = 3;
+
+/// Configuration for phone number redaction.
+///
+/// Keeps the last `visible_suffix` scalar values visible and, by default, a
+/// leading `+` followed by a 1–3 digit country code (e.g. `+44`), masking the
+/// rest one mask character per Unicode scalar value. The country code is only
+/// recognized when a non-digit separator follows it (`+1-555…`, `+44 20…`);
+/// for unseparated international numbers only the `+` is kept, since the code
+/// length cannot be known.
+///
+/// [`PhoneConfig::with_e164_normalization`] strips formatting before masking
+/// so differently formatted copies of one number redact identically.
+///
+/// Fails closed: when the remainder after the country code is at or below the
+/// keep length, it is fully masked.
+#[derive(Clone, Copy, Debug)]
+pub struct PhoneConfig {
+    /// Number of trailing characters to keep visible.
+    visible_suffix: usize,
+    /// Whether a leading `+` and country code stay visible.
+    keep_country_code: bool,
+    /// Whether spaces, parentheses, dots, and dashes are stripped before masking.
+    normalize: bool,
+    /// Symbol used to mask the hidden segment.
+    mask_char: char,
+}
+
+impl PhoneConfig {
+    /// Creates a config that keeps the country code and the last `visible_suffix` chars.
     #[must_use]
-    pub fn mask_first(mask_prefix: usize) -> Self {
-        Self::mask_with(MaskConfig::first(mask_prefix))
+    pub fn new(visible_suffix: usize) -> Self {
+        Self {
+            visible_suffix,
+            keep_country_code: true,
+            normalize: false,
+            mask_char: MASK_CHAR,
+        }
     }
 
-    /// Masks the last `mask_suffix` scalar values.
+    /// Sets whether the leading `+` and country code stay visible.
+    ///
+    /// With `false`, the policy behaves like
+    /// [`TextRedactionPolicy::keep_last`] and masks the country code too.
     #[must_use]
-    pub fn mask_last(mask_suffix: usize) -> Self {
-        Self::mask_with(MaskConfig::last(mask_suffix))
+    pub fn with_country_code(mut self, keep_country_code: bool) -> Self {
+        self.keep_country_code = keep_country_code;
+        self
     }
 
-    /// Email-specific policy: keeps first `visible_prefix` chars of local part, preserves domain.
+    /// Sets whether formatting is stripped before masking, as in E.164.
+    ///
+    /// With `true`, spaces, parentheses, dots, and dashes are removed before
+    /// masking, so `(555) 123-4567` and `555-123-4567` both redact to
+    /// `******4567`. The normalized number has no separator after the country
+    /// code, so only the leading `+` stays visible: `+1 555 123 4567` and
+    /// `+15551234567` both redact to `+*******4567`.
     ///
-    /// # Example
     /// ```
-    /// use redactable::TextRedactionPolicy;
+    /// use redactable::{PhoneConfig, TextRedactionPolicy};
     ///
-    /// let policy = TextRedactionPolicy::email_local(2);
-    /// assert_eq!(policy.apply_to("alice@example.com"), "al***@example.com");
-    /// assert_eq!(policy.apply_to("bob@company.io"), "bo*@company.io");
+    /// let policy =
+    ///     TextRedactionPolicy::phone_with(PhoneConfig::new(4).with_e164_normalization(true));
+    /// assert_eq!(policy.apply_to("+1 (555) 123-4567"), "+*******4567");
+    /// assert_eq!(policy.apply_to("+15551234567"), "+*******4567");
     /// ```
     #[must_use]
-    pub fn email_local(visible_prefix: usize) -> Self {
-        Self::Email(EmailConfig::new(visible_prefix))
+    pub fn with_e164_normalization(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
     }
 
-    /// Overrides the masking character used by keep/mask/email policies.
-    ///
-    /// This method has no effect on [`TextRedactionPolicy::Full`] because full
-    /// redaction replaces the entire value with a placeholder string rather
-    /// than masking individual characters.
+    /// Uses a specific masking character.
     #[must_use]
     pub fn with_mask_char(mut self, mask_char: char) -> Self {
-        match &mut self {
-            TextRedactionPolicy::Full { .. } => {}
-            TextRedactionPolicy::Keep(config) => {
-                config.set_mask_char(mask_char);
-            }
-            TextRedactionPolicy::Mask(config) => {
-                config.set_mask_char(mask_char);
-            }
-            TextRedactionPolicy::Email(config) => {
-                config.set_mask_char(mask_char);
-            }
-        }
+        self.mask_char = mask_char;
         self
     }
 
-    /// Applies the policy to `value`.
-    ///
-    /// This method is total (it does not return errors).
-    #[must_use]
-    pub fn apply_to(&self, value: &str) -> String {
-        match self {
-            TextRedactionPolicy::Full { placeholder } => placeholder.clone().into_owned(),
-            TextRedactionPolicy::Keep(config) => config.apply_to(value),
-            TextRedactionPolicy::Mask(config) => config.apply_to(value),
-            TextRedactionPolicy::Email(config) => config.apply_to(value),
-        }
+    /// Sets the masking character in place.
+    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
+        self.mask_char = mask_char;
     }
-}
 
-impl std::default::Default for TextRedactionPolicy {
-    fn default() -> Self {
-        Self::default_full()
+    /// Returns whether `ch` is formatting removed by E.164 normalization.
+    fn is_formatting(ch: char) -> bool {
+        ch.is_whitespace() || matches!(ch, '(' | ')' | '-' | '.')
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::{KeepConfig, MaskConfig, REDACTED_PLACEHOLDER, TextRedactionPolicy};
+    /// Returns the byte length of the leading `+` and country code, if present.
+    fn country_code_len(value: &str) -> usize {
+        let Some(rest) = value.strip_prefix('+') else {
+            return 0;
+        };
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let separated = rest.len() > digits;
+        if (1..=MAX_COUNTRY_CODE_DIGITS).contains(&digits) && separated {
+            1 + digits
+        } else {
+            1
+        }
+    }
 
-    #[test]
-    fn keep_policy_masks_short_values() {
-        // Fail closed: values at or below the keep window are fully masked.
-        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(3));
-        assert_eq!(policy.apply_to("ab"), "**");
-        assert_eq!(policy.apply_to("abc"), "***");
+    /// Applies the policy to a phone number.
+    ///
+    /// Empty strings are fully redacted using [`REDACTED_PLACEHOLDER`].
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        // Normalizing first yields the unseparated E.164 form, so every
+        // formatting of a number detects the same (bare `+`) prefix.
+        let value: Cow<'_, str> = if self.normalize {
+            Cow::Owned(
+                value
+                    .chars()
+                    .filter(|ch| !Self::is_formatting(*ch))
+                    .collect(),
+            )
+        } else {
+            Cow::Borrowed(value)
+        };
+        if value.is_empty() {
+            return REDACTED_PLACEHOLDER.to_string();
+        }
 
-        // A 4-char token under keep-last-4 must not be revealed.
-        let policy = TextRedactionPolicy::keep_last(4);
-        assert_eq!(policy.apply_to("1234"), "****");
-        assert_eq!(policy.apply_to("12345"), "*2345");
+        let prefix_len = if self.keep_country_code {
+            Self::country_code_len(&value)
+        } else {
+            0
+        };
+        let (prefix, rest) = value.split_at(prefix_len);
+        let mut chars: Vec<char> = rest.chars().collect();
+        // Fail closed: mask everything after the prefix when the keep window
+        // covers it.
+        let masked = chars.len().saturating_sub(self.visible_suffix);
+        let masked = if masked == 0 { chars.len() } else { masked };
+        chars[..masked].fill(self.mask_char);
+
+        let mut result = String::with_capacity(value.len());
+        result.push_str(prefix);
+        result.extend(chars);
+        result
     }
+}
 
-    #[test]
-    fn keep_policy_counts_unicode_scalars_for_multibyte_inputs() {
-        let cjk = "\u{6771}\u{4eac}\u{5927}\u{962a}";
-        let policy = TextRedactionPolicy::keep_last(2);
+/// Configuration that keeps the network prefix of an IP address and masks the
+/// host segments.
+///
+/// The value is parsed as an IPv4 or IPv6 address and the configured number of
+/// trailing segments (octets for IPv4, 16-bit hextets for IPv6) are each
+/// replaced with a single mask character, so the output does not reveal their
+/// length. IPv6 output is written in full eight-segment form without `::`
+/// compression. Counts above the segment count mask the whole address.
+///
+/// Fails closed: values that do not parse as an IP address (including empty
+/// strings and addresses with ports or surrounding whitespace) are fully
+/// redacted using [`REDACTED_PLACEHOLDER`].
+#[derive(Clone, Copy, Debug)]
+pub struct IpConfig {
+    /// Number of trailing IPv4 octets to mask.
+    masked_v4_octets: usize,
+    /// Number of trailing IPv6 hextets to mask.
+    masked_v6_segments: usize,
+    /// Symbol written for each masked segment.
+    mask_char: char,
+}
+
+impl IpConfig {
+    /// Creates a config that masks the last `masked_v4_octets` IPv4 octets and
+    /// the last `masked_v6_segments` IPv6 hextets.
+    #[must_use]
+    pub fn new(masked_v4_octets: usize, masked_v6_segments: usize) -> Self {
+        Self {
+            masked_v4_octets,
+            masked_v6_segments,
+            mask_char: MASK_CHAR,
+        }
+    }
+
+    /// Uses a specific masking character.
+    #[must_use]
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = mask_char;
+        self
+    }
+
+    /// Sets the masking character in place.
+    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
+        self.mask_char = mask_char;
+    }
+
+    /// Returns the number of trailing IPv4 octets to mask.
+    #[cfg(feature = "ip-address")]
+    pub(crate) fn masked_v4_octets(&self) -> usize {
+        self.masked_v4_octets
+    }
+
+    /// Returns the number of trailing IPv6 hextets to mask.
+    #[cfg(feature = "ip-address")]
+    pub(crate) fn masked_v6_segments(&self) -> usize {
+        self.masked_v6_segments
+    }
+
+    /// Joins `segments`, masking all but the first `visible` of them.
+    fn join_segments<I>(&self, segments: I, visible: usize, separator: char) -> String
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut result = String::new();
+        for (index, segment) in segments.into_iter().enumerate() {
+            if index > 0 {
+                result.push(separator);
+            }
+            if index < visible {
+                result.push_str(&segment);
+            } else {
+                result.push(self.mask_char);
+            }
+        }
+        result
+    }
+
+    /// Applies the policy to a string holding an IP address.
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        self.try_apply_to(value)
+            .unwrap_or_else(|_| REDACTED_PLACEHOLDER.to_string())
+    }
+
+    /// Applies the policy, rejecting values that do not parse as an IP address.
+    pub(crate) fn try_apply_to(&self, value: &str) -> Result<String, RedactionError> {
+        match value.parse::<std::net::IpAddr>() {
+            Ok(std::net::IpAddr::V4(addr)) => Ok(self.join_segments(
+                addr.octets().map(|octet| octet.to_string()),
+                4usize.saturating_sub(self.masked_v4_octets),
+                '.',
+            )),
+            Ok(std::net::IpAddr::V6(addr)) => Ok(self.join_segments(
+                addr.segments().map(|segment| format!("{segment:x}")),
+                8usize.saturating_sub(self.masked_v6_segments),
+                ':',
+            )),
+            Err(_) => Err(RedactionError::new("not an IP address")),
+        }
+    }
+}
+
+impl Default for IpConfig {
+    /// Masks the last two IPv4 octets and the last five IPv6 hextets, keeping
+    /// a `/16` IPv4 or `/48` IPv6 network prefix.
+    fn default() -> Self {
+        Self::new(2, 5)
+    }
+}
+
+/// Configuration that keeps only the year of a calendar date.
+///
+/// Recognizes ISO `YYYY-MM-DD` and US `MM/DD/YYYY` dates and masks the month
+/// and day digits in place, so `1990-07-14` becomes `1990-**-**` and
+/// `07/14/1990` becomes `**/**/1990`.
+///
+/// Fails closed: any other shape, an out-of-range month or day (`13`, `00`,
+/// `32`), and surrounding whitespace are fully redacted using
+/// [`REDACTED_PLACEHOLDER`]. Days are not checked against the month length.
+#[derive(Clone, Copy, Debug)]
+pub struct DateConfig {
+    /// Symbol written for each masked month and day digit.
+    mask_char: char,
+}
+
+impl DateConfig {
+    /// Creates a config that keeps the year and masks the month and day.
+    #[must_use]
+    pub fn year_only() -> Self {
+        Self {
+            mask_char: MASK_CHAR,
+        }
+    }
+
+    /// Uses a specific masking character.
+    #[must_use]
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = mask_char;
+        self
+    }
+
+    /// Sets the masking character in place.
+    pub(crate) fn set_mask_char(&mut self, mask_char: char) {
+        self.mask_char = mask_char;
+    }
+
+    /// Byte range holding the month and day of a recognized date.
+    fn month_and_day(value: &str) -> Option<std::ops::Range<usize>> {
+        let bytes = value.as_bytes();
+        if bytes.len() != 10 {
+            return None;
+        }
+        // Start offsets of the year, month, and day, plus the separator
+        // positions, for `YYYY-MM-DD` and `MM/DD/YYYY`.
+        let (year, month, day, separators) = if bytes[4] == b'-' {
+            (0, 5, 8, [(4, b'-'), (7, b'-')])
+        } else {
+            (6, 0, 3, [(2, b'/'), (5, b'/')])
+        };
+        if separators
+            .iter()
+            .any(|&(index, separator)| bytes[index] != separator)
+        {
+            return None;
+        }
+        let number = |start: usize, len: usize| -> Option<u32> {
+            bytes[start..start + len].iter().try_fold(0, |acc, byte| {
+                byte.is_ascii_digit()
+                    .then(|| acc * 10 + u32::from(byte - b'0'))
+            })
+        };
+        number(year, 4)?;
+        let valid_month = (1..=12).contains(&number(month, 2)?);
+        let valid_day = (1..=31).contains(&number(day, 2)?);
+        (valid_month && valid_day).then_some(month.min(day)..month.max(day) + 2)
+    }
+
+    /// Applies the policy to a string holding a date.
+    pub(crate) fn apply_to(self, value: &str) -> String {
+        self.try_apply_to(value)
+            .unwrap_or_else(|_| REDACTED_PLACEHOLDER.to_string())
+    }
+
+    /// Applies the policy, rejecting values that are not a recognized date.
+    pub(crate) fn try_apply_to(self, value: &str) -> Result<String, RedactionError> {
+        let Some(masked) = Self::month_and_day(value) else {
+            return Err(RedactionError::new("not a recognized date"));
+        };
+        Ok(value
+            .char_indices()
+            .map(|(index, ch)| {
+                if masked.contains(&index) && ch.is_ascii_digit() {
+                    self.mask_char
+                } else {
+                    ch
+                }
+            })
+            .collect())
+    }
+}
+
+/// Configuration that redacts selected keys inside a string holding a JSON document.
+///
+/// The string is parsed as JSON, every value stored under a configured object
+/// key (at any depth, compared ASCII case-insensitively) is replaced with
+/// [`REDACTED_PLACEHOLDER`], and the document is re-serialized compactly.
+///
+/// Fails closed: input that does not parse as JSON is fully redacted.
+///
+/// This is distinct from `serde_json::Value` fields, which are opaque leaves
+/// and always redact completely.
+#[cfg(feature = "json")]
+#[derive(Clone, Debug)]
+pub struct JsonStringConfig {
+    /// Object keys whose values are redacted.
+    keys: Vec<Cow<'static, str>>,
+}
+
+#[cfg(feature = "json")]
+impl JsonStringConfig {
+    /// Creates a configuration that redacts the values stored under `keys`.
+    #[must_use]
+    pub fn new<I, K>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Cow<'static, str>>,
+    {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    fn is_redacted_key(&self, key: &str) -> bool {
+        self.keys
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(key))
+    }
+
+    fn redact_value(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, entry) in map.iter_mut() {
+                    if self.is_redacted_key(key) {
+                        *entry = serde_json::Value::String(REDACTED_PLACEHOLDER.to_string());
+                    } else {
+                        self.redact_value(entry);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    self.redact_value(item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Applies the policy to a string holding a JSON document.
+    ///
+    /// Values that fail to parse (including empty strings) are replaced with
+    /// [`REDACTED_PLACEHOLDER`].
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        self.try_apply_to(value)
+            .unwrap_or_else(|_| REDACTED_PLACEHOLDER.to_string())
+    }
+
+    /// Applies the policy, rejecting values that do not parse as JSON.
+    pub(crate) fn try_apply_to(&self, value: &str) -> Result<String, RedactionError> {
+        let Ok(mut document) = serde_json::from_str::<serde_json::Value>(value) else {
+            return Err(RedactionError::new("not a JSON document"));
+        };
+        self.redact_value(&mut document);
+        Ok(document.to_string())
+    }
+}
+
+/// Configuration that replaces every match of a regular expression (requires the
+/// `regex` feature).
+///
+/// Only the matched text changes, so secrets embedded in free-form messages can
+/// be scrubbed while the surrounding text stays readable. The replacement may
+/// reference capture groups (`$1`, `$name`); write `$$` for a literal `$`.
+///
+/// Unlike the other configurations this one does not fail closed: values with
+/// no match, including empty strings, are returned unchanged. Prefer a masking
+/// or full policy when the whole value is sensitive.
+#[cfg(feature = "regex")]
+#[derive(Clone, Debug)]
+pub struct RegexConfig {
+    /// Compiled pattern, shared so cloning the policy reuses its match caches.
+    regex: Arc<regex::Regex>,
+    /// Text substituted for each match.
+    replacement: Cow<'static, str>,
+}
+
+#[cfg(feature = "regex")]
+impl RegexConfig {
+    /// Creates a configuration from a compiled pattern and its replacement.
+    #[must_use]
+    pub fn new<R>(regex: regex::Regex, replacement: R) -> Self
+    where
+        R: Into<Cow<'static, str>>,
+    {
+        Self {
+            regex: Arc::new(regex),
+            replacement: replacement.into(),
+        }
+    }
+
+    /// Replaces every match in `value`, borrowing it when nothing matches.
+    pub(crate) fn apply_to_borrowed<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        self.regex.replace_all(value, self.replacement.as_ref())
+    }
+
+    /// Applies the policy to a string value.
+    pub(crate) fn apply_to(&self, value: &str) -> String {
+        self.apply_to_borrowed(value).into_owned()
+    }
+}
+
+/// Number of hex characters in a full SHA-256 digest.
+#[cfg(feature = "hash")]
+const SHA256_HEX_LEN: usize = 64;
+
+/// Number of unpadded Base32 characters in a full SHA-256 digest.
+#[cfg(feature = "hash")]
+const SHA256_BASE32_LEN: usize = 52;
+
+/// RFC 4648 Base32 alphabet.
+#[cfg(feature = "hash")]
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Text encoding of the digest kept by a [`HashConfig`].
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HashEncoding {
+    /// Lowercase hexadecimal, 4 bits per character.
+    Hex,
+    /// Unpadded RFC 4648 Base32, 5 bits per character.
+    Base32,
+}
+
+/// Configuration that replaces a value with a prefix of its encoded SHA-256
+/// digest (requires the `hash` feature).
+///
+/// Identical inputs always produce identical output, so the same secret can be
+/// correlated across log lines without being revealed. The digest is unsalted:
+/// low-entropy values (PINs, short passwords, phone numbers) can be recovered
+/// by hashing candidate inputs, so reserve this for high-entropy secrets.
+///
+/// The digest is written as lowercase hex ([`HashConfig::new`]) or as denser
+/// unpadded Base32 ([`HashConfig::base32`]).
+///
+/// Empty strings are fully redacted using [`REDACTED_PLACEHOLDER`].
+#[cfg(feature = "hash")]
+#[derive(Clone, Copy, Debug)]
+pub struct HashConfig {
+    /// Number of encoded characters of the digest to keep.
+    prefix_len: usize,
+    /// Encoding of the kept digest characters.
+    encoding: HashEncoding,
+}
+
+#[cfg(feature = "hash")]
+impl HashConfig {
+    /// Creates a config that keeps the first `prefix_len` hex characters of the
+    /// digest.
+    ///
+    /// A length of 0 keeps one character, so non-empty inputs never collapse to
+    /// an empty string. Lengths above 64 keep the full digest.
+    #[must_use]
+    pub fn new(prefix_len: usize) -> Self {
+        Self {
+            prefix_len: prefix_len.clamp(1, SHA256_HEX_LEN),
+            encoding: HashEncoding::Hex,
+        }
+    }
+
+    /// Creates a config that keeps the first `prefix_len` Base32 characters of
+    /// the digest.
+    ///
+    /// Uses the uppercase RFC 4648 alphabet without padding. A length of 0 keeps
+    /// one character, and lengths above 52 keep the full digest.
+    #[must_use]
+    pub fn base32(prefix_len: usize) -> Self {
+        Self {
+            prefix_len: prefix_len.clamp(1, SHA256_BASE32_LEN),
+            encoding: HashEncoding::Base32,
+        }
+    }
+
+    /// Applies the policy to a string value.
+    pub(crate) fn apply_to(self, value: &str) -> String {
+        use std::fmt::Write as _;
+
+        use sha2::{Digest, Sha256};
+
+        if value.is_empty() {
+            return REDACTED_PLACEHOLDER.to_string();
+        }
+
+        let digest = Sha256::digest(value.as_bytes());
+        let mut encoded = String::with_capacity(self.prefix_len);
+        match self.encoding {
+            HashEncoding::Hex => {
+                for byte in digest {
+                    let _ = write!(encoded, "{byte:02x}");
+                }
+                encoded.truncate(self.prefix_len);
+            }
+            HashEncoding::Base32 => {
+                let mut buffer: u16 = 0;
+                let mut bits = 0;
+                for byte in digest {
+                    buffer = (buffer << 8) | u16::from(byte);
+                    bits += 8;
+                    while bits >= 5 {
+                        bits -= 5;
+                        encoded.push(char::from(
+                            BASE32_ALPHABET[usize::from((buffer >> bits) & 31)],
+                        ));
+                    }
+                }
+                if bits > 0 {
+                    encoded.push(char::from(
+                        BASE32_ALPHABET[usize::from((buffer << (5 - bits)) & 31)],
+                    ));
+                }
+                encoded.truncate(self.prefix_len);
+            }
+        }
+        encoded
+    }
+}
+
+/// Error returned when a fallible leaf cannot apply its redaction policy.
+///
+/// The message describes why the input was rejected (for example "not a
+/// card number"). It must never contain the raw input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedactionError {
+    message: Cow<'static, str>,
+}
+
+impl RedactionError {
+    /// Creates an error with a description of the rejected input.
+    #[must_use]
+    pub fn new<M>(message: M) -> Self
+    where
+        M: Into<Cow<'static, str>>,
+    {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Returns the description of the rejected input.
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl std::fmt::Display for RedactionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "redaction failed: {}", self.message)
+    }
+}
+
+impl std::error::Error for RedactionError {}
+
+/// A redaction strategy for string-like values.
+///
+/// All strategies operate on Unicode scalar values and return an owned `String`.
+///
+/// Marked `#[non_exhaustive]`: the `JsonString`, `Regex`, and `Hash` variants
+/// depend on the `json`, `regex`, and `hash` features, and any crate in the
+/// build graph can switch them on. Downstream matches must carry a wildcard
+/// arm.
+// Use `Cow` so callers can provide borrowed or owned placeholders.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum TextRedactionPolicy {
+    /// Replace the entire value with a fixed placeholder.
+    Full {
+        /// The placeholder text to use.
+        placeholder: Cow<'static, str>,
+    },
+    /// Keep configured segments visible while masking everything else.
+    Keep(KeepConfig),
+    /// Mask configured segments while leaving the remainder untouched.
+    Mask(MaskConfig),
+    /// Email-specific: mask local part while preserving domain.
+    Email(EmailConfig),
+    /// Keep one side of a delimiter visible while masking the other.
+    Delimited(DelimiterConfig),
+    /// Keep leading delimiter-separated segments and replace the last one.
+    Segmented(SegmentConfig),
+    /// Keep a leading prefix and drop the rest behind an ellipsis.
+    Truncate(TruncateConfig),
+    /// Phone-specific: keep the country code and trailing digits visible.
+    Phone(PhoneConfig),
+    /// IP-specific: keep the network prefix and mask host segments.
+    Ip(IpConfig),
+    /// Date-specific: keep the year and mask the month and day.
+    Date(DateConfig),
+    /// Redact selected keys inside a JSON document (requires the `json` feature).
+    #[cfg(feature = "json")]
+    JsonString(JsonStringConfig),
+    /// Replace every match of a regular expression (requires the `regex` feature).
+    #[cfg(feature = "regex")]
+    Regex(RegexConfig),
+    /// Replace the value with a SHA-256 digest prefix (requires the `hash` feature).
+    #[cfg(feature = "hash")]
+    Hash(HashConfig),
+    /// Apply the first policy, then the second to its output.
+    Chain(Box<TextRedactionPolicy>, Box<TextRedactionPolicy>),
+    /// Replace the value with the output of a caller-supplied function.
+    Custom(fn(&str) -> String),
+}
+
+impl TextRedactionPolicy {
+    /// Constructs [`TextRedactionPolicy::Full`] using [`REDACTED_PLACEHOLDER`].
+    #[must_use]
+    pub fn default_full() -> Self {
+        Self::Full {
+            placeholder: Cow::Borrowed(REDACTED_PLACEHOLDER),
+        }
+    }
+
+    /// Constructs [`TextRedactionPolicy::Full`] using a custom placeholder.
+    #[must_use]
+    pub fn full_with<P>(placeholder: P) -> Self
+    where
+        P: Into<Cow<'static, str>>,
+    {
+        Self::Full {
+            placeholder: placeholder.into(),
+        }
+    }
+
+    /// Replaces every scalar value with the mask character, preserving length.
+    ///
+    /// Unlike [`TextRedactionPolicy::default_full`], the output keeps the
+    /// input's width, which fixed-column log formats rely on. Empty strings
+    /// still redact to [`REDACTED_PLACEHOLDER`], and
+    /// [`TextRedactionPolicy::with_mask_char`] changes the mask character.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::full_masked();
+    /// assert_eq!(policy.apply_to("tok_0123456789ab"), "****************");
+    /// assert_eq!(policy.apply_to(""), "[REDACTED]");
+    /// ```
+    #[must_use]
+    pub fn full_masked() -> Self {
+        Self::mask_with(MaskConfig::all())
+    }
+
+    /// Like [`TextRedactionPolicy::full_masked`], masking with `mask_char`.
+    ///
+    /// This is the full-redaction form that honors a mask character: the
+    /// [`TextRedactionPolicy::Full`] placeholder is fixed text, so
+    /// [`TextRedactionPolicy::with_mask_char`] leaves it unchanged, while this
+    /// policy writes `mask_char` once per scalar value of the input. Empty
+    /// strings still redact to [`REDACTED_PLACEHOLDER`], and a later
+    /// `with_mask_char` call replaces `mask_char`.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::full_masked_with_char('#');
+    /// assert_eq!(policy.apply_to("hunter2"), "#######");
+    /// assert_eq!(policy.apply_to(""), "[REDACTED]");
+    /// ```
+    #[must_use]
+    pub fn full_masked_with_char(mask_char: char) -> Self {
+        Self::mask_with(MaskConfig::all().with_mask_char(mask_char))
+    }
+
+    /// Masks every scalar value by tiling `pattern`, preserving length.
+    ///
+    /// Output still "looks like" a value of the same length (realistic
+    /// screenshots and fixtures) without revealing any of it: position `i`
+    /// becomes the pattern's `i % len`th scalar value. Empty strings redact to
+    /// [`REDACTED_PLACEHOLDER`], and an empty pattern falls back to `*`.
+    /// [`TextRedactionPolicy::with_mask_char`] or
+    /// [`TextRedactionPolicy::with_mask_str`] replace the pattern.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::placeholder_pattern("Xx9");
+    /// assert_eq!(policy.apply_to("0123456789"), "Xx9Xx9Xx9X");
+    /// ```
+    #[must_use]
+    pub fn placeholder_pattern(pattern: impl Into<Cow<'static, str>>) -> Self {
+        Self::mask_with(MaskConfig::all().with_mask_pattern(pattern))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Keep`] from an explicit configuration.
+    #[must_use]
+    pub fn keep_with(config: KeepConfig) -> Self {
+        Self::Keep(config)
+    }
+
+    /// Keeps only the first `visible_prefix` scalar values in clear text.
+    #[must_use]
+    pub fn keep_first(visible_prefix: usize) -> Self {
+        Self::keep_with(KeepConfig::first(visible_prefix))
+    }
+
+    /// Keeps only the last `visible_suffix` scalar values in clear text.
+    #[must_use]
+    pub fn keep_last(visible_suffix: usize) -> Self {
+        Self::keep_with(KeepConfig::last(visible_suffix))
+    }
+
+    /// Keeps only the final scalar value, such as an account's check digit.
+    ///
+    /// Equivalent to `keep_last(1)`, named for the intent: one trailing
+    /// character is enough to tell similar values apart while debugging.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_checksum();
+    /// assert_eq!(policy.apply_to("79927398713"), "**********3");
+    /// ```
+    #[must_use]
+    pub fn keep_checksum() -> Self {
+        Self::keep_last(1)
+    }
+
+    /// Keeps `visible_len` scalar values after masking the first `skip_prefix`,
+    /// and masks the remainder.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_middle(6, 4);
+    /// assert_eq!(policy.apply_to("order-2024-XYZ-batch"), "******2024**********");
+    /// ```
+    #[must_use]
+    pub fn keep_middle(skip_prefix: usize, visible_len: usize) -> Self {
+        Self::keep_with(KeepConfig::middle(skip_prefix, visible_len))
+    }
+
+    /// Keeps `ratio` of each value visible, split between prefix and suffix.
+    ///
+    /// See [`KeepConfig::ratio`] for how the visible count is computed.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_ratio(0.25);
+    /// assert_eq!(policy.apply_to("abcdefghijklmnopqrst"), "ab***************rst");
+    /// assert_eq!(policy.apply_to("a"), "*");
+    /// ```
+    #[must_use]
+    pub fn keep_ratio(ratio: f32) -> Self {
+        Self::keep_with(KeepConfig::ratio(ratio))
+    }
+
+    /// Masks segments using the provided configuration.
+    #[must_use]
+    pub fn mask_with(config: MaskConfig) -> Self {
+        Self::Mask(config)
+    }
+
+    /// Masks the first `mask_prefix` scalar values.
+    #[must_use]
+    pub fn mask_first(mask_prefix: usize) -> Self {
+        Self::mask_with(MaskConfig::first(mask_prefix))
+    }
+
+    /// Masks the last `mask_suffix` scalar values.
+    #[must_use]
+    pub fn mask_last(mask_suffix: usize) -> Self {
+        Self::mask_with(MaskConfig::last(mask_suffix))
+    }
+
+    /// Email-specific policy: keeps first `visible_prefix` chars of local part, preserves domain.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::email_local(2);
+    /// assert_eq!(policy.apply_to("alice@example.com"), "al***@example.com");
+    /// assert_eq!(policy.apply_to("bob@company.io"), "bo*@company.io");
+    /// ```
+    #[must_use]
+    pub fn email_local(visible_prefix: usize) -> Self {
+        Self::Email(EmailConfig::new(visible_prefix))
+    }
+
+    /// Email-specific policy that masks the whole local part and keeps only the
+    /// domain, for analytics that group by provider.
+    ///
+    /// Equivalent to `email_local(0)`. A value without an `@` has no domain to
+    /// keep and is masked entirely.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::email_domain_only();
+    /// assert_eq!(policy.apply_to("alice@example.com"), "*****@example.com");
+    /// assert_eq!(policy.apply_to("alice"), "*****");
+    /// ```
+    #[must_use]
+    pub fn email_domain_only() -> Self {
+        Self::email_local(0)
+    }
+
+    /// Constructs [`TextRedactionPolicy::Delimited`] from an explicit configuration.
+    #[must_use]
+    pub fn delimited_with(config: DelimiterConfig) -> Self {
+        Self::Delimited(config)
+    }
+
+    /// Keeps the last `delimiter` and everything after it, masking what precedes it.
+    ///
+    /// Values without the delimiter are fully masked.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_after_last('@');
+    /// assert_eq!(policy.apply_to("alice@example.com"), "*****@example.com");
+    /// assert_eq!(policy.apply_to("no-delimiter"), "************");
+    /// ```
+    #[must_use]
+    pub fn keep_after_last(delimiter: char) -> Self {
+        Self::delimited_with(DelimiterConfig::after_last(delimiter))
+    }
+
+    /// Keeps everything up to and including the first `delimiter`, masking what follows it.
+    ///
+    /// Values without the delimiter are fully masked.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_before_first(':');
+    /// assert_eq!(policy.apply_to("admin:hunter2"), "admin:*******");
+    /// ```
+    #[must_use]
+    pub fn keep_before_first(delimiter: char) -> Self {
+        Self::delimited_with(DelimiterConfig::before_first(delimiter))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Segmented`] from an explicit configuration.
+    #[must_use]
+    pub fn segmented_with(config: SegmentConfig) -> Self {
+        Self::Segmented(config)
+    }
+
+    /// Replaces the last of exactly `segments` `delimiter`-separated segments.
+    ///
+    /// Values with a different number of segments are fully redacted.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::redact_last_segment('.', 3);
+    /// assert_eq!(policy.apply_to("hdr.payload.sig"), "hdr.payload.[REDACTED]");
+    /// assert_eq!(policy.apply_to("hdr.payload"), "[REDACTED]");
+    /// ```
+    #[must_use]
+    pub fn redact_last_segment(delimiter: char, segments: usize) -> Self {
+        Self::segmented_with(SegmentConfig::last_of(delimiter, segments))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Truncate`] from an explicit configuration.
+    #[must_use]
+    pub fn truncate_with(config: TruncateConfig) -> Self {
+        Self::Truncate(config)
+    }
+
+    /// Keeps the first `max_len` scalar values and appends `…` when longer.
+    ///
+    /// Nothing is masked; values within the cap are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::truncate(11);
+    /// assert_eq!(policy.apply_to("Mozilla/5.0 (X11; Linux x86_64)"), "Mozilla/5.0…");
+    /// assert_eq!(policy.apply_to("curl/8.4.0"), "curl/8.4.0");
+    /// ```
+    #[must_use]
+    pub fn truncate(max_len: usize) -> Self {
+        Self::truncate_with(TruncateConfig::new(max_len))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Phone`] from an explicit configuration.
+    #[must_use]
+    pub fn phone_with(config: PhoneConfig) -> Self {
+        Self::Phone(config)
+    }
+
+    /// Keeps a leading `+` with its country code and the last `visible_suffix`
+    /// characters of a phone number visible.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::phone(4);
+    /// assert_eq!(policy.apply_to("+1-555-123-4567"), "+1*********4567");
+    /// assert_eq!(policy.apply_to("5551234567"), "******4567");
+    /// ```
+    #[must_use]
+    pub fn phone(visible_suffix: usize) -> Self {
+        Self::phone_with(PhoneConfig::new(visible_suffix))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Ip`] from an explicit configuration.
+    #[must_use]
+    pub fn ip_with(config: IpConfig) -> Self {
+        Self::Ip(config)
+    }
+
+    /// Keeps the network prefix of an IP address, masking the last
+    /// `masked_v4_octets` IPv4 octets or `masked_v6_segments` IPv6 hextets.
+    ///
+    /// Values that are not IP addresses are fully redacted.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::ip_network(2, 5);
+    /// assert_eq!(policy.apply_to("192.168.1.42"), "192.168.*.*");
+    /// assert_eq!(policy.apply_to("2001:db8:85a3::8a2e:370:7334"), "2001:db8:85a3:*:*:*:*:*");
+    /// assert_eq!(policy.apply_to("not an ip"), "[REDACTED]");
+    /// ```
+    #[must_use]
+    pub fn ip_network(masked_v4_octets: usize, masked_v6_segments: usize) -> Self {
+        Self::ip_with(IpConfig::new(masked_v4_octets, masked_v6_segments))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Date`] from an explicit configuration.
+    #[must_use]
+    pub fn date_with(config: DateConfig) -> Self {
+        Self::Date(config)
+    }
+
+    /// Keeps the year of a `YYYY-MM-DD` or `MM/DD/YYYY` date and masks the
+    /// month and day.
+    ///
+    /// Values that are not dates in one of these forms are fully redacted.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_year();
+    /// assert_eq!(policy.apply_to("1990-07-14"), "1990-**-**");
+    /// assert_eq!(policy.apply_to("07/14/1990"), "**/**/1990");
+    /// assert_eq!(policy.apply_to("July 1990"), "[REDACTED]");
+    /// ```
+    #[must_use]
+    pub fn keep_year() -> Self {
+        Self::date_with(DateConfig::year_only())
+    }
+
+    /// Constructs [`TextRedactionPolicy::JsonString`] from an explicit configuration.
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn json_string_with(config: JsonStringConfig) -> Self {
+        Self::JsonString(config)
+    }
+
+    /// Redacts the values stored under `keys` inside a JSON document string.
+    ///
+    /// Input that does not parse as JSON is fully redacted.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "json")]
+    /// # {
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::json_keys(["password"]);
+    /// assert_eq!(
+    ///     policy.apply_to(r#"{"user":"alice","password":"hunter2"}"#),
+    ///     r#"{"password":"[REDACTED]","user":"alice"}"#
+    /// );
+    /// assert_eq!(policy.apply_to("not json"), "[REDACTED]");
+    /// # }
+    /// ```
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn json_keys<I, K>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<Cow<'static, str>>,
+    {
+        Self::json_string_with(JsonStringConfig::new(keys))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Regex`] from an explicit configuration.
+    #[cfg(feature = "regex")]
+    #[must_use]
+    pub fn regex_with(config: RegexConfig) -> Self {
+        Self::Regex(config)
+    }
+
+    /// Replaces every match of `pattern` with `replacement`, leaving the rest of
+    /// the value intact.
+    ///
+    /// # Errors
+    /// Returns the compile error when `pattern` is not a valid regular expression.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "regex")]
+    /// # {
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::regex(r"[\w.+-]+@[\w-]+\.[\w.]+", "[EMAIL]").unwrap();
+    /// assert_eq!(
+    ///     policy.apply_to("contact alice@example.com for access"),
+    ///     "contact [EMAIL] for access"
+    /// );
+    /// # }
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn regex<R>(pattern: &str, replacement: R) -> Result<Self, regex::Error>
+    where
+        R: Into<Cow<'static, str>>,
+    {
+        Ok(Self::regex_with(RegexConfig::new(
+            regex::Regex::new(pattern)?,
+            replacement,
+        )))
+    }
+
+    /// Constructs [`TextRedactionPolicy::Hash`] from an explicit configuration.
+    #[cfg(feature = "hash")]
+    #[must_use]
+    pub fn hash_with(config: HashConfig) -> Self {
+        Self::Hash(config)
+    }
+
+    /// Replaces the value with the first `prefix_len` hex characters of its
+    /// SHA-256 digest.
+    ///
+    /// See [`HashConfig`] for when an unsalted digest is appropriate.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "hash")]
+    /// # {
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::hashed(8);
+    /// assert_eq!(policy.apply_to("secret"), "2bb80d53");
+    /// assert_eq!(policy.apply_to(""), "[REDACTED]");
+    /// # }
+    /// ```
+    #[cfg(feature = "hash")]
+    #[must_use]
+    pub fn hashed(prefix_len: usize) -> Self {
+        Self::hash_with(HashConfig::new(prefix_len))
+    }
+
+    /// Replaces the value with the first `len` Base32 characters of its
+    /// SHA-256 digest.
+    ///
+    /// A denser alternative to [`TextRedactionPolicy::hashed`] for systems that
+    /// key on short opaque tokens: each character carries 5 bits instead of 4.
+    /// See [`HashConfig`] for when an unsalted digest is appropriate.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "hash")]
+    /// # {
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::token_hash(8);
+    /// assert_eq!(policy.apply_to("secret"), "FO4A2U33");
+    /// # }
+    /// ```
+    #[cfg(feature = "hash")]
+    #[must_use]
+    pub fn token_hash(len: usize) -> Self {
+        Self::hash_with(HashConfig::base32(len))
+    }
+
+    /// Composes this policy with `next`, which runs on this policy's output.
+    ///
+    /// Ordering matters: each policy only sees what the previous one emitted,
+    /// so masking first and truncating second caps the masked text, while the
+    /// reverse would mask an already shortened value. Empty inputs follow the
+    /// first policy, and `next` receives whatever it produced for them (for
+    /// most policies, [`REDACTED_PLACEHOLDER`]).
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::email_local(2).then(TextRedactionPolicy::truncate(10));
+    /// assert_eq!(policy.apply_to("alice@example.com"), "al***@exam…");
+    /// ```
+    #[must_use]
+    pub fn then(self, next: TextRedactionPolicy) -> Self {
+        Self::Chain(Box::new(self), Box::new(next))
+    }
+
+    /// Redacts with a caller-supplied function, for one-off transforms that do
+    /// not warrant a marker type.
+    ///
+    /// The function receives every input, including the empty string, so it
+    /// is responsible for failing closed on values it does not recognize.
+    /// Mask overrides such as [`TextRedactionPolicy::with_mask_char`] do not
+    /// reach into it.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// fn initials(value: &str) -> String {
+    ///     value
+    ///         .split_whitespace()
+    ///         .filter_map(|word| word.chars().next())
+    ///         .collect()
+    /// }
+    ///
+    /// let policy = TextRedactionPolicy::custom(initials);
+    /// assert_eq!(policy.apply_to("Ada Lovelace"), "AL");
+    /// ```
+    #[must_use]
+    pub fn custom(redact: fn(&str) -> String) -> Self {
+        Self::Custom(redact)
+    }
+
+    /// Overrides the masking character used by keep, mask, email, delimited,
+    /// phone, IP, and date policies.
+    ///
+    /// This method has no effect on [`TextRedactionPolicy::Full`] because full
+    /// redaction replaces the entire value with a placeholder string rather
+    /// than masking individual characters; use
+    /// [`TextRedactionPolicy::full_masked_with_char`] for full redaction that
+    /// follows the mask character. Segmented, JSON-string, regex, and hash
+    /// policies are likewise unaffected because they substitute replacement
+    /// text, truncation masks nothing, and custom functions own their output.
+    /// Chained policies apply the override to both links.
+    #[must_use]
+    pub fn with_mask_char(mut self, mask_char: char) -> Self {
+        match &mut self {
+            TextRedactionPolicy::Full { .. } => {}
+            TextRedactionPolicy::Keep(config) => {
+                config.set_mask_char(mask_char);
+            }
+            TextRedactionPolicy::Mask(config) => {
+                config.set_mask_char(mask_char);
+            }
+            TextRedactionPolicy::Email(config) => {
+                config.set_mask_char(mask_char);
+            }
+            TextRedactionPolicy::Delimited(config) => {
+                config.set_mask_char(mask_char);
+            }
+            TextRedactionPolicy::Segmented(_) | TextRedactionPolicy::Truncate(_) => {}
+            TextRedactionPolicy::Phone(config) => {
+                config.set_mask_char(mask_char);
+            }
+            TextRedactionPolicy::Ip(config) => {
+                config.set_mask_char(mask_char);
+            }
+            TextRedactionPolicy::Date(config) => {
+                config.set_mask_char(mask_char);
+            }
+            #[cfg(feature = "json")]
+            TextRedactionPolicy::JsonString(_) => {}
+            #[cfg(feature = "regex")]
+            TextRedactionPolicy::Regex(_) => {}
+            #[cfg(feature = "hash")]
+            TextRedactionPolicy::Hash(_) => {}
+            TextRedactionPolicy::Custom(_) => {}
+            TextRedactionPolicy::Chain(first, second) => {
+                **first = std::mem::take(&mut **first).with_mask_char(mask_char);
+                **second = std::mem::take(&mut **second).with_mask_char(mask_char);
+            }
+        }
+        self
+    }
+
+    /// Overrides the mask with a string written once per masked scalar value.
+    ///
+    /// Applies to keep and mask policies; an empty string keeps the default
+    /// mask. Email, delimited, phone, IP, and date policies keep their single
+    /// mask character, and the policies listed under
+    /// [`TextRedactionPolicy::with_mask_char`] are unaffected. Chained policies
+    /// apply the override to both links.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::mask_first(2).with_mask_str("##");
+    /// assert_eq!(policy.apply_to("abcdef"), "####cdef");
+    /// ```
+    #[must_use]
+    pub fn with_mask_str(mut self, mask: impl Into<Cow<'static, str>>) -> Self {
+        match &mut self {
+            TextRedactionPolicy::Keep(config) => config.set_mask_str(mask.into()),
+            TextRedactionPolicy::Mask(config) => config.set_mask_str(mask.into()),
+            TextRedactionPolicy::Chain(first, second) => {
+                let mask = mask.into();
+                **first = std::mem::take(&mut **first).with_mask_str(mask.clone());
+                **second = std::mem::take(&mut **second).with_mask_str(mask);
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Applies the policy to `value`.
+    ///
+    /// This method is total (it does not return errors).
+    #[must_use]
+    pub fn apply_to(&self, value: &str) -> String {
+        match self {
+            TextRedactionPolicy::Full { placeholder } => placeholder.clone().into_owned(),
+            TextRedactionPolicy::Keep(config) => config.apply_to(value),
+            TextRedactionPolicy::Mask(config) => config.apply_to(value),
+            TextRedactionPolicy::Email(config) => config.apply_to(value),
+            TextRedactionPolicy::Delimited(config) => config.apply_to(value),
+            TextRedactionPolicy::Segmented(config) => config.apply_to(value),
+            TextRedactionPolicy::Truncate(config) => config.apply_to(value),
+            TextRedactionPolicy::Phone(config) => config.apply_to(value),
+            TextRedactionPolicy::Ip(config) => config.apply_to(value),
+            TextRedactionPolicy::Date(config) => config.apply_to(value),
+            #[cfg(feature = "json")]
+            TextRedactionPolicy::JsonString(config) => config.apply_to(value),
+            #[cfg(feature = "regex")]
+            TextRedactionPolicy::Regex(config) => config.apply_to(value),
+            #[cfg(feature = "hash")]
+            TextRedactionPolicy::Hash(config) => config.apply_to(value),
+            TextRedactionPolicy::Chain(first, second) => second.apply_to(&first.apply_to(value)),
+            TextRedactionPolicy::Custom(redact) => redact(value),
+        }
+    }
+
+    /// Applies the policy to `value`, rejecting input it cannot parse.
+    ///
+    /// [`TextRedactionPolicy::apply_to`] fails closed on malformed input by
+    /// fully redacting it. This variant surfaces that case as an error for the
+    /// structural policies instead: IP address, date, segment, and JSON string
+    /// policies, and chains that contain them. Every other policy accepts any
+    /// input and returns the same result as `apply_to`.
+    ///
+    /// # Errors
+    /// Returns [`RedactionError`] when a structural policy cannot parse `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::ip_network(2, 5);
+    /// assert_eq!(policy.try_apply_to("192.168.1.42").unwrap(), "192.168.*.*");
+    /// assert!(policy.try_apply_to("not an ip").is_err());
+    /// ```
+    pub fn try_apply_to(&self, value: &str) -> Result<String, RedactionError> {
+        match self {
+            TextRedactionPolicy::Segmented(config) => config.try_apply_to(value),
+            TextRedactionPolicy::Ip(config) => config.try_apply_to(value),
+            TextRedactionPolicy::Date(config) => config.try_apply_to(value),
+            #[cfg(feature = "json")]
+            TextRedactionPolicy::JsonString(config) => config.try_apply_to(value),
+            TextRedactionPolicy::Chain(first, second) => {
+                second.try_apply_to(&first.try_apply_to(value)?)
+            }
+            _ => Ok(self.apply_to(value)),
+        }
+    }
+
+    /// Applies the policy to `value`, borrowing from the input when possible.
+    ///
+    /// Returns `Cow::Borrowed(value)`, with the caller's lifetime, when the
+    /// policy leaves the value unchanged (for example `mask_first(0)`), and a
+    /// borrowed placeholder for [`TextRedactionPolicy::Full`] with a static
+    /// placeholder. Mask policies that select no characters, regex policies
+    /// without a match, and truncation within the cap skip allocation entirely;
+    /// other policies compute the result and borrow only if it equals the
+    /// input.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let value = String::from("public");
+    /// let redacted = TextRedactionPolicy::mask_first(0).apply_to_borrowed(&value);
+    /// assert!(matches!(redacted, Cow::Borrowed("public")));
+    /// ```
+    #[must_use]
+    pub fn apply_to_borrowed<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        match self {
+            TextRedactionPolicy::Full {
+                placeholder: Cow::Borrowed(placeholder),
+            } => Cow::Borrowed(placeholder),
+            TextRedactionPolicy::Mask(config)
+                if !value.is_empty() && config.mask_prefix == 0 && config.mask_suffix == 0 =>
+            {
+                Cow::Borrowed(value)
+            }
+            TextRedactionPolicy::Truncate(config) if config.cut_at(value).is_none() => {
+                Cow::Borrowed(value)
+            }
+            #[cfg(feature = "regex")]
+            TextRedactionPolicy::Regex(config) => config.apply_to_borrowed(value),
+            _ => {
+                let redacted = self.apply_to(value);
+                if redacted == value {
+                    Cow::Borrowed(value)
+                } else {
+                    Cow::Owned(redacted)
+                }
+            }
+        }
+    }
+
+    /// Applies the policy to a platform string such as a path.
+    ///
+    /// The policy runs on the lossy UTF-8 view of `value`
+    /// ([`OsStr::to_string_lossy`]): each invalid sequence becomes one
+    /// `U+FFFD` replacement character before redaction, so it is counted and
+    /// masked like any other scalar value and shows up as `U+FFFD` if it falls
+    /// in a visible segment. The raw bytes of the input never reach the output.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::OsStr;
+    ///
+    /// use redactable::TextRedactionPolicy;
+    ///
+    /// let policy = TextRedactionPolicy::keep_after_last('/');
+    /// let redacted = policy.apply_to_os(OsStr::new("/home/alice/id_rsa"));
+    /// assert_eq!(redacted, "***********/id_rsa");
+    /// ```
+    #[must_use]
+    pub fn apply_to_os(&self, value: &OsStr) -> OsString {
+        self.apply_to(&value.to_string_lossy()).into()
+    }
+}
+
+impl std::default::Default for TextRedactionPolicy {
+    fn default() -> Self {
+        Self::default_full()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        DateConfig, DelimiterConfig, EmailConfig, IpConfig, KeepConfig, KeepWindow, MASK_CHAR,
+        MaskConfig, PhoneConfig, REDACTED_PLACEHOLDER, SegmentConfig, TextRedactionPolicy,
+        TruncateConfig,
+    };
+
+    #[test]
+    fn keep_policy_masks_short_values() {
+        // Fail closed: values at or below the keep window are fully masked.
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(3));
+        assert_eq!(policy.apply_to("ab"), "**");
+        assert_eq!(policy.apply_to("abc"), "***");
+
+        // A 4-char token under keep-last-4 must not be revealed.
+        let policy = TextRedactionPolicy::keep_last(4);
+        assert_eq!(policy.apply_to("1234"), "****");
+        assert_eq!(policy.apply_to("12345"), "*2345");
+    }
+
+    #[test]
+    fn keep_policy_counts_unicode_scalars_for_multibyte_inputs() {
+        let cjk = "\u{6771}\u{4eac}\u{5927}\u{962a}";
+        let policy = TextRedactionPolicy::keep_last(2);
+        assert_eq!(policy.apply_to(cjk), "**\u{5927}\u{962a}");
+        assert_eq!(policy.apply_to("\u{6771}\u{4eac}"), "**");
+
+        let emoji_zwj = "\u{1f469}\u{200d}\u{1f4bb}";
+        let policy = TextRedactionPolicy::keep_last(1);
+        assert_eq!(policy.apply_to(emoji_zwj), "**\u{1f4bb}");
+
+        let policy = TextRedactionPolicy::keep_last(3);
+        assert_eq!(policy.apply_to(emoji_zwj), "***");
+
+        let combining = "e\u{301}clair";
+        let policy = TextRedactionPolicy::keep_first(1);
+        assert_eq!(policy.apply_to(combining), "e******");
+
+        let policy = TextRedactionPolicy::keep_first(2);
+        assert_eq!(policy.apply_to("e\u{301}"), "**");
+    }
+
+    #[test]
+    fn keep_and_mask_policies_count_astral_plane_characters_once() {
+        let policy = TextRedactionPolicy::keep_last(2);
+        assert_eq!(
+            policy.apply_to("pass\u{1f600}\u{1f600}"),
+            "****\u{1f600}\u{1f600}"
+        );
+        assert_eq!(policy.apply_to("secret\u{1f600}"), "*****t\u{1f600}");
+        assert_eq!(policy.apply_to("\u{1f600}x\u{1f600}"), "*x\u{1f600}");
+        assert_eq!(policy.apply_to("\u{1f600}\u{1f600}"), "**");
+        assert_eq!(policy.apply_to("\u{1f600}"), "*");
+
+        let policy = TextRedactionPolicy::mask_last(2);
+        assert_eq!(policy.apply_to("pass\u{1f600}\u{1f600}"), "pass**");
+
+        // Without grapheme counting, a two-scalar flag is split by the window.
+        let flag = "\u{1F1F3}\u{1F1FF}";
+        let policy = TextRedactionPolicy::keep_last(1);
+        assert_eq!(policy.apply_to(&format!("ab{flag}")), "***\u{1F1FF}");
+    }
+
+    #[test]
+    fn keep_ratio_scales_with_value_length() {
+        let policy = TextRedactionPolicy::keep_ratio(0.25);
+        let redacted = policy.apply_to("abcdefghijklmnopqrst");
+        assert_eq!(redacted, "ab***************rst");
+        assert_eq!(redacted.chars().filter(|ch| *ch != '*').count(), 5);
+        assert_eq!(policy.apply_to("abcdefgh"), "a******h");
+
+        // At least one scalar value is always masked.
+        assert_eq!(policy.apply_to("a"), "*");
+        assert_eq!(TextRedactionPolicy::keep_ratio(1.0).apply_to("ab"), "*b");
+        assert_eq!(
+            TextRedactionPolicy::keep_ratio(f32::NAN).apply_to("abc"),
+            "***"
+        );
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+    }
+
+    #[test]
+    fn full_window_len_scales_short_values() {
+        let policy =
+            TextRedactionPolicy::keep_with(KeepConfig::both(4, 4).with_full_window_len(16));
+        assert_eq!(
+            policy.apply_to("abcdefghijklmnopqrst"),
+            "abcd************qrst"
+        );
+        assert_eq!(policy.apply_to("abcdefghijkl"), "abc******jkl");
+        assert_eq!(policy.apply_to("abcd"), "a**d");
+        assert_eq!(policy.apply_to("abc"), "***");
+
+        let policy =
+            TextRedactionPolicy::keep_with(KeepConfig::middle(4, 4).with_full_window_len(16));
+        assert_eq!(policy.apply_to("abcdefgh"), "**cd****");
+
+        let policy = TextRedactionPolicy::keep_with(
+            KeepConfig::last(4)
+                .with_preserved_separators()
+                .with_full_window_len(8),
+        );
+        assert_eq!(policy.apply_to("12-34"), "**-34");
+
+        let policy = TextRedactionPolicy::keep_with(
+            KeepConfig::first(usize::MAX / 2).with_full_window_len(100),
+        );
+        assert_eq!(policy.apply_to("abcdefghij"), "**********");
+    }
+
+    #[test]
+    fn keep_policy_respects_mask_char() {
+        let policy = TextRedactionPolicy::keep_first(2).with_mask_char('#');
+        assert_eq!(policy.apply_to("abcdef"), "ab####");
+    }
+
+    #[test]
+    fn full_policy_uses_default_placeholder() {
+        let policy = TextRedactionPolicy::default_full();
+        assert_eq!(policy.apply_to("secret"), REDACTED_PLACEHOLDER);
+    }
+
+    #[test]
+    fn full_policy_uses_custom_placeholder() {
+        let policy = TextRedactionPolicy::full_with("<redacted>");
+        assert_eq!(policy.apply_to("secret"), "<redacted>");
+    }
+
+    #[test]
+    fn mask_policy_masks_first_and_last_segments() {
+        let policy = TextRedactionPolicy::mask_first(2);
+        assert_eq!(policy.apply_to("abcdef"), "**cdef");
+
+        let policy = TextRedactionPolicy::mask_last(3);
+        assert_eq!(policy.apply_to("abcdef"), "abc***");
+    }
+
+    #[test]
+    fn mask_policy_counts_unicode_scalars_for_multibyte_inputs() {
+        let cjk = "\u{6771}\u{4eac}\u{5927}\u{962a}";
+        let policy = TextRedactionPolicy::mask_first(2);
         assert_eq!(policy.apply_to(cjk), "**\u{5927}\u{962a}");
         assert_eq!(policy.apply_to("\u{6771}\u{4eac}"), "**");
 
-        let emoji_zwj = "\u{1f469}\u{200d}\u{1f4bb}";
-        let policy = TextRedactionPolicy::keep_last(1);
-        assert_eq!(policy.apply_to(emoji_zwj), "**\u{1f4bb}");
+        let policy = TextRedactionPolicy::mask_last(2);
+        assert_eq!(policy.apply_to(cjk), "\u{6771}\u{4eac}**");
+
+        let emoji_zwj = "\u{1f469}\u{200d}\u{1f4bb}";
+        let policy = TextRedactionPolicy::mask_last(1);
+        assert_eq!(policy.apply_to(emoji_zwj), "\u{1f469}\u{200d}*");
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(1, 2));
+        assert_eq!(policy.apply_to(emoji_zwj), "***");
+
+        let combining = "e\u{301}clair";
+        let policy = TextRedactionPolicy::mask_first(1);
+        assert_eq!(policy.apply_to(combining), "*\u{301}clair");
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(1, 1));
+        assert_eq!(policy.apply_to("e\u{301}"), "**");
+    }
+
+    #[test]
+    fn mask_policy_respects_custom_mask_char() {
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::last(2)).with_mask_char('#');
+        assert_eq!(policy.apply_to("abcd"), "ab##");
+    }
+
+    #[test]
+    fn email_policy_preserves_domain() {
+        let policy = TextRedactionPolicy::email_local(2);
+        assert_eq!(policy.apply_to("alice@example.com"), "al***@example.com");
+        assert_eq!(policy.apply_to("bob@company.io"), "bo*@company.io");
+        // Fail closed: single char local with prefix=2 masks the local part.
+        assert_eq!(policy.apply_to("x@a.com"), "*@a.com");
+    }
+
+    #[test]
+    fn email_policy_counts_unicode_scalars_for_local_parts() {
+        let policy = TextRedactionPolicy::email_local(2);
+        assert_eq!(
+            policy.apply_to("\u{6771}\u{4eac}user@example.com"),
+            "\u{6771}\u{4eac}****@example.com"
+        );
+        assert_eq!(
+            policy.apply_to("\u{6771}\u{4eac}@example.com"),
+            "**@example.com"
+        );
+
+        assert_eq!(
+            policy.apply_to("\u{1f469}\u{200d}\u{1f4bb}dev@example.com"),
+            "\u{1f469}\u{200d}****@example.com"
+        );
+
+        let policy = TextRedactionPolicy::email_local(3);
+        assert_eq!(
+            policy.apply_to("\u{1f469}\u{200d}\u{1f4bb}@example.com"),
+            "***@example.com"
+        );
+
+        let policy = TextRedactionPolicy::email_local(1);
+        assert_eq!(
+            policy.apply_to("e\u{301}clair@example.com"),
+            "e******@example.com"
+        );
+
+        let policy = TextRedactionPolicy::email_local(2);
+        assert_eq!(policy.apply_to("e\u{301}@example.com"), "**@example.com");
+    }
+
+    #[test]
+    fn email_policy_masks_non_email_inputs() {
+        let policy = TextRedactionPolicy::email_local(2);
+
+        // No @ symbol - mask like prefix keep
+        assert_eq!(policy.apply_to("noatsymbol"), "no********");
+
+        // Empty string
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        // Fail closed: local part exactly at the keep length is fully masked
+        assert_eq!(policy.apply_to("ab@x.com"), "**@x.com");
+
+        // Fail closed: local part below the keep length is fully masked
+        assert_eq!(policy.apply_to("a@b.c"), "*@b.c");
+    }
+
+    #[test]
+    fn email_policy_respects_mask_char() {
+        let policy = TextRedactionPolicy::email_local(2).with_mask_char('#');
+        assert_eq!(policy.apply_to("alice@example.com"), "al###@example.com");
+    }
+
+    #[test]
+    fn email_policy_splits_on_last_at_sign() {
+        // Quoted local parts may contain '@'; the separator is the last one,
+        // so no fragment of the local part survives in the "domain" segment.
+        let policy = TextRedactionPolicy::email_local(2);
+        assert_eq!(policy.apply_to("\"a@b\"@example.com"), "\"a***@example.com");
+        assert_eq!(policy.apply_to("user@@example.com"), "us***@example.com");
+    }
+
+    #[test]
+    fn empty_string_returns_placeholder_for_policies() {
+        // Empty strings are fully redacted for keep/mask/email policies.
+        let keep_policy = TextRedactionPolicy::keep_first(4);
+        assert_eq!(keep_policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        let mask_policy = TextRedactionPolicy::mask_first(4);
+        assert_eq!(mask_policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        let email_policy = TextRedactionPolicy::email_local(2);
+        assert_eq!(email_policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        let full_policy = TextRedactionPolicy::default_full();
+        assert_eq!(full_policy.apply_to(""), REDACTED_PLACEHOLDER);
+    }
+
+    #[test]
+    fn keep_both_overlap_masks_entire_value() {
+        // Fail closed: when prefix + suffix >= total, mask everything
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2));
+        assert_eq!(policy.apply_to("abc"), "***"); // 2 + 2 = 4 >= 3
+
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(3, 3));
+        assert_eq!(policy.apply_to("abcd"), "****"); // 3 + 3 = 6 >= 4
+
+        // Edge case: exactly equals total
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2));
+        assert_eq!(policy.apply_to("abcd"), "****"); // 2 + 2 = 4 >= 4
+
+        // Overflow-safe: large values still mask the entire value
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(usize::MAX, usize::MAX));
+        assert_eq!(policy.apply_to("abcd"), "****");
+    }
+
+    #[test]
+    fn mask_both_overlap_masks_entire_value() {
+        // When prefix + suffix >= total, mask everything
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(2, 2));
+        assert_eq!(policy.apply_to("abc"), "***"); // 2 + 2 = 4 >= 3
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(3, 3));
+        assert_eq!(policy.apply_to("abcd"), "****"); // 3 + 3 = 6 >= 4
+
+        // Edge case: exactly equals total
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(2, 2));
+        assert_eq!(policy.apply_to("abcd"), "****"); // 2 + 2 = 4 >= 4
+
+        // Overflow-safe: large values still mask entire value
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(usize::MAX, usize::MAX));
+        assert_eq!(policy.apply_to("abcd"), "****");
+    }
+
+    #[test]
+    fn keep_policy_caps_long_mask_runs() {
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2).with_max_mask(0));
+        assert_eq!(
+            policy.apply_to(&format!("ab{}ef", "x".repeat(200))),
+            "ab\u{2026}ef"
+        );
+
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2).with_max_mask(4));
+        assert_eq!(
+            policy.apply_to(&format!("ab{}ef", "x".repeat(200))),
+            "ab****\u{2026}ef"
+        );
+        // Runs at or below the cap are unchanged.
+        assert_eq!(policy.apply_to("ab1234ef"), "ab****ef");
+        assert_eq!(policy.apply_to("ab12ef"), "ab**ef");
+
+        // The cap also applies when failing closed.
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::last(4).with_max_mask(2));
+        assert_eq!(policy.apply_to("1234"), "**\u{2026}");
+        assert_eq!(policy.apply_to("sk_live_abcdef"), "**\u{2026}cdef");
+
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(1).with_max_mask(1))
+            .with_mask_char('#');
+        assert_eq!(policy.apply_to("secret"), "s#\u{2026}");
+    }
+
+    #[test]
+    fn keep_both_no_overlap() {
+        // Normal case: prefix + suffix < total
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2));
+        assert_eq!(policy.apply_to("abcdef"), "ab**ef"); // keep first 2 and last 2
+    }
+
+    #[test]
+    fn keep_middle_overlap_masks_prefix_only() {
+        // skip_prefix >= total masks everything
+        let policy = TextRedactionPolicy::keep_middle(3, 2);
+        assert_eq!(policy.apply_to("abc"), "***");
+        let policy = TextRedactionPolicy::keep_middle(5, 2);
+        assert_eq!(policy.apply_to("abc"), "***");
+
+        // skip_prefix + visible_len >= total masks only the prefix
+        let policy = TextRedactionPolicy::keep_middle(2, 2);
+        assert_eq!(policy.apply_to("abcd"), "**cd"); // 2 + 2 = 4 >= 4
+        let policy = TextRedactionPolicy::keep_middle(2, 5);
+        assert_eq!(policy.apply_to("abcd"), "**cd");
+
+        // Overflow-safe: large values still mask the entire value
+        let policy = TextRedactionPolicy::keep_middle(usize::MAX, usize::MAX);
+        assert_eq!(policy.apply_to("abcd"), "****");
+
+        // A window that would reveal the whole value masks everything
+        let policy = TextRedactionPolicy::keep_middle(0, 4);
+        assert_eq!(policy.apply_to("abcd"), "****");
+        let policy = TextRedactionPolicy::keep_middle(0, usize::MAX);
+        assert_eq!(policy.apply_to("abcd"), "****");
+        let policy =
+            TextRedactionPolicy::keep_with(KeepConfig::middle(0, 4).with_preserved_separators());
+        assert_eq!(policy.apply_to("ab-cd"), "**-**");
+    }
+
+    #[test]
+    fn keep_middle_no_overlap() {
+        // Normal case: skip_prefix + visible_len < total
+        let policy = TextRedactionPolicy::keep_middle(2, 2);
+        assert_eq!(policy.apply_to("abcdef"), "**cd**"); // mask 2, keep 2, mask the rest
+
+        let policy = TextRedactionPolicy::keep_middle(1, 2).with_mask_char('#');
+        assert_eq!(policy.apply_to("\u{e9}t\u{e9}s"), "#t\u{e9}#");
+
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::middle(2, 2).with_max_mask(1));
+        assert_eq!(policy.apply_to("abcdefgh"), "*\u{2026}cd*\u{2026}");
+    }
+
+    #[test]
+    fn full_masked_preserves_scalar_length() {
+        let policy = TextRedactionPolicy::full_masked();
+        assert_eq!(policy.apply_to("0123456789abcdef"), "*".repeat(16));
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        let cjk = "\u{6771}\u{4eac}\u{5927}\u{962a}";
+        assert_eq!(policy.apply_to(cjk), "****");
+        let emoji = "\u{1f600}\u{1f601}";
+        assert_eq!(policy.apply_to(emoji), "**");
+
+        let policy = TextRedactionPolicy::full_masked().with_mask_char('#');
+        assert_eq!(policy.apply_to("caf\u{e9}"), "####");
+    }
+
+    #[test]
+    fn apply_to_borrowed_borrows_unchanged_values() {
+        use std::borrow::Cow;
+
+        let owned = String::from("public");
+        let value: &str = &owned;
+
+        let redacted = TextRedactionPolicy::mask_first(0).apply_to_borrowed(value);
+        assert!(matches!(&redacted, Cow::Borrowed(borrowed) if std::ptr::eq(*borrowed, value)));
+
+        // A middle window covering the whole value fails closed.
+        let redacted = TextRedactionPolicy::keep_middle(0, 10).apply_to_borrowed(value);
+        assert!(matches!(&redacted, Cow::Owned(masked) if masked == "******"));
+
+        let redacted = TextRedactionPolicy::default_full().apply_to_borrowed(value);
+        assert!(matches!(redacted, Cow::Borrowed(REDACTED_PLACEHOLDER)));
+
+        let redacted = TextRedactionPolicy::keep_last(2).apply_to_borrowed(value);
+        assert!(matches!(&redacted, Cow::Owned(masked) if masked == "****ic"));
+
+        let redacted = TextRedactionPolicy::mask_first(0).apply_to_borrowed("");
+        assert!(matches!(redacted, Cow::Owned(placeholder) if placeholder == REDACTED_PLACEHOLDER));
+    }
+
+    #[test]
+    fn mask_str_repeats_per_masked_scalar() {
+        let policy = TextRedactionPolicy::mask_first(2).with_mask_str("##");
+        assert_eq!(policy.apply_to("abcdef"), "####cdef");
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(1, 1))
+            .with_mask_str("\u{2022}\u{2022}");
+        assert_eq!(
+            policy.apply_to("\u{e9}t\u{e9}"),
+            "\u{2022}\u{2022}t\u{2022}\u{2022}"
+        );
+
+        let policy = TextRedactionPolicy::keep_last(2).with_mask_str(String::from("XX"));
+        assert_eq!(policy.apply_to("abcd"), "XXXXcd");
+        // Fail-closed masking also uses the mask string.
+        assert_eq!(policy.apply_to("ab"), "XXXX");
+
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(1).with_max_mask(2))
+            .with_mask_str("XX");
+        assert_eq!(policy.apply_to("abcdef"), "aXXXX\u{2026}");
+
+        // `with_mask_char` still sets a single-character mask.
+        let policy = TextRedactionPolicy::mask_first(2)
+            .with_mask_str("##")
+            .with_mask_char('-');
+        assert_eq!(policy.apply_to("abcdef"), "--cdef");
+
+        // Email policies keep their single mask character.
+        let policy = TextRedactionPolicy::email_local(1).with_mask_str("##");
+        assert_eq!(policy.apply_to("bob@x.io"), "b**@x.io");
+    }
+
+    #[test]
+    fn empty_mask_str_keeps_default_mask() {
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::last(2).with_mask_str(""));
+        assert_eq!(policy.apply_to("abcdef"), "****ef");
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::first(2).with_mask_str(""));
+        assert_eq!(policy.apply_to("abcdef"), "**cdef");
+    }
+
+    #[test]
+    fn mask_both_no_overlap() {
+        // Normal case: prefix + suffix < total
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(2, 2));
+        assert_eq!(policy.apply_to("abcdef"), "**cd**"); // mask first 2 and last 2
+    }
+
+    #[test]
+    fn delimited_policy_keeps_after_last_delimiter() {
+        let policy = TextRedactionPolicy::keep_after_last('@');
+        assert_eq!(policy.apply_to("alice@example.com"), "*****@example.com");
+        assert_eq!(policy.apply_to("\"a@b\"@example.com"), "*****@example.com");
+
+        let policy = TextRedactionPolicy::keep_after_last('/');
+        assert_eq!(
+            policy.apply_to("s3cr3t/tenant/orders"),
+            "*************/orders"
+        );
+
+        let policy = TextRedactionPolicy::keep_after_last(':');
+        assert_eq!(policy.apply_to("user:pass:8080"), "*********:8080");
+    }
 
-        let policy = TextRedactionPolicy::keep_last(3);
-        assert_eq!(policy.apply_to(emoji_zwj), "***");
+    #[test]
+    fn delimited_policy_keeps_before_first_delimiter() {
+        let policy = TextRedactionPolicy::keep_before_first(':');
+        assert_eq!(policy.apply_to("admin:hunter2"), "admin:*******");
+        assert_eq!(
+            policy.apply_to("admin:hunter2:extra"),
+            "admin:*************"
+        );
 
-        let combining = "e\u{301}clair";
-        let policy = TextRedactionPolicy::keep_first(1);
-        assert_eq!(policy.apply_to(combining), "e******");
+        let policy = TextRedactionPolicy::keep_before_first('/');
+        assert_eq!(policy.apply_to("tenant/\u{6771}\u{4eac}"), "tenant/**");
 
-        let policy = TextRedactionPolicy::keep_first(2);
-        assert_eq!(policy.apply_to("e\u{301}"), "**");
+        let policy = TextRedactionPolicy::keep_before_first('@');
+        assert_eq!(policy.apply_to("alice@example.com"), "alice@***********");
     }
 
     #[test]
-    fn keep_policy_respects_mask_char() {
-        let policy = TextRedactionPolicy::keep_first(2).with_mask_char('#');
-        assert_eq!(policy.apply_to("abcdef"), "ab####");
+    fn delimited_policy_masks_values_without_delimiter() {
+        // Fail closed: without the delimiter there is no known-safe segment.
+        let policy = TextRedactionPolicy::keep_after_last('@');
+        assert_eq!(policy.apply_to("noatsymbol"), "**********");
+
+        let policy = TextRedactionPolicy::keep_before_first(':');
+        assert_eq!(policy.apply_to("hunter2"), "*******");
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
     }
 
     #[test]
-    fn full_policy_uses_default_placeholder() {
-        let policy = TextRedactionPolicy::default_full();
-        assert_eq!(policy.apply_to("secret"), REDACTED_PLACEHOLDER);
+    fn delimited_policy_respects_mask_char() {
+        let policy = TextRedactionPolicy::delimited_with(
+            DelimiterConfig::before_first(':').with_mask_char('#'),
+        );
+        assert_eq!(policy.apply_to("user:pass"), "user:####");
+
+        let policy = TextRedactionPolicy::keep_after_last('@').with_mask_char('#');
+        assert_eq!(policy.apply_to("bob@x.io"), "###@x.io");
     }
 
     #[test]
-    fn full_policy_uses_custom_placeholder() {
-        let policy = TextRedactionPolicy::full_with("<redacted>");
-        assert_eq!(policy.apply_to("secret"), "<redacted>");
+    fn phone_policy_keeps_country_code_and_last_digits() {
+        let policy = TextRedactionPolicy::phone(4);
+        assert_eq!(policy.apply_to("+1-555-123-4567"), "+1*********4567");
+        assert_eq!(policy.apply_to("+44 20 7946 0958"), "+44*********0958");
+        assert_eq!(policy.apply_to("5551234567"), "******4567");
+        // Without a separator the country code length is unknown: keep only `+`.
+        assert_eq!(policy.apply_to("+15551234567"), "+*******4567");
+        // Fail closed: a remainder within the keep window is fully masked.
+        assert_eq!(policy.apply_to("+1 567"), "+1****");
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
     }
 
     #[test]
-    fn mask_policy_masks_first_and_last_segments() {
-        let policy = TextRedactionPolicy::mask_first(2);
-        assert_eq!(policy.apply_to("abcdef"), "**cdef");
+    fn phone_policy_can_mask_country_code() {
+        let config = PhoneConfig::new(4).with_country_code(false);
+        let policy = TextRedactionPolicy::phone_with(config);
+        assert_eq!(policy.apply_to("+1-555-123-4567"), "***********4567");
+        assert_eq!(
+            policy.apply_to("5551234567"),
+            TextRedactionPolicy::keep_last(4).apply_to("5551234567")
+        );
 
-        let policy = TextRedactionPolicy::mask_last(3);
-        assert_eq!(policy.apply_to("abcdef"), "abc***");
+        let policy = TextRedactionPolicy::phone(4).with_mask_char('#');
+        assert_eq!(policy.apply_to("+1-555-123-4567"), "+1#########4567");
     }
 
     #[test]
-    fn mask_policy_counts_unicode_scalars_for_multibyte_inputs() {
-        let cjk = "\u{6771}\u{4eac}\u{5927}\u{962a}";
-        let policy = TextRedactionPolicy::mask_first(2);
-        assert_eq!(policy.apply_to(cjk), "**\u{5927}\u{962a}");
-        assert_eq!(policy.apply_to("\u{6771}\u{4eac}"), "**");
+    fn phone_policy_normalizes_formatting_before_masking() {
+        let policy =
+            TextRedactionPolicy::phone_with(PhoneConfig::new(4).with_e164_normalization(true));
+        for input in [
+            "(555) 123-4567",
+            "555-123-4567",
+            "555.123.4567",
+            "5551234567",
+        ] {
+            assert_eq!(policy.apply_to(input), "******4567", "input: {input}");
+        }
+        for input in [
+            "+1 (555) 123-4567",
+            "+1-555-123-4567",
+            "+1 555 123 4567",
+            "+15551234567",
+        ] {
+            assert_eq!(policy.apply_to(input), "+*******4567", "input: {input}");
+        }
+        assert_eq!(policy.apply_to(" - "), REDACTED_PLACEHOLDER);
 
-        let policy = TextRedactionPolicy::mask_last(2);
-        assert_eq!(policy.apply_to(cjk), "\u{6771}\u{4eac}**");
+        // Without normalization the formatting is masked in place.
+        let policy = TextRedactionPolicy::phone(4);
+        assert_ne!(
+            policy.apply_to("(555) 123-4567"),
+            policy.apply_to("555-123-4567")
+        );
+    }
 
-        let emoji_zwj = "\u{1f469}\u{200d}\u{1f4bb}";
-        let policy = TextRedactionPolicy::mask_last(1);
-        assert_eq!(policy.apply_to(emoji_zwj), "\u{1f469}\u{200d}*");
+    #[test]
+    fn ip_policy_keeps_ipv4_network_prefix() {
+        let policy = TextRedactionPolicy::ip_with(IpConfig::default());
+        assert_eq!(policy.apply_to("192.168.1.42"), "192.168.*.*");
 
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(1, 2));
-        assert_eq!(policy.apply_to(emoji_zwj), "***");
+        let policy = TextRedactionPolicy::ip_network(1, 0).with_mask_char('x');
+        assert_eq!(policy.apply_to("10.0.0.255"), "10.0.0.x");
 
-        let combining = "e\u{301}clair";
-        let policy = TextRedactionPolicy::mask_first(1);
-        assert_eq!(policy.apply_to(combining), "*\u{301}clair");
+        let policy = TextRedactionPolicy::ip_network(9, 9);
+        assert_eq!(policy.apply_to("10.0.0.255"), "*.*.*.*");
+    }
 
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(1, 1));
-        assert_eq!(policy.apply_to("e\u{301}"), "**");
+    #[test]
+    fn ip_policy_keeps_ipv6_network_prefix() {
+        let policy = TextRedactionPolicy::ip_with(IpConfig::default());
+        assert_eq!(
+            policy.apply_to("2001:0db8:85a3:0000:0000:8a2e:0370:7334"),
+            "2001:db8:85a3:*:*:*:*:*"
+        );
+        assert_eq!(policy.apply_to("fe80::1"), "fe80:0:0:*:*:*:*:*");
     }
 
     #[test]
-    fn mask_policy_respects_custom_mask_char() {
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::last(2)).with_mask_char('#');
-        assert_eq!(policy.apply_to("abcd"), "ab##");
+    fn ip_policy_fully_redacts_malformed_input() {
+        let policy = TextRedactionPolicy::ip_with(IpConfig::default());
+        assert_eq!(policy.apply_to("192.168.1"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to("192.168.1.42:8080"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to(" 192.168.1.42"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to("hostname"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
     }
 
     #[test]
-    fn email_policy_preserves_domain() {
-        let policy = TextRedactionPolicy::email_local(2);
-        assert_eq!(policy.apply_to("alice@example.com"), "al***@example.com");
-        assert_eq!(policy.apply_to("bob@company.io"), "bo*@company.io");
-        // Fail closed: single char local with prefix=2 masks the local part.
-        assert_eq!(policy.apply_to("x@a.com"), "*@a.com");
+    fn try_apply_to_reports_input_structural_policies_cannot_parse() {
+        let ip = TextRedactionPolicy::ip_with(IpConfig::default());
+        assert_eq!(ip.try_apply_to("192.168.1.42").unwrap(), "192.168.*.*");
+        let error = ip.try_apply_to("192.168.1").unwrap_err();
+        assert_eq!(error.message(), "not an IP address");
+
+        let date = TextRedactionPolicy::keep_year();
+        assert_eq!(date.try_apply_to("1990-07-14").unwrap(), "1990-**-**");
+        assert!(date.try_apply_to("July 14, 1990").is_err());
+
+        let segment = TextRedactionPolicy::redact_last_segment('.', 3);
+        assert_eq!(segment.try_apply_to("a.b.c").unwrap(), "a.b.[REDACTED]");
+        assert!(segment.try_apply_to("a.b").is_err());
+
+        let chain = TextRedactionPolicy::truncate(64).then(ip);
+        assert!(chain.try_apply_to("hostname").is_err());
+
+        let keep = TextRedactionPolicy::keep_last(4);
+        assert_eq!(keep.try_apply_to("hostname").unwrap(), "****name");
+        assert_eq!(keep.try_apply_to("").unwrap(), keep.apply_to(""));
     }
 
+    #[cfg(feature = "json")]
     #[test]
-    fn email_policy_counts_unicode_scalars_for_local_parts() {
-        let policy = TextRedactionPolicy::email_local(2);
+    fn json_string_policy_redacts_configured_keys() {
+        let policy = TextRedactionPolicy::json_keys(["password", "token"]);
         assert_eq!(
-            policy.apply_to("\u{6771}\u{4eac}user@example.com"),
-            "\u{6771}\u{4eac}****@example.com"
+            policy.apply_to(r#"{"user":"alice","password":"hunter2","token":"abc"}"#),
+            r#"{"password":"[REDACTED]","token":"[REDACTED]","user":"alice"}"#
         );
+
+        // Nested objects and arrays are searched; redacted keys hide whole subtrees.
         assert_eq!(
-            policy.apply_to("\u{6771}\u{4eac}@example.com"),
-            "**@example.com"
+            policy.apply_to(
+                r#"{"sessions":[{"id":1,"Token":"t1"}],"auth":{"password":{"hash":"x"}}}"#
+            ),
+            r#"{"auth":{"password":"[REDACTED]"},"sessions":[{"Token":"[REDACTED]","id":1}]}"#
         );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_string_policy_fails_closed_on_invalid_json() {
+        let policy = TextRedactionPolicy::json_keys(["password"]);
+        assert_eq!(policy.apply_to("password=hunter2"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to(r#"{"password":"#), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+        // Scalars parse as JSON and carry no keys.
+        assert_eq!(policy.apply_to("42"), "42");
+        assert!(policy.try_apply_to("password=hunter2").is_err());
+    }
 
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_policy_scrubs_matches_in_place() {
+        let policy =
+            TextRedactionPolicy::regex(r"[\w.+-]+@[\w-]+\.[\w.]+", REDACTED_PLACEHOLDER).unwrap();
         assert_eq!(
-            policy.apply_to("\u{1f469}\u{200d}\u{1f4bb}dev@example.com"),
-            "\u{1f469}\u{200d}****@example.com"
+            policy.apply_to("user alice@example.com failed login; notify ops@corp.io now"),
+            "user [REDACTED] failed login; notify [REDACTED] now"
         );
+        // No match: the value is returned unchanged (and borrowed).
+        assert_eq!(policy.apply_to("no addresses here"), "no addresses here");
+        assert!(matches!(
+            policy.apply_to_borrowed("no addresses here"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+        assert_eq!(policy.apply_to(""), "");
+    }
 
-        let policy = TextRedactionPolicy::email_local(3);
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_policy_expands_capture_groups() {
+        let policy = TextRedactionPolicy::regex(r"[\w.+-]+@([\w-]+\.[\w.]+)", "***@$1").unwrap();
         assert_eq!(
-            policy.apply_to("\u{1f469}\u{200d}\u{1f4bb}@example.com"),
-            "***@example.com"
+            policy.apply_to("sent to alice@example.com."),
+            "sent to ***@example.com."
         );
+        // Mask characters do not apply to regex replacements.
+        let policy = policy.with_mask_char('#');
+        assert_eq!(policy.apply_to("bob@x.io"), "***@x.io");
+    }
 
-        let policy = TextRedactionPolicy::email_local(1);
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_policy_rejects_invalid_patterns() {
+        assert!(TextRedactionPolicy::regex("(unclosed", "x").is_err());
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn hash_policy_is_deterministic() {
+        let policy = TextRedactionPolicy::hashed(8);
+        assert_eq!(policy.apply_to("secret"), policy.apply_to("secret"));
+        assert_eq!(policy.apply_to("secret"), "2bb80d53");
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+    }
+
+    #[cfg(feature = "hash")]
+    #[test]
+    fn hash_policy_distinguishes_inputs() {
+        let policy = TextRedactionPolicy::hashed(8);
+        assert_ne!(policy.apply_to("secret"), policy.apply_to("secret2"));
+
+        // Lengths are clamped to between one character and the full
+        // 64-character digest.
+        assert_eq!(TextRedactionPolicy::hashed(0).apply_to("secret"), "2");
+        let full = TextRedactionPolicy::hashed(usize::MAX).apply_to("secret");
+        assert_eq!(full.len(), 64);
+        assert!(full.starts_with("2bb80d53"));
+        assert!(full.chars().all(|ch| ch.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn keep_policy_preserves_separators() {
+        let policy =
+            TextRedactionPolicy::keep_with(KeepConfig::last(4).with_preserved_separators());
         assert_eq!(
-            policy.apply_to("e\u{301}clair@example.com"),
-            "e******@example.com"
+            policy.apply_to("4111-1111-1111-1111"),
+            "****-****-****-1111"
         );
+        assert_eq!(
+            policy.apply_to("4111 1111 1111 1111"),
+            "**** **** **** 1111"
+        );
+        assert_eq!(policy.apply_to("4111111111111111"), "************1111");
+        // Separators do not count toward the keep window, so this still fails closed.
+        assert_eq!(policy.apply_to("12-34"), "**-**");
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
 
-        let policy = TextRedactionPolicy::email_local(2);
-        assert_eq!(policy.apply_to("e\u{301}@example.com"), "**@example.com");
+        let policy = TextRedactionPolicy::keep_with(
+            KeepConfig::middle(2, 3)
+                .with_preserved_separators()
+                .with_mask_char('#'),
+        );
+        assert_eq!(policy.apply_to("ab-cde-fg"), "##-cde-##");
     }
 
     #[test]
-    fn email_policy_masks_non_email_inputs() {
-        let policy = TextRedactionPolicy::email_local(2);
+    fn segmented_policy_replaces_only_the_last_segment() {
+        let policy = TextRedactionPolicy::redact_last_segment('.', 3);
+        assert_eq!(
+            policy.apply_to("eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.c2ln"),
+            "eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.[REDACTED]"
+        );
+        assert_eq!(policy.apply_to("hdr.payload"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to("a.b.c.d"), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
 
-        // No @ symbol - mask like prefix keep
-        assert_eq!(policy.apply_to("noatsymbol"), "no********");
+        let policy = TextRedactionPolicy::segmented_with(
+            SegmentConfig::last_of(':', 2).with_placeholder("<hidden>"),
+        )
+        .with_mask_char('#');
+        assert_eq!(policy.apply_to("user:hunter2"), "user:<hidden>");
+        assert_eq!(policy.apply_to("hunter2"), "<hidden>");
+    }
 
-        // Empty string
+    #[test]
+    fn truncate_policy_caps_scalar_values() {
+        let policy = TextRedactionPolicy::truncate(5);
+        assert_eq!(policy.apply_to("abc"), "abc");
+        assert_eq!(policy.apply_to("abcde"), "abcde");
+        assert_eq!(policy.apply_to("abcdefgh"), "abcde…");
+        assert_eq!(policy.apply_to(""), "");
+        assert!(matches!(
+            policy.apply_to_borrowed("abcde"),
+            std::borrow::Cow::Borrowed("abcde")
+        ));
+    }
+
+    #[test]
+    fn truncate_policy_counts_multibyte_scalars() {
+        let policy = TextRedactionPolicy::truncate_with(TruncateConfig::new(3));
+        assert_eq!(policy.apply_to("ñáé"), "ñáé");
+        assert_eq!(policy.apply_to("日本語テキスト"), "日本語…");
+        assert_eq!(policy.with_mask_char('#').apply_to("añbcd"), "añb…");
+    }
+
+    #[test]
+    fn email_policy_masks_leading_domain_labels() {
+        let policy = TextRedactionPolicy::Email(EmailConfig::new(2).mask_domain_labels(1));
+        assert_eq!(
+            policy.apply_to("alice@mail.corp.com"),
+            "al***@****.corp.com"
+        );
+        assert_eq!(policy.apply_to("alice@example.com"), "al***@*******.com");
+        assert_eq!(policy.apply_to("alice@localhost"), "al***@*********");
+
+        let policy = TextRedactionPolicy::Email(EmailConfig::new(2).mask_domain_labels(5));
+        assert_eq!(
+            policy.apply_to("alice@mail.corp.com"),
+            "al***@****.****.com"
+        );
+        assert_eq!(
+            TextRedactionPolicy::email_local(2).apply_to("alice@mail.corp.com"),
+            "al***@mail.corp.com"
+        );
+    }
+
+    #[test]
+    fn full_masked_with_char_masks_every_scalar_value() {
+        let policy = TextRedactionPolicy::full_masked_with_char('#');
+        assert_eq!(policy.apply_to("hunter2"), "#######");
+        assert_eq!(policy.apply_to("пароль"), "######");
         assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+        assert_eq!(policy.with_mask_char('x').apply_to("abc"), "xxx");
 
-        // Fail closed: local part exactly at the keep length is fully masked
-        assert_eq!(policy.apply_to("ab@x.com"), "**@x.com");
+        // The placeholder form ignores the mask character.
+        let full = TextRedactionPolicy::default_full().with_mask_char('#');
+        assert_eq!(full.apply_to("hunter2"), REDACTED_PLACEHOLDER);
+    }
 
-        // Fail closed: local part below the keep length is fully masked
-        assert_eq!(policy.apply_to("a@b.c"), "*@b.c");
+    #[cfg(feature = "hash")]
+    #[test]
+    fn token_hash_policy_emits_stable_base32_prefixes() {
+        let policy = TextRedactionPolicy::token_hash(12);
+        assert_eq!(policy.apply_to("secret"), "FO4A2U33DWR6");
+        assert_eq!(policy.apply_to("secret"), policy.apply_to("secret"));
+        assert_ne!(policy.apply_to("secret"), policy.apply_to("secreT"));
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        assert_eq!(TextRedactionPolicy::token_hash(0).apply_to("secret"), "F");
+        for len in [1, 26, 52] {
+            assert_eq!(
+                TextRedactionPolicy::token_hash(len)
+                    .apply_to("secret")
+                    .len(),
+                len
+            );
+        }
+        assert_eq!(
+            TextRedactionPolicy::token_hash(100).apply_to("secret"),
+            "FO4A2U33DWR6HC6TANQ2VBKWQ266B2WNOFRP55VCL7UXX5JHUJNQ"
+        );
     }
 
+    #[cfg(feature = "unicode-segmentation")]
     #[test]
-    fn email_policy_respects_mask_char() {
-        let policy = TextRedactionPolicy::email_local(2).with_mask_char('#');
-        assert_eq!(policy.apply_to("alice@example.com"), "al###@example.com");
+    fn grapheme_mode_keeps_emoji_clusters_whole() {
+        const FAMILY: &str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(1).with_graphemes());
+        assert_eq!(
+            policy.apply_to(&format!("{FAMILY}abc")),
+            format!("{FAMILY}***")
+        );
+        assert_eq!(
+            policy.apply_to("\u{1F1F3}\u{1F1FF}\u{1F1E6}\u{1F1FA}"),
+            "\u{1F1F3}\u{1F1FF}*"
+        );
+        // Scalar mode splits the family into its five scalar values.
+        let scalar = TextRedactionPolicy::keep_with(KeepConfig::first(1));
+        assert_eq!(scalar.apply_to(&format!("{FAMILY}abc")), "\u{1F468}*******");
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::first(1).with_graphemes());
+        assert_eq!(policy.apply_to(&format!("{FAMILY} family")), "* family");
+
+        let policy = TextRedactionPolicy::keep_with(
+            KeepConfig::last(1)
+                .with_graphemes()
+                .with_preserved_separators(),
+        );
+        // Clusters count as alphanumeric by their base scalar value.
+        assert_eq!(policy.apply_to("ab-ce\u{301}"), "**-*e\u{301}");
     }
 
+    #[cfg(feature = "unicode-segmentation")]
     #[test]
-    fn email_policy_splits_on_last_at_sign() {
-        // Quoted local parts may contain '@'; the separator is the last one,
-        // so no fragment of the local part survives in the "domain" segment.
-        let policy = TextRedactionPolicy::email_local(2);
-        assert_eq!(policy.apply_to("\"a@b\"@example.com"), "\"a***@example.com");
-        assert_eq!(policy.apply_to("user@@example.com"), "us***@example.com");
+    fn grapheme_mode_keeps_combining_accents_attached() {
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(1).with_graphemes());
+        assert_eq!(policy.apply_to("e\u{301}le\u{300}ve"), "e\u{301}****");
+
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::last(2).with_graphemes());
+        assert_eq!(policy.apply_to("cafe\u{301}"), "ca**");
+
+        let policy = TextRedactionPolicy::Email(EmailConfig::new(1).with_graphemes());
+        assert_eq!(
+            policy.apply_to("e\u{301}mile@example.com"),
+            "e\u{301}****@example.com"
+        );
+        assert_eq!(policy.apply_to("e\u{301}"), "*");
+
+        let policy =
+            TextRedactionPolicy::Email(EmailConfig::new(0).mask_domain_labels(1).with_graphemes());
+        assert_eq!(policy.apply_to("a@cafe\u{301}.fr"), "*@****.fr");
     }
 
     #[test]
-    fn empty_string_returns_placeholder_for_policies() {
-        // Empty strings are fully redacted for keep/mask/email policies.
-        let keep_policy = TextRedactionPolicy::keep_first(4);
-        assert_eq!(keep_policy.apply_to(""), REDACTED_PLACEHOLDER);
+    fn placeholder_pattern_tiles_each_masked_position() {
+        let policy = TextRedactionPolicy::placeholder_pattern("Xx9");
+        assert_eq!(policy.apply_to("0123456789"), "Xx9Xx9Xx9X");
+        assert_eq!(policy.apply_to("ab"), "Xx");
+        assert_eq!(policy.apply_to("ab"), policy.apply_to("cd"));
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+        assert_eq!(
+            TextRedactionPolicy::placeholder_pattern("").apply_to("abc"),
+            "***"
+        );
 
-        let mask_policy = TextRedactionPolicy::mask_first(4);
-        assert_eq!(mask_policy.apply_to(""), REDACTED_PLACEHOLDER);
+        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(3, 2).with_mask_pattern("ab"));
+        assert_eq!(policy.apply_to("0123456789"), "aba34567ab");
 
-        let email_policy = TextRedactionPolicy::email_local(2);
-        assert_eq!(email_policy.apply_to(""), REDACTED_PLACEHOLDER);
+        let policy = TextRedactionPolicy::keep_with(KeepConfig::first(2).with_mask_pattern("Xx9"));
+        assert_eq!(policy.apply_to("sk0123456789"), "skXx9Xx9Xx9X");
+    }
 
-        let full_policy = TextRedactionPolicy::default_full();
-        assert_eq!(full_policy.apply_to(""), REDACTED_PLACEHOLDER);
+    #[test]
+    fn keep_config_accessors_report_configured_values() {
+        let config = KeepConfig::both(2, 4)
+            .with_mask_char('#')
+            .with_max_mask(6)
+            .with_preserved_separators()
+            .with_preserved_prefix("0x");
+        assert_eq!(
+            config.window(),
+            KeepWindow::Edges {
+                visible_prefix: 2,
+                visible_suffix: 4,
+            }
+        );
+        assert_eq!(config.mask_char(), Some('#'));
+        assert_eq!(config.mask_str(), Some("#"));
+        assert_eq!(config.mask_pattern(), None);
+        assert_eq!(config.max_mask(), Some(6));
+        assert!(config.preserves_separators());
+        assert_eq!(config.preserved_prefix(), Some("0x"));
+        assert!(!config.counts_graphemes());
+        assert_eq!(config.full_window_len(), 0);
+        assert_eq!(
+            KeepConfig::last(4)
+                .with_full_window_len(12)
+                .full_window_len(),
+            12
+        );
+
+        let config = KeepConfig::middle(6, 4).with_mask_str("XX");
+        assert_eq!(
+            config.window(),
+            KeepWindow::Middle {
+                skip_prefix: 6,
+                visible_len: 4,
+            }
+        );
+        assert_eq!(config.mask_char(), None);
+        assert_eq!(config.mask_str(), Some("XX"));
+        assert_eq!(config.max_mask(), None);
+        assert_eq!(config.preserved_prefix(), None);
+
+        let config = KeepConfig::ratio(0.5).with_mask_pattern("Xx9");
+        assert_eq!(config.window(), KeepWindow::Ratio { ratio: 0.5 });
+        assert_eq!(config.mask_char(), None);
+        assert_eq!(config.mask_str(), None);
+        assert_eq!(config.mask_pattern(), Some("Xx9"));
     }
 
     #[test]
-    fn keep_both_overlap_masks_entire_value() {
-        // Fail closed: when prefix + suffix >= total, mask everything
-        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2));
-        assert_eq!(policy.apply_to("abc"), "***"); // 2 + 2 = 4 >= 3
+    fn mask_and_email_config_accessors_report_configured_values() {
+        let config = MaskConfig::both(3, 2).with_mask_char('X');
+        assert_eq!(config.mask_prefix(), 3);
+        assert_eq!(config.mask_suffix(), 2);
+        assert_eq!(config.mask_char(), Some('X'));
+        assert_eq!(config.mask_pattern(), None);
+        assert!(!config.counts_graphemes());
+        assert_eq!(MaskConfig::all().mask_prefix(), usize::MAX);
+        assert_eq!(MaskConfig::last(1).mask_char(), Some(MASK_CHAR));
+
+        let config = EmailConfig::new(2)
+            .mask_domain_labels(1)
+            .with_mask_char('#');
+        assert_eq!(config.visible_prefix(), 2);
+        assert_eq!(config.masked_domain_labels(), 1);
+        assert_eq!(config.mask_char(), '#');
+        assert!(!config.counts_graphemes());
+    }
 
-        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(3, 3));
-        assert_eq!(policy.apply_to("abcd"), "****"); // 3 + 3 = 6 >= 4
+    #[test]
+    fn apply_to_os_redacts_ascii_paths() {
+        use std::ffi::OsStr;
+        use std::path::Path;
 
-        // Edge case: exactly equals total
-        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2));
-        assert_eq!(policy.apply_to("abcd"), "****"); // 2 + 2 = 4 >= 4
+        let policy = TextRedactionPolicy::keep_after_last('/');
+        let path = Path::new("/var/lib/app/secrets.toml");
+        assert_eq!(
+            policy.apply_to_os(path.as_os_str()),
+            "************/secrets.toml"
+        );
+        assert_eq!(
+            TextRedactionPolicy::default_full().apply_to_os(OsStr::new("/etc/passwd")),
+            REDACTED_PLACEHOLDER
+        );
+        assert_eq!(
+            TextRedactionPolicy::keep_last(4).apply_to_os(OsStr::new(r"C:\Users\bob")),
+            r"********\bob"
+        );
+    }
 
-        // Overflow-safe: large values still mask the entire value
-        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(usize::MAX, usize::MAX));
-        assert_eq!(policy.apply_to("abcd"), "****");
+    #[cfg(unix)]
+    #[test]
+    fn apply_to_os_redacts_the_lossy_view_of_invalid_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let value = OsStr::from_bytes(b"/srv/\xFFdata/key\xFE");
+        let policy = TextRedactionPolicy::keep_after_last('/');
+        assert_eq!(policy.apply_to_os(value), "**********/key\u{FFFD}");
+        let masked = TextRedactionPolicy::mask_with(MaskConfig::all()).apply_to_os(value);
+        assert_eq!(masked, "*".repeat(15).as_str());
     }
 
     #[test]
-    fn mask_both_overlap_masks_entire_value() {
-        // When prefix + suffix >= total, mask everything
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(2, 2));
-        assert_eq!(policy.apply_to("abc"), "***"); // 2 + 2 = 4 >= 3
+    fn chained_policies_apply_in_order() {
+        let policy = TextRedactionPolicy::email_local(2).then(TextRedactionPolicy::truncate(10));
+        assert_eq!(policy.apply_to("alice@example.com"), "al***@exam\u{2026}");
+        assert_eq!(policy.apply_to("al@ex.io"), "**@ex.io");
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
 
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(3, 3));
-        assert_eq!(policy.apply_to("abcd"), "****"); // 3 + 3 = 6 >= 4
+        // Truncating first hides the domain the email policy would keep.
+        let reversed = TextRedactionPolicy::truncate(5).then(TextRedactionPolicy::email_local(2));
+        assert_eq!(reversed.apply_to("alice@example.com"), "al****");
 
-        // Edge case: exactly equals total
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(2, 2));
-        assert_eq!(policy.apply_to("abcd"), "****"); // 2 + 2 = 4 >= 4
+        let policy = TextRedactionPolicy::keep_last(4)
+            .then(TextRedactionPolicy::mask_first(1))
+            .with_mask_char('#');
+        assert_eq!(policy.apply_to("abcdefgh"), "####efgh");
+        assert_eq!(policy.apply_to_borrowed("abcdefgh"), "####efgh");
+    }
 
-        // Overflow-safe: large values still mask entire value
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(usize::MAX, usize::MAX));
-        assert_eq!(policy.apply_to("abcd"), "****");
+    #[test]
+    fn email_domain_only_masks_the_whole_local_part() {
+        let policy = TextRedactionPolicy::email_domain_only();
+        assert_eq!(policy.apply_to("alice@example.com"), "*****@example.com");
+        assert_eq!(policy.apply_to("a@b.io"), "*@b.io");
+        assert_eq!(policy.apply_to(r#""a@b"@example.com"#), "*****@example.com");
+        assert_eq!(policy.apply_to("@example.com"), "@example.com");
+
+        // Without an `@` there is no domain to keep.
+        assert_eq!(policy.apply_to("alice"), "*****");
+        assert_eq!(policy.apply_to(""), REDACTED_PLACEHOLDER);
+
+        let policy = TextRedactionPolicy::Email(EmailConfig::new(0).mask_domain_labels(1));
+        assert_eq!(
+            policy.apply_to("alice@mail.example.com"),
+            "*****@****.example.com"
+        );
     }
 
     #[test]
-    fn keep_both_no_overlap() {
-        // Normal case: prefix + suffix < total
-        let policy = TextRedactionPolicy::keep_with(KeepConfig::both(2, 2));
-        assert_eq!(policy.apply_to("abcdef"), "ab**ef"); // keep first 2 and last 2
+    fn custom_policy_runs_the_supplied_function() {
+        fn uppercase_mask(value: &str) -> String {
+            value
+                .chars()
+                .map(|c| if c.is_alphanumeric() { 'X' } else { c })
+                .collect()
+        }
+
+        let policy = TextRedactionPolicy::custom(uppercase_mask);
+        assert_eq!(policy.apply_to("ab-12 cd"), "XX-XX XX");
+        assert_eq!(policy.apply_to(""), "");
+        assert_eq!(policy.clone().with_mask_char('#').apply_to("ab"), "XX");
+        assert_eq!(policy.apply_to_borrowed("--"), "--");
+
+        let chained =
+            TextRedactionPolicy::custom(uppercase_mask).then(TextRedactionPolicy::keep_last(2));
+        assert_eq!(chained.apply_to("abcdef"), "****XX");
     }
 
     #[test]
-    fn mask_both_no_overlap() {
-        // Normal case: prefix + suffix < total
-        let policy = TextRedactionPolicy::mask_with(MaskConfig::both(2, 2));
-        assert_eq!(policy.apply_to("abcdef"), "**cd**"); // mask first 2 and last 2
+    fn keep_checksum_reveals_exactly_one_trailing_scalar() {
+        let policy = TextRedactionPolicy::keep_checksum();
+        assert_eq!(policy.apply_to("79927398713"), "**********3");
+        assert_eq!(policy.apply_to("ab"), "*b");
+        assert_eq!(policy.apply_to("caf\u{e9}"), "***\u{e9}");
+        assert_eq!(policy.apply_to("7"), "*");
+    }
+
+    #[test]
+    fn date_policy_keeps_only_the_year() {
+        let policy = TextRedactionPolicy::keep_year();
+        assert_eq!(policy.apply_to("1990-07-14"), "1990-**-**");
+        assert_eq!(policy.apply_to("07/14/1990"), "**/**/1990");
+        assert_eq!(policy.apply_to("2000-12-31"), "2000-**-**");
+        assert_eq!(policy.apply_to("01/01/2000"), "**/**/2000");
+
+        let policy = TextRedactionPolicy::date_with(DateConfig::year_only().with_mask_char('x'));
+        assert_eq!(policy.apply_to("1990-07-14"), "1990-xx-xx");
+        let policy = TextRedactionPolicy::keep_year().with_mask_char('#');
+        assert_eq!(policy.apply_to("07/14/1990"), "##/##/1990");
+    }
+
+    #[test]
+    fn date_policy_fully_redacts_unparseable_input() {
+        let policy = TextRedactionPolicy::keep_year();
+        for value in [
+            "",
+            "garbage!!!",
+            "July 14, 1990",
+            "1990/07/14",
+            "14-07-1990",
+            "1990-13-01",
+            "1990-00-10",
+            "1990-07-32",
+            "13/14/1990",
+            "07/14/19x0",
+            " 1990-07-14",
+            "1990-07-14T00:00",
+            "1990-7-14",
+            "1990-07/14",
+            "+990-07-14",
+        ] {
+            assert_eq!(policy.apply_to(value), REDACTED_PLACEHOLDER, "{value:?}");
+        }
     }
 }
@@ -3,76 +3,92 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 
 use crate::{
-    policy::IpAddress,
-    redaction::{
-        display::RedactableWithFormatter,
-        redact::RedactableMapper,
-        traits::{RedactableWithMapper, SensitiveWithPolicy},
-    },
+    policy::{IpAddress, IpConfig, TextRedactionPolicy},
+    redaction::traits::SensitiveWithPolicy,
 };
 
-// Preserve a valid address by zeroing all but the last segment.
-fn redact_ipv4(addr: Ipv4Addr) -> Ipv4Addr {
-    let octets = addr.octets();
-    Ipv4Addr::new(0, 0, 0, octets[3])
+/// Returns the network split of `policy`, falling back to the `IpAddress`
+/// default so typed and text values mask the same host segments.
+fn ip_config(policy: &TextRedactionPolicy) -> IpConfig {
+    match policy {
+        TextRedactionPolicy::Ip(config) => *config,
+        _ => IpConfig::default(),
+    }
+}
+
+// Preserve a valid address by zeroing the masked host octets.
+fn redact_ipv4(addr: Ipv4Addr, config: &IpConfig) -> Ipv4Addr {
+    let mut octets = addr.octets();
+    let visible = octets.len().saturating_sub(config.masked_v4_octets());
+    octets[visible..].fill(0);
+    Ipv4Addr::from(octets)
 }
 
-fn redact_ipv6(addr: Ipv6Addr) -> Ipv6Addr {
+fn redact_ipv6(addr: Ipv6Addr, config: &IpConfig) -> Ipv6Addr {
     // Dual-stack listeners commonly observe IPv4 clients as IPv4-mapped IPv6
-    // addresses (::ffff:a.b.c.d). The last 16-bit segment of those holds the
-    // last TWO octets of the embedded IPv4, so redact the embedded address
-    // with the IPv4 rule (keep only the final octet) instead.
+    // addresses (::ffff:a.b.c.d). The trailing segments of those hold the
+    // embedded IPv4, so redact the embedded address with the IPv4 rule instead.
     if let Some(v4) = addr.to_ipv4_mapped() {
-        return redact_ipv4(v4).to_ipv6_mapped();
+        return redact_ipv4(v4, config).to_ipv6_mapped();
     }
-    let segments = addr.segments();
-    Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, segments[7])
+    let mut segments = addr.segments();
+    let visible = segments.len().saturating_sub(config.masked_v6_segments());
+    segments[visible..].fill(0);
+    Ipv6Addr::from(segments)
 }
 
 impl SensitiveWithPolicy<IpAddress> for Ipv4Addr {
-    fn redact_with_policy(self, _policy: &crate::policy::TextRedactionPolicy) -> Self {
-        redact_ipv4(self)
+    fn redact_with_policy(self, policy: &TextRedactionPolicy) -> Self {
+        let config = ip_config(policy);
+        redact_ipv4(self, &config)
     }
 
-    fn redacted_string(&self, _policy: &crate::policy::TextRedactionPolicy) -> String {
-        redact_ipv4(*self).to_string()
+    fn redacted_string(&self, policy: &TextRedactionPolicy) -> String {
+        let config = ip_config(policy);
+        redact_ipv4(*self, &config).to_string()
     }
 }
 
 impl SensitiveWithPolicy<IpAddress> for Ipv6Addr {
-    fn redact_with_policy(self, _policy: &crate::policy::TextRedactionPolicy) -> Self {
-        redact_ipv6(self)
+    fn redact_with_policy(self, policy: &TextRedactionPolicy) -> Self {
+        let config = ip_config(policy);
+        redact_ipv6(self, &config)
     }
 
-    fn redacted_string(&self, _policy: &crate::policy::TextRedactionPolicy) -> String {
-        redact_ipv6(*self).to_string()
+    fn redacted_string(&self, policy: &TextRedactionPolicy) -> String {
+        let config = ip_config(policy);
+        redact_ipv6(*self, &config).to_string()
     }
 }
 
 impl SensitiveWithPolicy<IpAddress> for IpAddr {
-    fn redact_with_policy(self, _policy: &crate::policy::TextRedactionPolicy) -> Self {
+    fn redact_with_policy(self, policy: &TextRedactionPolicy) -> Self {
+        let config = ip_config(policy);
         match self {
-            IpAddr::V4(addr) => IpAddr::V4(redact_ipv4(addr)),
-            IpAddr::V6(addr) => IpAddr::V6(redact_ipv6(addr)),
+            IpAddr::V4(addr) => IpAddr::V4(redact_ipv4(addr, &config)),
+            IpAddr::V6(addr) => IpAddr::V6(redact_ipv6(addr, &config)),
         }
     }
 
-    fn redacted_string(&self, _policy: &crate::policy::TextRedactionPolicy) -> String {
+    fn redacted_string(&self, policy: &TextRedactionPolicy) -> String {
+        let config = ip_config(policy);
         match self {
-            IpAddr::V4(addr) => redact_ipv4(*addr).to_string(),
-            IpAddr::V6(addr) => redact_ipv6(*addr).to_string(),
+            IpAddr::V4(addr) => redact_ipv4(*addr, &config).to_string(),
+            IpAddr::V6(addr) => redact_ipv6(*addr, &config).to_string(),
         }
     }
 }
 
 impl SensitiveWithPolicy<IpAddress> for SocketAddr {
-    fn redact_with_policy(self, _policy: &crate::policy::TextRedactionPolicy) -> Self {
+    fn redact_with_policy(self, policy: &TextRedactionPolicy) -> Self {
+        let config = ip_config(policy);
         match self {
-            SocketAddr::V4(addr) => {
-                SocketAddr::V4(SocketAddrV4::new(redact_ipv4(*addr.ip()), addr.port()))
-            }
+            SocketAddr::V4(addr) => SocketAddr::V4(SocketAddrV4::new(
+                redact_ipv4(*addr.ip(), &config),
+                addr.port(),
+            )),
             SocketAddr::V6(addr) => SocketAddr::V6(SocketAddrV6::new(
-                redact_ipv6(*addr.ip()),
+                redact_ipv6(*addr.ip(), &config),
                 addr.port(),
                 addr.flowinfo(),
                 addr.scope_id(),
@@ -80,13 +96,16 @@ impl SensitiveWithPolicy<IpAddress> for SocketAddr {
         }
     }
 
-    fn redacted_string(&self, _policy: &crate::policy::TextRedactionPolicy) -> String {
+    fn redacted_string(&self, policy: &TextRedactionPolicy) -> String {
+        let config = ip_config(policy);
         match self {
-            SocketAddr::V4(addr) => {
-                SocketAddr::V4(SocketAddrV4::new(redact_ipv4(*addr.ip()), addr.port())).to_string()
-            }
+            SocketAddr::V4(addr) => SocketAddr::V4(SocketAddrV4::new(
+                redact_ipv4(*addr.ip(), &config),
+                addr.port(),
+            ))
+            .to_string(),
             SocketAddr::V6(addr) => SocketAddr::V6(SocketAddrV6::new(
-                redact_ipv6(*addr.ip()),
+                redact_ipv6(*addr.ip(), &config),
                 addr.port(),
                 addr.flowinfo(),
                 addr.scope_id(),
@@ -96,54 +115,6 @@ impl SensitiveWithPolicy<IpAddress> for SocketAddr {
     }
 }
 
-impl RedactableWithMapper for Ipv4Addr {
-    fn redact_with<M: RedactableMapper>(self, _mapper: &M) -> Self {
-        self
-    }
-}
-
-impl RedactableWithMapper for Ipv6Addr {
-    fn redact_with<M: RedactableMapper>(self, _mapper: &M) -> Self {
-        self
-    }
-}
-
-impl RedactableWithMapper for IpAddr {
-    fn redact_with<M: RedactableMapper>(self, _mapper: &M) -> Self {
-        self
-    }
-}
-
-impl RedactableWithMapper for SocketAddr {
-    fn redact_with<M: RedactableMapper>(self, _mapper: &M) -> Self {
-        self
-    }
-}
-
-impl RedactableWithFormatter for Ipv4Addr {
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self, f)
-    }
-}
-
-impl RedactableWithFormatter for Ipv6Addr {
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self, f)
-    }
-}
-
-impl RedactableWithFormatter for IpAddr {
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self, f)
-    }
-}
-
-impl RedactableWithFormatter for SocketAddr {
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self, f)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
@@ -152,27 +123,49 @@ mod tests {
     use crate::policy::{IpAddress, RedactionPolicy};
 
     #[test]
-    fn ipv4_keeps_only_last_octet() {
+    fn ipv4_keeps_the_network_prefix() {
         let addr = Ipv4Addr::new(203, 0, 113, 77);
         let redacted = addr.redact_with_policy(&IpAddress::policy());
-        assert_eq!(redacted, Ipv4Addr::new(0, 0, 0, 77));
+        assert_eq!(redacted, Ipv4Addr::new(203, 0, 0, 0));
     }
 
     #[test]
-    fn ipv6_keeps_only_last_segment() {
-        let addr = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0xabcd, 0x1234);
+    fn ipv6_keeps_the_network_prefix() {
+        let addr = Ipv6Addr::new(0x2001, 0xdb8, 0x85a3, 0, 0, 0, 0xabcd, 0x1234);
         let redacted = addr.redact_with_policy(&IpAddress::policy());
-        assert_eq!(redacted, Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0x1234));
+        assert_eq!(
+            redacted,
+            Ipv6Addr::new(0x2001, 0xdb8, 0x85a3, 0, 0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn typed_and_text_values_reveal_the_same_prefix() {
+        // A record that logs an address both as text and as a typed value must
+        // not reveal more of it than either form does on its own.
+        let policy = IpAddress::policy();
+        let addr = Ipv4Addr::new(192, 168, 1, 42);
+        assert_eq!(addr.redacted_string(&policy), "192.168.0.0");
+        assert_eq!(policy.apply_to("192.168.1.42"), "192.168.*.*");
+    }
+
+    #[test]
+    fn typed_values_follow_a_custom_network_split() {
+        let policy = TextRedactionPolicy::ip_network(1, 6);
+        let v4 = Ipv4Addr::new(10, 20, 30, 40).redact_with_policy(&policy);
+        assert_eq!(v4, Ipv4Addr::new(10, 20, 30, 0));
+        let v6 = Ipv6Addr::new(0x2001, 0xdb8, 1, 2, 3, 4, 5, 6).redact_with_policy(&policy);
+        assert_eq!(v6, Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0));
     }
 
     #[test]
     fn ipv4_mapped_ipv6_redacts_like_ipv4() {
         // ::ffff:203.0.113.77 - the standard dual-stack representation of an
-        // IPv4 client. The last v6 segment holds TWO IPv4 octets, so applying
-        // the plain v6 rule would leak twice as much as the v4 rule.
+        // IPv4 client. Its trailing v6 segments hold the IPv4 host octets, so
+        // the embedded address takes the v4 rule.
         let addr = Ipv4Addr::new(203, 0, 113, 77).to_ipv6_mapped();
         let redacted = addr.redact_with_policy(&IpAddress::policy());
-        assert_eq!(redacted, Ipv4Addr::new(0, 0, 0, 77).to_ipv6_mapped());
+        assert_eq!(redacted, Ipv4Addr::new(203, 0, 0, 0).to_ipv6_mapped());
     }
 
     #[test]
@@ -187,7 +180,7 @@ mod tests {
         let SocketAddr::V6(redacted) = redacted else {
             panic!("variant must be preserved");
         };
-        assert_eq!(*redacted.ip(), Ipv4Addr::new(0, 0, 0, 77).to_ipv6_mapped());
+        assert_eq!(*redacted.ip(), Ipv4Addr::new(203, 0, 0, 0).to_ipv6_mapped());
         assert_eq!(redacted.port(), 8443);
     }
 }
@@ -1,4 +1,7 @@
 //! Redaction traversal for map containers (values only).
+//!
+//! With the `indexmap` feature, `IndexMap` is traversed the same way and keeps
+//! its insertion order.
 
 use std::{
     collections::{BTreeMap, HashMap},
@@ -40,3 +43,20 @@ where
             .collect()
     }
 }
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> RedactableWithMapper for indexmap::IndexMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: RedactableWithMapper,
+    S: std::hash::BuildHasher + Clone,
+{
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        // NOTE: Map keys are not redacted by design. Entries are rebuilt in
+        // iteration order, so the insertion order survives redaction.
+        let hasher = self.hasher().clone();
+        let mut result = indexmap::IndexMap::with_capacity_and_hasher(self.len(), hasher);
+        result.extend(self.into_iter().map(|(k, v)| (k, v.redact_with(mapper))));
+        result
+    }
+}
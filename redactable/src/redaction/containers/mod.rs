@@ -1,9 +1,9 @@
 //! `RedactableWithMapper` implementations for standard library types.
 //!
 //! This module provides `RedactableWithMapper` implementations for common std
-//! containers (`Option`, `Vec`, `VecDeque`, arrays, tuples, `Box`, locks, maps,
-//! sets). When walking into these containers, they recursively apply redaction
-//! to their contents.
+//! containers (`Option`, `Vec`, `VecDeque`, `LinkedList`, `BinaryHeap`, arrays,
+//! tuples, `Box`, locks, maps, sets). When walking into these containers, they
+//! recursively apply redaction to their contents.
 //!
 //! Passthrough leaf types still are not certified for `.redact()`. Container
 //! certification forwards only when the contained values have declared
@@ -44,6 +44,24 @@
 //! let _ = value.redact();
 //! ```
 //!
+//! ## Element Bounds
+//!
+//! Traversal rebuilds each container from its redacted contents and never
+//! needs `Default` or a placeholder element. Beyond `RedactableWithMapper` on
+//! the contents, the impls only require what rebuilding the container needs:
+//!
+//! | Container | Extra bounds |
+//! |---|---|
+//! | `Option`, `Result`, `Vec`, `VecDeque`, `LinkedList`, `[T; N]`, tuples, `Box` | none |
+//! | `Mutex`, `RwLock`, `RefCell` | none (poisoned locks are recovered) |
+//! | `Arc`, `Rc` | `T: Clone` (shared values are cloned out) |
+//! | `Cell` | `T: Copy` |
+//! | `HashMap<K, V, S>` | `K: Hash + Eq`, `S: BuildHasher + Clone` (keys pass through) |
+//! | `BTreeMap<K, V>` | `K: Ord` (keys pass through) |
+//! | `HashSet<T, S>` | `T: Hash + Eq`, `S: BuildHasher + Clone` |
+//! | `BTreeSet<T>` | `T: Ord` |
+//! | `BinaryHeap<T>` | `T: Ord` |
+//!
 //! ## Map Keys Are Not Redacted
 //!
 //! For map containers (`HashMap`, `BTreeMap`), only **values** are redacted.
@@ -56,6 +74,14 @@
 //! element and the results are collected back into a set. If redaction changes
 //! equality or ordering (e.g., multiple values redact to `"[REDACTED]"`), the
 //! resulting set may shrink.
+//!
+//! ## Heap Order Follows the Redacted Values
+//!
+//! `BinaryHeap` is rebuilt from its redacted elements, so the result is a valid
+//! heap ordered by the redacted values. If redaction changes how elements
+//! compare, the element that `peek` returns may differ from the original.
+//! Like set collapse, this is inherent to ordered containers: do not rely on
+//! the pre-redaction priority order surviving redaction.
 
 mod cells;
 #[cfg(feature = "ip-address")]
@@ -1,74 +1,149 @@
 //! Passthrough `RedactableWithMapper` implementations for scalar-like types.
+//!
+//! Strings, primitive scalars, and the other std and date/time leaves route
+//! through the mapper's unannotated-leaf hooks, which return them unchanged
+//! except under [`crate::RedactEverything`].
 
 use std::{
     borrow::Cow,
     cmp::Ordering,
     marker::PhantomData,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
     num::{
         NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
         NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
     },
-    time::{Duration, Instant, SystemTime},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use super::impl_redactable_container_passthrough;
-use crate::redaction::{redact::RedactableMapper, traits::RedactableWithMapper};
+use crate::redaction::{
+    redact::{PassthroughRedaction, RedactableMapper},
+    traits::RedactableWithMapper,
+};
 
 // =============================================================================
 // Passthrough implementations (scalars and primitives)
 // =============================================================================
 
-impl_redactable_container_passthrough!(String);
-impl_redactable_container_passthrough!(bool);
-impl_redactable_container_passthrough!(char);
+impl RedactableWithMapper for String {
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        mapper.map_unannotated_text(self)
+    }
+}
+
+/// Scalars consult the mapper so [`crate::RedactEverything`] can redact them.
+macro_rules! impl_redactable_scalar_leaf {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl RedactableWithMapper for $ty {
+                fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+                    mapper.map_unannotated_scalar(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_redactable_scalar_leaf!(bool, char);
 
 // Signed integers
-impl_redactable_container_passthrough!(i8);
-impl_redactable_container_passthrough!(i16);
-impl_redactable_container_passthrough!(i32);
-impl_redactable_container_passthrough!(i64);
-impl_redactable_container_passthrough!(i128);
-impl_redactable_container_passthrough!(isize);
+impl_redactable_scalar_leaf!(i8, i16, i32, i64, i128, isize);
 
 // Unsigned integers
-impl_redactable_container_passthrough!(u8);
-impl_redactable_container_passthrough!(u16);
-impl_redactable_container_passthrough!(u32);
-impl_redactable_container_passthrough!(u64);
-impl_redactable_container_passthrough!(u128);
-impl_redactable_container_passthrough!(usize);
+impl_redactable_scalar_leaf!(u8, u16, u32, u64, u128, usize);
 
 // Floating point
-impl_redactable_container_passthrough!(f32);
-impl_redactable_container_passthrough!(f64);
+impl_redactable_scalar_leaf!(f32, f64);
 
 // Unit type
 impl_redactable_container_passthrough!(());
 
+/// Other leaves consult the mapper so [`crate::RedactEverything`] can replace
+/// them with their [`PassthroughRedaction`] placeholder.
+macro_rules! impl_redactable_passthrough_leaf {
+    ($($ty:ty => |$value:pat_param| $placeholder:expr),* $(,)?) => {
+        $(
+            impl crate::redaction::redact::PassthroughRedaction for $ty {
+                fn redact(self) -> Self {
+                    let $value = self;
+                    $placeholder
+                }
+            }
+
+            impl crate::redaction::traits::RedactableWithMapper for $ty {
+                fn redact_with<M: crate::redaction::redact::RedactableMapper>(
+                    self,
+                    mapper: &M,
+                ) -> Self {
+                    mapper.map_unannotated_passthrough(self)
+                }
+            }
+        )*
+    };
+}
+
 // =============================================================================
 // NonZero integer passthrough implementations
 // =============================================================================
 
-impl_redactable_container_passthrough!(NonZeroI8);
-impl_redactable_container_passthrough!(NonZeroI16);
-impl_redactable_container_passthrough!(NonZeroI32);
-impl_redactable_container_passthrough!(NonZeroI64);
-impl_redactable_container_passthrough!(NonZeroI128);
-impl_redactable_container_passthrough!(NonZeroIsize);
-impl_redactable_container_passthrough!(NonZeroU8);
-impl_redactable_container_passthrough!(NonZeroU16);
-impl_redactable_container_passthrough!(NonZeroU32);
-impl_redactable_container_passthrough!(NonZeroU64);
-impl_redactable_container_passthrough!(NonZeroU128);
-impl_redactable_container_passthrough!(NonZeroUsize);
+macro_rules! impl_nonzero_passthrough_leaf {
+    ($($ty:ident),* $(,)?) => {
+        impl_redactable_passthrough_leaf!($(
+            $ty => |_| const {
+                match $ty::new(1) {
+                    Some(one) => one,
+                    None => unreachable!(),
+                }
+            }
+        ),*);
+    };
+}
+
+impl_nonzero_passthrough_leaf!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+);
 
 // =============================================================================
 // std::time passthrough implementations
 // =============================================================================
 
-impl_redactable_container_passthrough!(Duration);
+impl_redactable_passthrough_leaf!(
+    Duration => |_| Duration::ZERO,
+    SystemTime => |_| UNIX_EPOCH,
+);
+
+// An `Instant` has no fixed value to stand in for it, so it always passes
+// through.
 impl_redactable_container_passthrough!(Instant);
-impl_redactable_container_passthrough!(SystemTime);
+
+// =============================================================================
+// std::net passthrough implementations
+// =============================================================================
+
+// Unannotated addresses pass through the default mapper;
+// `#[sensitive(IpAddress)]` (behind the `ip-address` feature) is the opt-in
+// that masks them. `RedactEverything` keeps only the address family.
+impl_redactable_passthrough_leaf!(
+    IpAddr => |value| match value {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+    },
+    Ipv4Addr => |_| Ipv4Addr::UNSPECIFIED,
+    Ipv6Addr => |_| Ipv6Addr::UNSPECIFIED,
+    SocketAddr => |value| SocketAddr::new(PassthroughRedaction::redact(value.ip()), 0),
+);
 
 // =============================================================================
 // Other std passthrough implementations
@@ -83,8 +158,14 @@ impl<T> RedactableWithMapper for PhantomData<T> {
 }
 
 impl RedactableWithMapper for Cow<'_, str> {
-    fn redact_with<M: RedactableMapper>(self, _mapper: &M) -> Self {
-        self
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        mapper.map_unannotated_text(self)
+    }
+}
+
+impl RedactableWithMapper for Box<str> {
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        mapper.map_unannotated_text(self)
     }
 }
 
@@ -102,24 +183,30 @@ mod chrono_passthrough {
     use super::impl_redactable_container_passthrough;
 
     // DateTime variants
-    impl_redactable_container_passthrough!(DateTime<Utc>);
-    impl_redactable_container_passthrough!(DateTime<Local>);
-    impl_redactable_container_passthrough!(DateTime<FixedOffset>);
+    impl_redactable_passthrough_leaf!(
+        DateTime<Utc> => |_| DateTime::UNIX_EPOCH,
+        DateTime<Local> => |_| DateTime::UNIX_EPOCH.with_timezone(&Local),
+        DateTime<FixedOffset> => |_| DateTime::UNIX_EPOCH.fixed_offset(),
+    );
 
     // Timezone marker
     impl_redactable_container_passthrough!(Utc);
 
     // Naive date/time types
-    impl_redactable_container_passthrough!(NaiveDateTime);
-    impl_redactable_container_passthrough!(NaiveDate);
-    impl_redactable_container_passthrough!(NaiveTime);
+    impl_redactable_passthrough_leaf!(
+        NaiveDateTime => |_| DateTime::UNIX_EPOCH.naive_utc(),
+        NaiveDate => |_| DateTime::UNIX_EPOCH.date_naive(),
+        NaiveTime => |_| NaiveTime::MIN,
+    );
 
     // Duration (TimeDelta is an alias for Duration)
-    impl_redactable_container_passthrough!(Duration);
+    impl_redactable_passthrough_leaf!(Duration => |_| Duration::zero());
 
     // Calendar enums
-    impl_redactable_container_passthrough!(Month);
-    impl_redactable_container_passthrough!(Weekday);
+    impl_redactable_passthrough_leaf!(
+        Month => |_| Month::January,
+        Weekday => |_| Weekday::Mon,
+    );
 }
 
 #[cfg(feature = "time")]
@@ -128,25 +215,32 @@ mod time_passthrough {
         Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
     };
 
-    use super::impl_redactable_container_passthrough;
-
     // DateTime types
-    impl_redactable_container_passthrough!(OffsetDateTime);
-    impl_redactable_container_passthrough!(PrimitiveDateTime);
+    impl_redactable_passthrough_leaf!(
+        OffsetDateTime => |_| OffsetDateTime::UNIX_EPOCH,
+        PrimitiveDateTime => |_| PrimitiveDateTime::new(
+            OffsetDateTime::UNIX_EPOCH.date(),
+            Time::MIDNIGHT,
+        ),
+    );
 
     // Date/time components
-    impl_redactable_container_passthrough!(Date);
-    impl_redactable_container_passthrough!(Time);
+    impl_redactable_passthrough_leaf!(
+        Date => |_| OffsetDateTime::UNIX_EPOCH.date(),
+        Time => |_| Time::MIDNIGHT,
+    );
 
     // Duration
-    impl_redactable_container_passthrough!(Duration);
+    impl_redactable_passthrough_leaf!(Duration => |_| Duration::ZERO);
 
     // Timezone offset
-    impl_redactable_container_passthrough!(UtcOffset);
+    impl_redactable_passthrough_leaf!(UtcOffset => |_| UtcOffset::UTC);
 
     // Calendar enums
-    impl_redactable_container_passthrough!(Month);
-    impl_redactable_container_passthrough!(Weekday);
+    impl_redactable_passthrough_leaf!(
+        Month => |_| Month::January,
+        Weekday => |_| Weekday::Monday,
+    );
 }
 
 // =============================================================================
@@ -157,7 +251,5 @@ mod time_passthrough {
 mod uuid_passthrough {
     use uuid::Uuid;
 
-    use super::impl_redactable_container_passthrough;
-
-    impl_redactable_container_passthrough!(Uuid);
+    impl_redactable_passthrough_leaf!(Uuid => |_| Uuid::nil());
 }
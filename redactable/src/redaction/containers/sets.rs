@@ -1,4 +1,7 @@
 //! Redaction traversal for set containers.
+//!
+//! With the `indexmap` feature, `IndexSet` is traversed the same way and keeps
+//! the insertion order of the elements that survive collapse.
 
 use std::{
     collections::{BTreeSet, HashSet},
@@ -38,3 +41,19 @@ where
             .collect()
     }
 }
+
+#[cfg(feature = "indexmap")]
+impl<T, S> RedactableWithMapper for indexmap::IndexSet<T, S>
+where
+    T: RedactableWithMapper + Hash + Eq,
+    S: std::hash::BuildHasher + Clone,
+{
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        // NOTE: Redaction can collapse distinct values into equal ones; the
+        // first occurrence keeps its position and later duplicates are dropped.
+        let hasher = self.hasher().clone();
+        let mut result = indexmap::IndexSet::with_capacity_and_hasher(self.len(), hasher);
+        result.extend(self.into_iter().map(|value| value.redact_with(mapper)));
+        result
+    }
+}
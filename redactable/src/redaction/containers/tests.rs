@@ -86,6 +86,99 @@ fn passthrough_ordering_unchanged() {
     assert_eq!(machine_redact(Ordering::Greater), Ordering::Greater);
 }
 
+/// Runs the traversal machinery with [`crate::RedactEverything`].
+fn machine_redact_everything<T: crate::redaction::traits::RedactableWithMapper>(value: T) -> T {
+    crate::redaction::traits::RedactableWithMapper::redact_with(value, &crate::RedactEverything)
+}
+
+#[test]
+fn redact_everything_replaces_std_passthrough_leaves() {
+    use std::{
+        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+        num::{NonZeroI32, NonZeroU64},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    assert_eq!(
+        machine_redact_everything(NonZeroI32::new(-42).unwrap()).get(),
+        1
+    );
+    assert_eq!(
+        machine_redact_everything(NonZeroU64::new(100).unwrap()).get(),
+        1
+    );
+    assert_eq!(
+        machine_redact_everything(Duration::from_mins(1)),
+        Duration::ZERO
+    );
+    assert_eq!(machine_redact_everything(SystemTime::now()), UNIX_EPOCH);
+    assert_eq!(
+        machine_redact_everything(IpAddr::from([10, 1, 2, 3])),
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+    );
+    assert_eq!(
+        machine_redact_everything(Ipv6Addr::LOCALHOST),
+        Ipv6Addr::UNSPECIFIED
+    );
+    assert_eq!(
+        machine_redact_everything(SocketAddr::from((Ipv6Addr::LOCALHOST, 8443))),
+        SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0))
+    );
+
+    // The default mapper still passes them through.
+    let socket = SocketAddr::from(([10, 1, 2, 3], 8443));
+    assert_eq!(machine_redact(socket), socket);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn redact_everything_replaces_chrono_leaves() {
+    use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc, Weekday};
+
+    let now = Utc::now();
+    assert_eq!(machine_redact_everything(now), DateTime::UNIX_EPOCH);
+    assert_eq!(
+        machine_redact_everything(now.fixed_offset()),
+        DateTime::UNIX_EPOCH.fixed_offset()
+    );
+    assert_eq!(
+        machine_redact_everything(now.date_naive()),
+        NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+    );
+    assert_eq!(machine_redact_everything(now.time()), NaiveTime::MIN);
+    assert_eq!(
+        machine_redact_everything(Duration::minutes(5)),
+        Duration::zero()
+    );
+    assert_eq!(machine_redact_everything(Weekday::Fri), Weekday::Mon);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn redact_everything_replaces_time_leaves() {
+    use time::{Duration, OffsetDateTime, Time, UtcOffset};
+
+    let now = OffsetDateTime::now_utc();
+    assert_eq!(machine_redact_everything(now), OffsetDateTime::UNIX_EPOCH);
+    assert_eq!(machine_redact_everything(now.time()), Time::MIDNIGHT);
+    assert_eq!(
+        machine_redact_everything(Duration::minutes(5)),
+        Duration::ZERO
+    );
+    assert_eq!(
+        machine_redact_everything(UtcOffset::from_hms(2, 0, 0).unwrap()),
+        UtcOffset::UTC
+    );
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn redact_everything_replaces_uuid_leaves() {
+    let id = uuid::Uuid::from_u128(0x1234_5678_9abc_def0);
+    assert_eq!(machine_redact_everything(id), uuid::Uuid::nil());
+    assert_eq!(machine_redact(id), id);
+}
+
 #[test]
 fn option_traversal_redacts_inner() {
     let value = Some(SensitiveString {
@@ -173,6 +266,30 @@ fn array_traversal_redacts_all_elements() {
     );
 }
 
+#[test]
+fn vec_traversal_redacts_large_vectors_in_order() {
+    let values: Vec<(usize, SensitiveString)> = (0..10_000)
+        .map(|index| {
+            (
+                index,
+                SensitiveString {
+                    value: format!("secret-{index}"),
+                },
+            )
+        })
+        .collect();
+
+    let redacted = machine_redact(values);
+
+    assert_eq!(redacted.len(), 10_000);
+    assert!(
+        redacted
+            .iter()
+            .enumerate()
+            .all(|(position, (index, value))| *index == position && value.value == "[REDACTED]")
+    );
+}
+
 #[test]
 fn tuple_traversal_redacts_all_arities() {
     let single = (SensitiveString {
@@ -406,6 +523,72 @@ fn map_keys_are_never_redacted() {
     assert_eq!(redacted[&key].value, "[REDACTED]");
 }
 
+/// Element type with no `Default` impl; a `NonZeroU32` field has no zero
+/// value to fall back on, so traversal must rebuild it from the input.
+#[derive(Clone, Sensitive)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+struct NoDefault {
+    id: std::num::NonZeroU32,
+    #[sensitive(Secret)]
+    value: String,
+}
+
+fn no_default(id: u32) -> NoDefault {
+    NoDefault {
+        id: std::num::NonZeroU32::new(id).unwrap(),
+        value: "secret".to_string(),
+    }
+}
+
+#[test]
+fn containers_redact_default_less_elements() {
+    let redacted = vec![no_default(1), no_default(2)].redact();
+    assert_eq!(redacted[1].id.get(), 2);
+    assert!(redacted.iter().all(|item| item.value == "[REDACTED]"));
+
+    let redacted = Some(no_default(3)).redact().unwrap();
+    assert_eq!(redacted.id.get(), 3);
+    assert_eq!(redacted.value, "[REDACTED]");
+}
+
+#[test]
+fn maps_redact_default_less_values() {
+    let map: HashMap<String, NoDefault> = HashMap::from([("a".to_string(), no_default(4))]);
+    let redacted = map.redact();
+    assert_eq!(redacted["a"].id.get(), 4);
+    assert_eq!(redacted["a"].value, "[REDACTED]");
+
+    let map: BTreeMap<u8, Vec<NoDefault>> = BTreeMap::from([(1, vec![no_default(5)])]);
+    let redacted = map.redact();
+    assert_eq!(redacted[&1][0].id.get(), 5);
+    assert_eq!(redacted[&1][0].value, "[REDACTED]");
+}
+
+#[test]
+fn map_values_never_call_default() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "json", derive(serde::Serialize))]
+    struct PanicsOnDefault {
+        #[sensitive(Secret)]
+        value: String,
+    }
+
+    impl Default for PanicsOnDefault {
+        fn default() -> Self {
+            panic!("map traversal must not construct default values");
+        }
+    }
+
+    let map: HashMap<String, PanicsOnDefault> = HashMap::from([(
+        "a".to_string(),
+        PanicsOnDefault {
+            value: "secret".to_string(),
+        },
+    )]);
+    let redacted = map.redact();
+    assert_eq!(redacted["a"].value, "[REDACTED]");
+}
+
 #[test]
 fn btreeset_traversal_keeps_elements() {
     let mut set: BTreeSet<String> = BTreeSet::new();
@@ -520,7 +703,7 @@ fn ip_in_container_redacts_via_sensitive_value_workaround() {
     let inner = redacted.addr.expect("Some is preserved");
     assert_eq!(
         *inner.expose(),
-        "0.0.0.77".parse::<IpAddr>().expect("valid IPv4")
+        "203.0.0.0".parse::<IpAddr>().expect("valid IPv4")
     );
 }
 
@@ -553,10 +736,10 @@ fn annotated_ipaddr_redacts() {
     };
     let redacted = connection.redact();
 
-    assert_eq!(redacted.ip, "0.0.0.100".parse::<IpAddr>().unwrap());
+    assert_eq!(redacted.ip, "192.168.0.0".parse::<IpAddr>().unwrap());
     assert_eq!(
         redacted.socket,
-        "0.0.0.3:443".parse::<SocketAddr>().unwrap()
+        "10.1.0.0:443".parse::<SocketAddr>().unwrap()
     );
 
     let display_connection = DisplayConnection {
@@ -564,7 +747,7 @@ fn annotated_ipaddr_redacts() {
     };
     assert_eq!(
         display_connection.redacted_display().to_string(),
-        "client 0.0.0.100"
+        "client 192.168.0.0"
     );
 }
 
@@ -661,3 +844,58 @@ fn passthrough_uuid_formatter_unchanged() {
     let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").expect("valid UUID");
     assert_eq!(id.redacted_display().to_string(), id.to_string());
 }
+
+// =============================================================================
+// indexmap traversal tests
+// =============================================================================
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexmap_traversal_redacts_values_in_insertion_order() {
+    use indexmap::IndexMap;
+
+    let secret = |value: &str| SensitiveString {
+        value: value.to_string(),
+    };
+    let mut map: IndexMap<String, SensitiveString> = IndexMap::new();
+    map.insert("zeta".to_string(), secret("one"));
+    map.insert("alpha".to_string(), secret("two"));
+    map.insert("mu".to_string(), secret("three"));
+
+    let redacted = map.redact();
+
+    let keys: Vec<_> = redacted.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["zeta", "alpha", "mu"]);
+    assert!(redacted.values().all(|entry| entry.value == "[REDACTED]"));
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn indexset_traversal_keeps_first_occurrence_order() {
+    use indexmap::IndexSet;
+
+    #[derive(Clone, Hash, Eq, PartialEq, Sensitive)]
+    #[cfg_attr(feature = "json", derive(serde::Serialize))]
+    struct Tagged {
+        tag: String,
+        #[sensitive(Secret)]
+        value: String,
+    }
+
+    let tagged = |tag: &str, value: &str| Tagged {
+        tag: tag.to_string(),
+        value: value.to_string(),
+    };
+    let set: IndexSet<Tagged> = IndexSet::from([
+        tagged("b", "one"),
+        tagged("a", "two"),
+        tagged("b", "three"),
+        tagged("c", "four"),
+    ]);
+
+    let redacted = set.redact();
+
+    let tags: Vec<_> = redacted.iter().map(|entry| entry.tag.as_str()).collect();
+    assert_eq!(tags, ["b", "a", "c"]);
+    assert!(redacted.iter().all(|entry| entry.value == "[REDACTED]"));
+}
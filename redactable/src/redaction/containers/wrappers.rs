@@ -1,7 +1,7 @@
 //! Redaction traversal for wrapper container types.
 
 use std::{
-    collections::VecDeque,
+    collections::{BinaryHeap, LinkedList, VecDeque},
     sync::{Mutex, RwLock},
 };
 
@@ -37,6 +37,8 @@ impl<T> RedactableWithMapper for Vec<T>
 where
     T: RedactableWithMapper,
 {
+    // `Vec::into_iter().map(..).collect()` with an unchanged element type lets
+    // std reuse the source buffer. That is an optimization, not a guarantee.
     fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
         self.into_iter()
             .map(|value| value.redact_with(mapper))
@@ -55,6 +57,31 @@ where
     }
 }
 
+impl<T> RedactableWithMapper for LinkedList<T>
+where
+    T: RedactableWithMapper,
+{
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        self.into_iter()
+            .map(|value| value.redact_with(mapper))
+            .collect()
+    }
+}
+
+impl<T> RedactableWithMapper for BinaryHeap<T>
+where
+    T: RedactableWithMapper + Ord,
+{
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        // NOTE: Redaction can change how elements compare (e.g., distinct values
+        // redacting to "[REDACTED]"), so collecting re-heapifies the result
+        // against the redacted ordering.
+        self.into_iter()
+            .map(|value| value.redact_with(mapper))
+            .collect()
+    }
+}
+
 impl<T, const N: usize> RedactableWithMapper for [T; N]
 where
     T: RedactableWithMapper,
@@ -135,3 +162,11 @@ impl_tuple_redactable_with_mapper!(T0);
 impl_tuple_redactable_with_mapper!(T0, T1);
 impl_tuple_redactable_with_mapper!(T0, T1, T2);
 impl_tuple_redactable_with_mapper!(T0, T1, T2, T3);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5, T6);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5, T6, T7);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5, T6, T7, T8);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_tuple_redactable_with_mapper!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
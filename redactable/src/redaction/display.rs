@@ -4,11 +4,14 @@
 //!
 //! - [`RedactableWithFormatter`]: Trait for types that can format redacted display strings
 //! - [`RedactedFormatterRef`]: Display wrapper that uses `fmt_redacted`
+//! - [`DisplayKeys`]: Map wrapper whose keys format with `fmt_redacted` instead of `Debug`
 //!
 //! # Passthrough Implementations
 //!
 //! Common scalar types implement `RedactableWithFormatter` as passthrough (unchanged output):
-//! `String`, `str`, `bool`, `char`, integers, floats, `Cow<str>`, `PhantomData`, `()`.
+//! `String`, `str`, `bool`, `char`, integers, floats, `Cow<str>`, `PhantomData`, `()`,
+//! and the `std::net` address types.
+//! `Cow<[T]>` formats like a slice of its redacted elements.
 //!
 //! Container implementations format inner values recursively. Library-owned
 //! `RefCell` formatting, including the generated policy companion route, uses a
@@ -17,12 +20,14 @@
 //! `Mutex` and `RwLock` use non-blocking lock attempts so display redaction does
 //! not wait behind a writer.
 //!
-//! Feature-gated types: `chrono` date/time types, `time` crate types, `Uuid`.
+//! Feature-gated types: `chrono` date/time types, `time` crate types, `Uuid`,
+//! and `IndexMap`/`IndexSet` (which format in insertion order).
 
 use std::{
     borrow::Cow,
     cmp::Ordering,
     marker::PhantomData,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
     num::{
         NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
         NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
@@ -170,6 +175,12 @@ impl_redactable_display_passthrough!(NonZeroU64);
 impl_redactable_display_passthrough!(NonZeroU128);
 impl_redactable_display_passthrough!(NonZeroUsize);
 
+// std::net passthrough implementations
+impl_redactable_display_passthrough!(IpAddr);
+impl_redactable_display_passthrough!(Ipv4Addr);
+impl_redactable_display_passthrough!(Ipv6Addr);
+impl_redactable_display_passthrough!(SocketAddr);
+
 // std::time and ordering passthrough implementations
 impl_redactable_display_passthrough_debug!(Duration);
 impl_redactable_display_passthrough_debug!(Instant);
@@ -234,8 +245,33 @@ mod uuid_passthrough {
 // Container RedactableWithFormatter implementations
 // =============================================================================
 
+/// Pads a container's whole rendering when the formatter asks for a width or
+/// precision.
+///
+/// The debug builders hand the caller's formatter to every entry, so
+/// `{items:>20}` would otherwise pad each element instead of the collection.
+/// Returns `None` when no padding is requested.
+fn pad_rendered<T: RedactableWithFormatter + ?Sized>(
+    value: &T,
+    f: &mut std::fmt::Formatter<'_>,
+) -> Option<std::fmt::Result> {
+    if f.width().is_none() && f.precision().is_none() {
+        return None;
+    }
+    let value = RedactedFormatterRef(value);
+    let rendered = if f.alternate() {
+        format!("{value:#}")
+    } else {
+        format!("{value}")
+    };
+    Some(f.pad(&rendered))
+}
+
 impl<T: RedactableWithFormatter> RedactableWithFormatter for Option<T> {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         match self {
             Some(value) => f
                 .debug_tuple("Some")
@@ -248,6 +284,9 @@ impl<T: RedactableWithFormatter> RedactableWithFormatter for Option<T> {
 
 impl<T: RedactableWithFormatter> RedactableWithFormatter for Vec<T> {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut list = f.debug_list();
         for item in self {
             list.entry(&item.redacted_display());
@@ -258,6 +297,9 @@ impl<T: RedactableWithFormatter> RedactableWithFormatter for Vec<T> {
 
 impl<T: RedactableWithFormatter> RedactableWithFormatter for [T] {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut list = f.debug_list();
         for item in self {
             list.entry(&item.redacted_display());
@@ -272,8 +314,17 @@ impl<T: RedactableWithFormatter, const N: usize> RedactableWithFormatter for [T;
     }
 }
 
+impl<T: RedactableWithFormatter + Clone> RedactableWithFormatter for Cow<'_, [T]> {
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (**self).fmt_redacted(f)
+    }
+}
+
 impl<T: RedactableWithFormatter> RedactableWithFormatter for std::collections::VecDeque<T> {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut list = f.debug_list();
         for item in self {
             list.entry(&item.redacted_display());
@@ -284,57 +335,51 @@ impl<T: RedactableWithFormatter> RedactableWithFormatter for std::collections::V
 
 impl<T0: RedactableWithFormatter> RedactableWithFormatter for (T0,) {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         f.write_str("(")?;
         self.0.fmt_redacted(f)?;
         f.write_str(",)")
     }
 }
 
-impl<T0: RedactableWithFormatter, T1: RedactableWithFormatter> RedactableWithFormatter
-    for (T0, T1)
-{
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("(")?;
-        self.0.fmt_redacted(f)?;
-        f.write_str(", ")?;
-        self.1.fmt_redacted(f)?;
-        f.write_str(")")
-    }
+macro_rules! impl_tuple_redactable_with_formatter {
+    ($first:ident $(, $rest:ident)+ $(,)?) => {
+        impl<$first, $($rest),+> RedactableWithFormatter for ($first, $($rest),+)
+        where
+            $first: RedactableWithFormatter,
+            $($rest: RedactableWithFormatter,)+
+        {
+            #[allow(non_snake_case)]
+            fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if let Some(result) = pad_rendered(self, f) {
+                    return result;
+                }
+                let ($first, $($rest),+) = self;
+                f.write_str("(")?;
+                $first.fmt_redacted(f)?;
+                $(
+                    f.write_str(", ")?;
+                    $rest.fmt_redacted(f)?;
+                )+
+                f.write_str(")")
+            }
+        }
+    };
 }
 
-impl<T0: RedactableWithFormatter, T1: RedactableWithFormatter, T2: RedactableWithFormatter>
-    RedactableWithFormatter for (T0, T1, T2)
-{
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("(")?;
-        self.0.fmt_redacted(f)?;
-        f.write_str(", ")?;
-        self.1.fmt_redacted(f)?;
-        f.write_str(", ")?;
-        self.2.fmt_redacted(f)?;
-        f.write_str(")")
-    }
-}
-
-impl<
-    T0: RedactableWithFormatter,
-    T1: RedactableWithFormatter,
-    T2: RedactableWithFormatter,
-    T3: RedactableWithFormatter,
-> RedactableWithFormatter for (T0, T1, T2, T3)
-{
-    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("(")?;
-        self.0.fmt_redacted(f)?;
-        f.write_str(", ")?;
-        self.1.fmt_redacted(f)?;
-        f.write_str(", ")?;
-        self.2.fmt_redacted(f)?;
-        f.write_str(", ")?;
-        self.3.fmt_redacted(f)?;
-        f.write_str(")")
-    }
-}
+impl_tuple_redactable_with_formatter!(T0, T1);
+impl_tuple_redactable_with_formatter!(T0, T1, T2);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5, T6);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5, T6, T7);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5, T6, T7, T8);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_tuple_redactable_with_formatter!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
 
 impl<T: RedactableWithFormatter + ?Sized> RedactableWithFormatter for Box<T> {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -358,6 +403,9 @@ impl<T: RedactableWithFormatter, E: RedactableWithFormatter> RedactableWithForma
     for Result<T, E>
 {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         match self {
             Ok(value) => f
                 .debug_tuple("Ok")
@@ -374,6 +422,9 @@ where
     V: RedactableWithFormatter,
 {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut map = f.debug_map();
         for (key, value) in self {
             map.entry(key, &value.redacted_display());
@@ -388,6 +439,9 @@ where
     V: RedactableWithFormatter,
 {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut map = f.debug_map();
         for (key, value) in self {
             map.entry(key, &value.redacted_display());
@@ -396,11 +450,97 @@ where
     }
 }
 
+// =============================================================================
+// DisplayKeys - Map wrapper whose keys use their own redacted formatting
+// =============================================================================
+
+/// Wrapper for a `HashMap` or `BTreeMap` whose keys format with
+/// [`RedactableWithFormatter`] instead of `Debug`.
+///
+/// Map keys normally render through `Debug`, which prints key types such as
+/// enums with their raw contents. Wrap the map in `DisplayKeys` when the key
+/// type redacts itself (for example an enum deriving `SensitiveDisplay`): redacted
+/// display formatting then uses `key.redacted_display()` for keys and
+/// `value.redacted_display()` for values. `Debug` forwards to the inner map.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use redactable::{DisplayKeys, RedactableWithFormatter};
+///
+/// let scores = DisplayKeys::from(BTreeMap::from([(1_u8, "gold".to_string())]));
+/// assert_eq!(scores.redacted_display().to_string(), "{1: gold}");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisplayKeys<M>(M);
+
+impl<M> From<M> for DisplayKeys<M> {
+    fn from(map: M) -> Self {
+        Self(map)
+    }
+}
+
+impl<M> DisplayKeys<M> {
+    /// Explicitly access the inner map.
+    #[must_use]
+    pub fn expose(&self) -> &M {
+        &self.0
+    }
+
+    /// Explicitly access the inner map mutably.
+    pub fn expose_mut(&mut self) -> &mut M {
+        &mut self.0
+    }
+
+    /// Consume the wrapper and return the inner map.
+    #[must_use]
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<K, V, S> RedactableWithFormatter for DisplayKeys<std::collections::HashMap<K, V, S>>
+where
+    K: RedactableWithFormatter,
+    V: RedactableWithFormatter,
+{
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
+        let mut map = f.debug_map();
+        for (key, value) in &self.0 {
+            map.entry(&key.redacted_display(), &value.redacted_display());
+        }
+        map.finish()
+    }
+}
+
+impl<K, V> RedactableWithFormatter for DisplayKeys<std::collections::BTreeMap<K, V>>
+where
+    K: RedactableWithFormatter,
+    V: RedactableWithFormatter,
+{
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
+        let mut map = f.debug_map();
+        for (key, value) in &self.0 {
+            map.entry(&key.redacted_display(), &value.redacted_display());
+        }
+        map.finish()
+    }
+}
+
 impl<T, S> RedactableWithFormatter for std::collections::HashSet<T, S>
 where
     T: RedactableWithFormatter,
 {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut set = f.debug_set();
         for item in self {
             set.entry(&item.redacted_display());
@@ -414,6 +554,44 @@ where
     T: RedactableWithFormatter,
 {
     fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
+        let mut set = f.debug_set();
+        for item in self {
+            set.entry(&item.redacted_display());
+        }
+        set.finish()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> RedactableWithFormatter for indexmap::IndexMap<K, V, S>
+where
+    K: std::fmt::Debug,
+    V: RedactableWithFormatter,
+{
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
+        let mut map = f.debug_map();
+        for (key, value) in self {
+            map.entry(key, &value.redacted_display());
+        }
+        map.finish()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> RedactableWithFormatter for indexmap::IndexSet<T, S>
+where
+    T: RedactableWithFormatter,
+{
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(result) = pad_rendered(self, f) {
+            return result;
+        }
         let mut set = f.debug_set();
         for item in self {
             set.entry(&item.redacted_display());
@@ -484,7 +662,7 @@ mod tests {
         sync::{Arc, Mutex, RwLock},
     };
 
-    use super::RedactableWithFormatter;
+    use super::{DisplayKeys, RedactableWithFormatter};
     use crate::{Secret, SensitiveDisplay};
 
     #[derive(Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -527,6 +705,17 @@ mod tests {
         assert_eq!(format!("{}", v.redacted_display()), "[a, b, c]");
     }
 
+    #[test]
+    fn containers_pad_their_whole_rendering() {
+        let v = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(format!("{:>10}", v.redacted_display()), "    [a, b]");
+        assert_eq!(format!("{:.3}", v.redacted_display()), "[a,");
+
+        let opt = Some("hello".to_string());
+        assert_eq!(format!("{:*<13}", opt.redacted_display()), "Some(hello)**");
+        assert_eq!(format!("{:^8}", (1, 2).redacted_display()), " (1, 2) ");
+    }
+
     #[test]
     fn vec_empty_displays_brackets() {
         let v: Vec<String> = vec![];
@@ -618,6 +807,28 @@ mod tests {
             format!("{}", quad.redacted_display()),
             "([REDACTED], [REDACTED], [REDACTED], [REDACTED])"
         );
+
+        let secret = || DisplaySecret {
+            value: "element".to_string(),
+        };
+        let twelve = (
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+            secret(),
+        );
+        assert_eq!(
+            format!("{}", twelve.redacted_display()),
+            format!("({})", ["[REDACTED]"; 12].join(", "))
+        );
     }
 
     #[test]
@@ -710,6 +921,74 @@ mod tests {
         assert_eq!(format!("{}", m.redacted_display()), "{key: value}");
     }
 
+    #[derive(SensitiveDisplay, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    enum AccountKey {
+        #[error("email {0}")]
+        Email(#[sensitive(Secret)] String),
+        #[error("id {0}")]
+        Id(u32),
+    }
+
+    #[test]
+    fn display_keys_format_btreemap_keys_with_their_redacted_display() {
+        let m = DisplayKeys::from(BTreeMap::from([
+            (
+                AccountKey::Email("alice@example.com".to_string()),
+                "gold".to_string(),
+            ),
+            (AccountKey::Id(7), "silver".to_string()),
+        ]));
+        assert_eq!(
+            format!("{}", m.redacted_display()),
+            "{email [REDACTED]: gold, id 7: silver}"
+        );
+    }
+
+    #[test]
+    fn display_keys_format_hashmap_keys_with_their_redacted_display() {
+        let m = DisplayKeys::from(HashMap::from([(
+            AccountKey::Email("alice@example.com".to_string()),
+            DisplaySecret {
+                value: "gold".to_string(),
+            },
+        )]));
+        assert_eq!(
+            format!("{}", m.redacted_display()),
+            "{email [REDACTED]: [REDACTED]}"
+        );
+        assert_eq!(m.into_inner().len(), 1);
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn indexmap_displays_entries_in_insertion_order() {
+        let m = indexmap::IndexMap::from([
+            (
+                Key("zeta"),
+                DisplaySecret {
+                    value: "one".to_string(),
+                },
+            ),
+            (
+                Key("alpha"),
+                DisplaySecret {
+                    value: "two".to_string(),
+                },
+            ),
+        ]);
+        assert_eq!(
+            format!("{}", m.redacted_display()),
+            "{zeta: [REDACTED], alpha: [REDACTED]}"
+        );
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn indexset_displays_elements_in_insertion_order() {
+        let s = indexmap::IndexSet::from(["b".to_string(), "a".to_string()]);
+        assert_eq!(format!("{}", s.redacted_display()), "{b, a}");
+    }
+
     #[test]
     fn btreeset_displays_elements() {
         let mut s = BTreeSet::new();
@@ -7,6 +7,7 @@
 //! - [`NotSensitiveDisplay`]: Wrapper using `Display` formatting
 //! - [`NotSensitiveDebug`]: Wrapper using `Debug` formatting
 //! - [`NotSensitiveJson`]: Wrapper using JSON serialization (requires `json` feature)
+//! - [`NotSensitiveBytes`]: Wrapper emitting raw bytes
 //!
 //! `NotSensitiveDisplay` and `NotSensitiveDebug` can own their values when
 //! constructed directly. Their Serde implementations, available through the
@@ -17,6 +18,7 @@
 //! - [`NotSensitiveDisplayExt`]: Provides `.not_sensitive_display()`
 //! - [`NotSensitiveDebugExt`]: Provides `.not_sensitive_debug()`
 //! - [`NotSensitiveJsonExt`]: Provides `.not_sensitive_json()`
+//! - [`NotSensitiveBytesExt`]: Provides `.not_sensitive_bytes()`
 
 use std::ops::{Deref, DerefMut};
 
@@ -297,6 +299,54 @@ where
     }
 }
 
+// =============================================================================
+// NotSensitiveBytes - Wrapper emitting raw bytes
+// =============================================================================
+
+/// Wrapper for explicitly non-sensitive byte payloads.
+///
+/// Use `.not_sensitive_bytes()` to declare bytes safe to log; the output is
+/// [`RedactedOutput::Bytes`] for sinks that accept binary field values.
+///
+/// ```
+/// use redactable::{NotSensitiveBytesExt, RedactedOutput, ToRedactedOutput};
+///
+/// let digest = [0xde_u8, 0xad, 0xbe, 0xef];
+/// assert_eq!(
+///     digest.not_sensitive_bytes().to_redacted_output(),
+///     RedactedOutput::Bytes(vec![0xde, 0xad, 0xbe, 0xef])
+/// );
+/// ```
+pub struct NotSensitiveBytes<'a, T: ?Sized>(&'a T);
+
+impl<T: ?Sized> NotSensitiveBytes<'_, T> {
+    /// Returns the inner value.
+    #[must_use]
+    pub fn inner(&self) -> &T {
+        self.0
+    }
+}
+
+impl<T> ToRedactedOutput for NotSensitiveBytes<'_, T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn to_redacted_output(&self) -> RedactedOutput {
+        RedactedOutput::Bytes(self.0.as_ref().to_vec())
+    }
+}
+
+impl<T> std::fmt::Debug for NotSensitiveBytes<'_, T>
+where
+    T: AsRef<[u8]> + ?Sized,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("NotSensitiveBytes")
+            .field(&self.0.as_ref())
+            .finish()
+    }
+}
+
 // =============================================================================
 // Extension traits
 // =============================================================================
@@ -360,3 +410,13 @@ where
         NotSensitiveJson(self)
     }
 }
+
+/// Extension trait to mark byte payloads as explicitly non-sensitive.
+pub trait NotSensitiveBytesExt: AsRef<[u8]> {
+    /// Wraps the value as explicitly non-sensitive raw bytes.
+    fn not_sensitive_bytes(&self) -> NotSensitiveBytes<'_, Self> {
+        NotSensitiveBytes(self)
+    }
+}
+
+impl<T> NotSensitiveBytesExt for T where T: AsRef<[u8]> + ?Sized {}
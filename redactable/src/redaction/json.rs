@@ -5,9 +5,14 @@
 //! redact by default as an explicit exception to the crate's mostly opt-in
 //! model: since `Value` can contain arbitrary data, we redact it entirely rather
 //! than attempting to traverse its dynamic structure.
+//!
+//! It also provides [`RedactSerialize`], a `Serialize` wrapper that serializes
+//! a redacted clone of a value for serde sinks other than logging adapters.
+
+use serde::{Serialize, Serializer};
 
 use super::{
-    redact::{PolicyApplicable, PolicyApplicableRef, RedactableMapper},
+    redact::{PolicyApplicable, PolicyApplicableRef, RedactableMapper, SerializeMapper},
     traits::{Redactable, RedactableWithMapper},
 };
 use crate::policy::{RecursivePolicyKind, RedactionPolicy};
@@ -47,3 +52,80 @@ impl RedactableWithMapper for serde_json::Value {
 // `Value` redaction is declared by the crate itself (full redaction as an
 // opaque leaf), so it is certified for the redacted-output extension traits.
 impl Redactable for serde_json::Value {}
+
+// =============================================================================
+// RedactSerialize - Serialize wrapper for redacted values
+// =============================================================================
+
+/// Serializes a redacted clone of the wrapped value.
+///
+/// Use it wherever a `Serialize` value is expected and the sink must not see
+/// raw secrets, e.g. `serde_json::to_value(value.redact_serialize())` for an
+/// audit log. The output has the shape the type's own `Serialize` impl gives it,
+/// applied to the redacted value, so `#[serde(...)]` attributes are honored.
+///
+/// Types with `#[sensitive(serde_redact)]` already serialize their redacted
+/// form and do not need this wrapper.
+///
+/// # Panics
+///
+/// Serializing this wrapper clones the complete value before redacting it and
+/// therefore inherits every panic from `Clone`, including a traversed
+/// [`std::cell::RefCell`] with a live mutable borrow.
+pub struct RedactSerialize<'a, T: ?Sized>(&'a T);
+
+impl<T> Serialize for RedactSerialize<'_, T>
+where
+    T: Redactable + Clone + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `serde_redact` values reached by the traversal redact while
+        // serializing, so the mapper leaves them for their own impl.
+        self.0
+            .clone()
+            .redact_with(&SerializeMapper)
+            .serialize(serializer)
+    }
+}
+
+/// Extension trait to serialize a value in its redacted form.
+///
+/// Requires [`Redactable`], so raw passthrough leaves like `String` cannot be
+/// wrapped.
+///
+/// ```
+/// # #![allow(hidden_glob_reexports)]
+/// # pub use redactable::*;
+/// use redactable::{RedactSerializeExt, Secret, Sensitive};
+///
+/// #[derive(Clone, Sensitive, serde::Serialize)]
+/// struct Login {
+///     user: String,
+///     #[sensitive(Secret)]
+///     password: String,
+/// }
+///
+/// # fn main() {
+/// let login = Login { user: "alice".into(), password: "hunter2".into() };
+/// let json = serde_json::to_string(&login.redact_serialize()).unwrap();
+/// assert_eq!(json, r#"{"user":"alice","password":"[REDACTED]"}"#);
+/// # }
+/// ```
+pub trait RedactSerializeExt {
+    /// Wraps `&self` so serializing it serializes a redacted clone.
+    fn redact_serialize(&self) -> RedactSerialize<'_, Self>
+    where
+        Self: Sized;
+}
+
+impl<T> RedactSerializeExt for T
+where
+    T: Redactable + Clone + Serialize,
+{
+    fn redact_serialize(&self) -> RedactSerialize<'_, Self> {
+        RedactSerialize(self)
+    }
+}
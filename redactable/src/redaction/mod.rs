@@ -2,7 +2,8 @@
 //!
 //! This module provides the machinery for applying redaction:
 //!
-//! - **`traits`**: Core traits (`RedactableWithMapper`, `SensitiveWithPolicy`, `Redactable`)
+//! - **`traits`**: Core traits (`RedactableWithMapper`, `SensitiveWithPolicy`,
+//!   `TryRedactableWithPolicy`, `Redactable`)
 //! - **`redact`**: Application layer - the redaction machinery (`PolicyApplicable`, `RedactableMapper`)
 //! - **`wrappers`**: Wrapper types (`SensitiveValue`, `NotSensitiveValue`)
 //! - **`output`**: Output types for logging boundaries (`RedactedOutput`, `ToRedactedOutput`)
@@ -25,29 +26,37 @@ mod wrappers;
 
 // Re-export core traits
 // Re-export display types
-pub use display::{PolicyRedactedFormatterRef, RedactableWithFormatter, RedactedFormatterRef};
+pub use display::{
+    DisplayKeys, PolicyRedactedFormatterRef, RedactableWithFormatter, RedactedFormatterRef,
+};
 // Re-export escape hatches
 pub use escape_hatches::{
-    NotSensitive, NotSensitiveDebug, NotSensitiveDebugExt, NotSensitiveDisplay,
-    NotSensitiveDisplayExt, NotSensitiveExt,
+    NotSensitive, NotSensitiveBytes, NotSensitiveBytesExt, NotSensitiveDebug, NotSensitiveDebugExt,
+    NotSensitiveDisplay, NotSensitiveDisplayExt, NotSensitiveExt,
 };
 #[cfg(feature = "json")]
 pub use escape_hatches::{NotSensitiveJson, NotSensitiveJsonExt};
 #[cfg(feature = "json")]
+pub use json::{RedactSerialize, RedactSerializeExt};
+#[cfg(feature = "json")]
 pub use output::serialize_redacted_json;
 #[cfg(feature = "json")]
 pub use output::{IntoRedactedJsonExt, RedactedJson, RedactedJsonExt, RedactedJsonRef};
 // Re-export output types
 pub use output::{
-    IntoRedactedOutputExt, RedactedOutput, RedactedOutputExt, RedactedOutputRef, ToRedactedOutput,
+    IntoRedactedOutputExt, RedactedOutput, RedactedOutputExt, RedactedOutputRef, RedactedRecord,
+    ToRedactedOutput,
 };
 // Re-export redaction machinery
 pub use redact::{
-    PolicyApplicable, PolicyApplicableRef, PolicyFormattingMapper, PolicyMapper, RedactableMapper,
-    ScalarRedaction, apply_policy, apply_policy_ref, redact,
+    PassthroughRedaction, PolicyApplicable, PolicyApplicableRef, PolicyFormattingMapper,
+    PolicyMapper, RedactEverything, RedactableMapper, ScalarRedaction, SerializeMapper,
+    apply_policy, apply_policy_ref, redact, redact_everything, redact_with,
+};
+pub use traits::{
+    Redactable, RedactableWithMapper, SensitiveText, SensitiveWithPolicy, TryRedactableWithPolicy,
 };
-pub use traits::{Redactable, RedactableWithMapper, SensitiveWithPolicy};
 // Re-export wrapper types
 #[doc(hidden)]
 pub use ip_policy::{IpPolicyApplicable, IpPolicyApplicableRef};
-pub use wrappers::{NotSensitiveValue, SensitiveValue};
+pub use wrappers::{NotSensitiveValue, RedactKeys, Redacted, SensitiveValue};
@@ -6,19 +6,24 @@
 //! - [`ToRedactedOutput`]: Trait for types that can produce redacted output
 //! - [`RedactedOutputRef`]: Wrapper for explicit redacted output
 //! - [`IntoRedactedOutputExt`]: Consuming output adapter that redacts via `.redact()`
+//! - [`RedactedRecord`]: Key-value record of redacted outputs, rendered as text or JSON
 //! - [`RedactedJson`]: Owned redacted JSON output
 //! - [`RedactedJsonRef`]: Wrapper for redacted JSON output
 
+use std::fmt::Write as _;
+
 #[cfg(feature = "json")]
 use serde::Serialize;
 #[cfg(feature = "json")]
 use serde_json::Value as JsonValue;
 
+#[cfg(feature = "json")]
+use super::redact::SerializeMapper;
 use super::{
     traits::{Redactable, SensitiveWithPolicy},
     wrappers::SensitiveValue,
 };
-use crate::policy::RedactionPolicy;
+use crate::policy::{RedactionPolicy, text::ELLIPSIS};
 
 /// Serializes an already-redacted value into a structured [`JsonValue`].
 ///
@@ -44,6 +49,16 @@ pub fn serialize_redacted_json<T: Serialize>(value: T) -> JsonValue {
         .unwrap_or_else(|_| JsonValue::String(crate::policy::REDACTED_PLACEHOLDER.into()))
 }
 
+/// Redacts `value` and serializes the result, running every policy once.
+///
+/// A `#[sensitive(serde_redact)]` value redacts inside its own `Serialize`
+/// impl, so the traversal leaves every such value it reaches to that impl
+/// instead of redacting it twice.
+#[cfg(feature = "json")]
+fn redact_to_json<T: Redactable + Serialize>(value: T) -> JsonValue {
+    serialize_redacted_json(value.redact_with(&SerializeMapper))
+}
+
 // =============================================================================
 // RedactedOutput - Output produced at logging boundaries
 // =============================================================================
@@ -54,6 +69,10 @@ pub fn serialize_redacted_json<T: Serialize>(value: T) -> JsonValue {
 /// feature, and feature unification means another crate in the build graph can
 /// switch it on. Exhaustive matches would break the moment that happens, so
 /// downstream matches must carry a wildcard arm.
+///
+/// Equality compares `Json` values structurally: object key order is ignored
+/// (also when `serde_json`'s `preserve_order` feature is on), while array
+/// order still matters. Values of different variants are never equal.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum RedactedOutput {
@@ -62,6 +81,133 @@ pub enum RedactedOutput {
     /// Redacted structured JSON output (requires the `json` feature).
     #[cfg(feature = "json")]
     Json(JsonValue),
+    /// Raw bytes for sinks that accept binary field values.
+    ///
+    /// Text renderings write the bytes as lowercase hex.
+    Bytes(Vec<u8>),
+}
+
+impl RedactedOutput {
+    /// Renders the output as text, pretty-printing JSON with indentation.
+    ///
+    /// [`RedactedOutput::Text`] is returned unchanged.
+    #[must_use]
+    pub fn to_string_pretty(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            #[cfg(feature = "json")]
+            Self::Json(json) => format!("{json:#}"),
+            Self::Bytes(bytes) => hex_string(bytes),
+        }
+    }
+
+    /// Renders the output as text, serializing JSON on a single line.
+    ///
+    /// [`RedactedOutput::Text`] is returned unchanged.
+    #[must_use]
+    pub fn to_string_compact(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            #[cfg(feature = "json")]
+            Self::Json(json) => json.to_string(),
+            Self::Bytes(bytes) => hex_string(bytes),
+        }
+    }
+
+    /// Bounds text to `max_len` bytes for log backends that cap field length.
+    ///
+    /// Text longer than `max_len` is cut on a `char` boundary and ends with an
+    /// ellipsis (`…`, 3 bytes); the result, ellipsis included, never exceeds
+    /// `max_len` bytes. When `max_len` cannot fit the ellipsis, the text is cut
+    /// without one. For `RedactedOutput::Json`, every string leaf is truncated
+    /// the same way while keys and the document structure are kept.
+    /// [`RedactedOutput::Bytes`] is cut to its first `max_len` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use redactable::RedactedOutput;
+    ///
+    /// let output = RedactedOutput::Text("user=alice token=[REDACTED]".into());
+    /// assert_eq!(output.truncated(13), RedactedOutput::Text("user=alice\u{2026}".into()));
+    /// ```
+    #[must_use]
+    pub fn truncated(self, max_len: usize) -> Self {
+        match self {
+            Self::Text(text) => Self::Text(truncate_text(text, max_len)),
+            #[cfg(feature = "json")]
+            Self::Json(json) => Self::Json(truncate_json(json, max_len)),
+            Self::Bytes(mut bytes) => {
+                bytes.truncate(max_len);
+                Self::Bytes(bytes)
+            }
+        }
+    }
+}
+
+/// Formats the output as its compact text rendering.
+///
+/// [`RedactedOutput::Text`] is written unchanged, `Json` as single-line JSON,
+/// and `Bytes` as lowercase hex, matching
+/// [`to_string_compact`](RedactedOutput::to_string_compact).
+///
+/// # Example
+/// ```
+/// use redactable::RedactedOutput;
+///
+/// let output = RedactedOutput::Text("user=alice token=[REDACTED]".into());
+/// assert_eq!(format!("event: {output}"), "event: user=alice token=[REDACTED]");
+/// ```
+impl std::fmt::Display for RedactedOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            #[cfg(feature = "json")]
+            Self::Json(json) => write!(f, "{json}"),
+            Self::Bytes(bytes) => bytes.iter().try_for_each(|byte| write!(f, "{byte:02x}")),
+        }
+    }
+}
+
+/// Renders bytes as lowercase hex, two digits per byte.
+fn hex_string(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+fn truncate_text(mut text: String, max_len: usize) -> String {
+    if text.len() <= max_len {
+        return text;
+    }
+    let suffix = (max_len >= ELLIPSIS.len_utf8()).then_some(ELLIPSIS);
+    let mut end = max_len - suffix.map_or(0, char::len_utf8);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text.truncate(end);
+    text.extend(suffix);
+    text
+}
+
+#[cfg(feature = "json")]
+fn truncate_json(json: JsonValue, max_len: usize) -> JsonValue {
+    match json {
+        JsonValue::String(text) => JsonValue::String(truncate_text(text, max_len)),
+        JsonValue::Array(items) => JsonValue::Array(
+            items
+                .into_iter()
+                .map(|item| truncate_json(item, max_len))
+                .collect(),
+        ),
+        JsonValue::Object(map) => JsonValue::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, truncate_json(value, max_len)))
+                .collect(),
+        ),
+        other => other,
+    }
 }
 
 // =============================================================================
@@ -138,7 +284,14 @@ where
 ///
 /// Requires [`Redactable`], which only types with declared redaction behavior
 /// implement - raw passthrough leaves like `String` cannot be certified as
-/// redacted output.
+/// redacted output. The borrowed wrapper clones before redacting, so the type
+/// must also implement `Clone`; `#[derive(Sensitive)]` does not derive it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be borrowed as redacted output",
+    label = "requires `Redactable + Clone + Debug`",
+    note = "raw values such as `String` have no redaction behavior; wrap them in `SensitiveValue` to redact them or `NotSensitiveValue` to log them as-is, or derive `Sensitive` on your own types",
+    note = "borrowed redacted output clones the value before redacting it; a derived type also needs `Clone`, or consume it with `IntoRedactedOutputExt::into_redacted_output`"
+)]
 pub trait RedactedOutputExt {
     /// Wraps the value for explicit logging-safe output.
     ///
@@ -191,6 +344,111 @@ where
     }
 }
 
+// =============================================================================
+// RedactedRecord - Key-value record built from redacted outputs
+// =============================================================================
+
+/// Ordered key-value record of redacted outputs for structured log lines.
+///
+/// A small structured-output primitive for applications that log without
+/// `slog` or `tracing`. Every value enters the record through
+/// [`ToRedactedOutput`], so only certified logging-safe output can be added.
+/// The same record renders as a `key=value` text line with
+/// [`RedactedRecord::to_text`] or, with the `json` feature, as a JSON object
+/// with `RedactedRecord::to_json`.
+///
+/// # Example
+/// ```
+/// use redactable::{RedactedRecord, Secret, SensitiveValue};
+///
+/// let token = SensitiveValue::<String, Secret>::from("sk_live_abc".to_owned());
+/// let record = RedactedRecord::new()
+///     .field("event", &redactable::NotSensitiveDisplay("login"))
+///     .field("token", &token);
+/// assert_eq!(record.to_text(), "event=login token=[REDACTED]");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RedactedRecord {
+    fields: Vec<(&'static str, RedactedOutput)>,
+}
+
+impl RedactedRecord {
+    /// Creates an empty record.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `key` with the redacted output of `value`.
+    #[must_use]
+    pub fn field<T: ToRedactedOutput + ?Sized>(mut self, key: &'static str, value: &T) -> Self {
+        self.push(key, value);
+        self
+    }
+
+    /// Appends `key` with the redacted output of `value` in place.
+    pub fn push<T: ToRedactedOutput + ?Sized>(&mut self, key: &'static str, value: &T) {
+        self.fields.push((key, value.to_redacted_output()));
+    }
+
+    /// Returns the fields in insertion order.
+    #[must_use]
+    pub fn fields(&self) -> &[(&'static str, RedactedOutput)] {
+        &self.fields
+    }
+
+    /// Renders the record as space-separated `key=value` pairs.
+    ///
+    /// Fields keep insertion order, including repeated keys. JSON values are
+    /// serialized compactly. A value that is empty or contains whitespace,
+    /// `=`, or `"` is written as a quoted, escaped string.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut line = String::new();
+        for (index, (key, output)) in self.fields.iter().enumerate() {
+            if index > 0 {
+                line.push(' ');
+            }
+            line.push_str(key);
+            line.push('=');
+            let value = output.to_string_compact();
+            if value.is_empty()
+                || value
+                    .chars()
+                    .any(|ch| ch.is_whitespace() || ch == '=' || ch == '"')
+            {
+                let _ = write!(line, "{value:?}");
+            } else {
+                line.push_str(&value);
+            }
+        }
+        line
+    }
+
+    /// Renders the record as a JSON object (requires the `json` feature).
+    ///
+    /// Text outputs become JSON strings, byte outputs become lowercase hex
+    /// strings, and JSON outputs are embedded as structured values. When a key
+    /// repeats, the last value wins.
+    #[cfg(feature = "json")]
+    #[must_use]
+    pub fn to_json(&self) -> JsonValue {
+        let object = self
+            .fields
+            .iter()
+            .map(|(key, output)| {
+                let value = match output {
+                    RedactedOutput::Text(text) => JsonValue::String(text.clone()),
+                    RedactedOutput::Json(json) => json.clone(),
+                    RedactedOutput::Bytes(bytes) => JsonValue::String(hex_string(bytes)),
+                };
+                ((*key).to_owned(), value)
+            })
+            .collect();
+        JsonValue::Object(object)
+    }
+}
+
 // =============================================================================
 // RedactedJson - Owned redacted JSON output
 // =============================================================================
@@ -212,6 +470,37 @@ impl RedactedJson {
     pub(crate) fn value(&self) -> &JsonValue {
         &self.value
     }
+
+    /// Renders the redacted value as indented JSON for human-readable dumps.
+    ///
+    /// Logging adapters keep emitting the compact value; this only formats the
+    /// already-redacted document.
+    ///
+    /// # Example
+    /// ```
+    /// # #![allow(hidden_glob_reexports)]
+    /// # pub use redactable::*;
+    /// use redactable::{IntoRedactedJsonExt, Secret, Sensitive};
+    ///
+    /// #[derive(Sensitive, serde::Serialize)]
+    /// struct Login {
+    ///     user: String,
+    ///     #[sensitive(Secret)]
+    ///     password: String,
+    /// }
+    ///
+    /// # fn main() {
+    /// let login = Login { user: "alice".into(), password: "hunter2".into() };
+    /// assert_eq!(
+    ///     login.into_redacted_json().to_pretty_string(),
+    ///     "{\n  \"password\": \"[REDACTED]\",\n  \"user\": \"alice\"\n}"
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_pretty_string(&self) -> String {
+        format!("{:#}", self.value)
+    }
 }
 
 #[cfg(feature = "json")]
@@ -243,8 +532,7 @@ where
     T: Redactable + Clone + Serialize,
 {
     fn to_redacted_output(&self) -> RedactedOutput {
-        let redacted = self.0.clone().redact();
-        RedactedOutput::Json(serialize_redacted_json(redacted))
+        RedactedOutput::Json(redact_to_json(self.0.clone()))
     }
 }
 
@@ -252,8 +540,15 @@ where
 ///
 /// Requires [`Redactable`], which only types with declared redaction behavior
 /// implement - raw passthrough leaves like `String` cannot be certified as
-/// redacted JSON.
+/// redacted JSON. The borrowed wrapper clones before redacting, so the type
+/// must also implement `Clone`; `#[derive(Sensitive)]` does not derive it.
 #[cfg(feature = "json")]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be borrowed as redacted JSON",
+    label = "requires `Redactable + Clone + Serialize`",
+    note = "raw values such as `String` have no redaction behavior; wrap them in `SensitiveValue` to redact them or `NotSensitiveValue` to log them as-is, or derive `Sensitive` on your own types",
+    note = "borrowed redacted JSON clones the value before redacting it; a derived type also needs `Clone`, or consume it with `IntoRedactedJsonExt::into_redacted_json`"
+)]
 pub trait RedactedJsonExt {
     /// Wraps the value for explicit redacted JSON output.
     ///
@@ -289,7 +584,7 @@ pub trait IntoRedactedJsonExt: Redactable + Serialize + Sized {
     #[must_use]
     fn into_redacted_json(self) -> RedactedJson {
         RedactedJson {
-            value: serialize_redacted_json(self.redact()),
+            value: redact_to_json(self),
         }
     }
 }
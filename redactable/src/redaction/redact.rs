@@ -37,7 +37,8 @@ mod sets;
 mod tests;
 
 pub use core::{
-    PolicyApplicable, PolicyApplicableRef, PolicyFormattingMapper, PolicyMapper, RedactableMapper,
-    ScalarRedaction, apply_policy, apply_policy_ref, redact,
+    PassthroughRedaction, PolicyApplicable, PolicyApplicableRef, PolicyFormattingMapper,
+    PolicyMapper, RedactEverything, RedactableMapper, ScalarRedaction, SerializeMapper,
+    apply_policy, apply_policy_ref, redact, redact_everything, redact_with,
 };
 pub use maps::PolicyMapOutput;
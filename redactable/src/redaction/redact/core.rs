@@ -5,7 +5,8 @@
 //! traversal) with its concrete [`PolicyMapper`] and [`PolicyFormattingMapper`]
 //! implementations, [`PolicyApplicable`] / [`PolicyApplicableRef`] (owned and
 //! borrowed recursive policy application), [`ScalarRedaction`] (default-value
-//! redaction for scalars), and the public entry points [`redact`],
+//! redaction for scalars), [`PassthroughRedaction`] (placeholders for other
+//! leaves), and the public entry points [`redact`],
 //! [`apply_policy`], and [`apply_policy_ref`]. It also holds the shared
 //! formatting helpers used by the borrowed container implementations.
 //!
@@ -18,7 +19,7 @@ use crate::{
         PolicyApplicableRefForGeneratedFormatting, PolicyField, PolicyFieldRef,
         PolicyFormattingOutput,
     },
-    policy::{RecursivePolicyKind, RedactionPolicy},
+    policy::{RecursivePolicyKind, RedactionPolicy, Secret},
     redaction::traits::{RedactableWithMapper, SensitiveWithPolicy},
 };
 
@@ -43,14 +44,82 @@ where
 }
 
 // =============================================================================
-// RedactableMapper - Internal mapping trait
+// RedactableMapper - Leaf mapping extension point
 // =============================================================================
 
-/// Maps sensitive and non-sensitive values during traversal.
+/// Maps sensitive and non-sensitive leaves during traversal.
 ///
-/// This is the internal machinery that applies redaction policies.
-/// Implementations must return the same value type for `map_sensitive`.
-#[doc(hidden)]
+/// [`RedactableWithMapper`] walks a value and hands each leaf to a mapper,
+/// which decides what the leaf becomes. [`redact`] uses the default mapper,
+/// which applies each field's policy. Implement this trait and pass it to
+/// [`redact_with`] to redact differently; the traversal itself (which fields
+/// are walked, how containers are rebuilt) stays the same.
+///
+/// `map_sensitive` receives `#[sensitive(Policy)]` leaves and `map_scalar` the
+/// scalar leaves of `#[sensitive(Secret)]` fields. The `map_unannotated_*`
+/// methods receive leaves without an annotation and return them unchanged by
+/// default. Fields marked `#[not_sensitive]` never reach the mapper.
+///
+/// # Example
+///
+/// A mapper that replaces every policy leaf with the policy's name in
+/// uppercase:
+///
+/// ```
+/// # #![allow(hidden_glob_reexports)]
+/// # pub use redactable::*;
+/// use redactable::{
+///     RedactableMapper, RedactionPolicy, ScalarRedaction, Sensitive, SensitiveWithPolicy,
+///     TextRedactionPolicy, redact_with,
+/// };
+///
+/// struct UppercasePlaceholders;
+///
+/// impl RedactableMapper for UppercasePlaceholders {
+///     fn map_sensitive<V, P>(&self, value: V) -> V
+///     where
+///         V: SensitiveWithPolicy<P>,
+///         P: RedactionPolicy,
+///     {
+///         let name = std::any::type_name::<P>().rsplit("::").next().unwrap_or("SECRET");
+///         let placeholder = format!("[{}]", name.to_uppercase());
+///         value.redact_with_policy(&TextRedactionPolicy::full_with(placeholder))
+///     }
+///
+///     fn map_scalar<S>(&self, value: S) -> S
+///     where
+///         S: Default + ScalarRedaction,
+///     {
+///         ScalarRedaction::redact(value)
+///     }
+/// }
+///
+/// #[derive(Clone, Sensitive)]
+/// # #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+/// struct Login {
+///     #[sensitive(redactable::Email)]
+///     email: String,
+///     #[sensitive(redactable::Token)]
+///     api_key: String,
+///     #[sensitive(redactable::Secret)]
+///     attempts: u32,
+///     client: String,
+/// }
+///
+/// # fn main() {
+/// let login = Login {
+///     email: "alice@example.com".into(),
+///     api_key: "sk-live-1234".into(),
+///     attempts: 3,
+///     client: "cli".into(),
+/// };
+/// let redacted = redact_with(login, &UppercasePlaceholders);
+/// assert_eq!(redacted.email, "[EMAIL]");
+/// assert_eq!(redacted.api_key, "[TOKEN]");
+/// assert_eq!(redacted.attempts, 0);
+/// assert_eq!(redacted.client, "cli");
+/// # }
+/// ```
 pub trait RedactableMapper {
     /// Maps a sensitive, string-like value.
     fn map_sensitive<V, P>(&self, value: V) -> V
@@ -65,6 +134,38 @@ pub trait RedactableMapper {
     where
         S: Default + ScalarRedaction;
 
+    /// Maps a string-like leaf reached without a policy annotation.
+    ///
+    /// Ordinary mappers return it unchanged; [`RedactEverything`] redacts it.
+    fn map_unannotated_text<V>(&self, value: V) -> V
+    where
+        V: SensitiveWithPolicy<Secret>,
+    {
+        value
+    }
+
+    /// Maps a primitive scalar leaf reached without a policy annotation.
+    ///
+    /// Ordinary mappers return it unchanged; [`RedactEverything`] redacts it.
+    fn map_unannotated_scalar<S>(&self, value: S) -> S
+    where
+        S: Default + ScalarRedaction,
+    {
+        value
+    }
+
+    /// Maps a passthrough leaf (addresses, durations, timestamps, ...) reached
+    /// without a policy annotation.
+    ///
+    /// Ordinary mappers return it unchanged; [`RedactEverything`] replaces it
+    /// with its [`PassthroughRedaction`] placeholder.
+    fn map_unannotated_passthrough<L>(&self, value: L) -> L
+    where
+        L: PassthroughRedaction,
+    {
+        value
+    }
+
     /// Reports the requested Debug mode for generated map-key formatting.
     ///
     /// Ordinary redaction mappers keep compact formatting. The generated
@@ -73,6 +174,17 @@ pub trait RedactableMapper {
     fn debug_alternate(&self) -> bool {
         false
     }
+
+    /// Reports whether `#[sensitive(serde_redact)]` values are left for their
+    /// own `Serialize` impl to redact.
+    ///
+    /// Ordinary mappers redact them like any other value. The mapper used by the
+    /// redacting serialization paths overrides this so those values run their
+    /// policies once, when they serialize.
+    #[doc(hidden)]
+    fn defers_serialize_redaction(&self) -> bool {
+        false
+    }
 }
 
 /// The default mapper that applies redaction policies.
@@ -99,6 +211,66 @@ impl RedactableMapper for PolicyMapper {
     }
 }
 
+/// Mapper that redacts every leaf, not only those with a policy annotation.
+///
+/// This inverts the default for "redact everything, reveal allowlisted"
+/// workflows: fields marked `#[not_sensitive]` are not traversed and survive
+/// unchanged, annotated fields apply their policy as usual, and every other
+/// string-like leaf becomes [`REDACTED_PLACEHOLDER`] while primitive scalars
+/// take their [`Secret`] default. Passthrough leaves such as addresses,
+/// durations, and timestamps take their [`PassthroughRedaction`] placeholder.
+/// Map keys are never traversed and stay visible, as with the default mapper.
+///
+/// ```
+/// use redactable::redact_everything;
+///
+/// let (name, age) = redact_everything((String::from("alice"), 31_u8));
+/// assert_eq!(name, "[REDACTED]");
+/// assert_eq!(age, 0);
+/// ```
+///
+/// [`REDACTED_PLACEHOLDER`]: crate::policy::REDACTED_PLACEHOLDER
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RedactEverything;
+
+impl RedactableMapper for RedactEverything {
+    fn map_sensitive<V, P>(&self, value: V) -> V
+    where
+        V: SensitiveWithPolicy<P>,
+        P: RedactionPolicy,
+    {
+        PolicyMapper.map_sensitive::<V, P>(value)
+    }
+
+    fn map_scalar<S>(&self, value: S) -> S
+    where
+        S: Default + ScalarRedaction,
+    {
+        PolicyMapper.map_scalar(value)
+    }
+
+    fn map_unannotated_text<V>(&self, value: V) -> V
+    where
+        V: SensitiveWithPolicy<Secret>,
+    {
+        PolicyMapper.map_sensitive::<V, Secret>(value)
+    }
+
+    fn map_unannotated_scalar<S>(&self, value: S) -> S
+    where
+        S: Default + ScalarRedaction,
+    {
+        PolicyMapper.map_scalar(value)
+    }
+
+    fn map_unannotated_passthrough<L>(&self, value: L) -> L
+    where
+        L: PassthroughRedaction,
+    {
+        value.redact()
+    }
+}
+
 /// Mapper used while a generated formatter projects policy-redacted values.
 ///
 /// It carries the active alternate-Debug flag through nested containers so map
@@ -138,13 +310,49 @@ impl RedactableMapper for PolicyFormattingMapper {
     }
 }
 
+/// Mapper used when a value is redacted only to be serialized.
+///
+/// It applies policies like [`PolicyMapper`] but leaves every
+/// `#[sensitive(serde_redact)]` value it reaches untouched: that value's
+/// `Serialize` impl redacts it, so redacting it here as well would apply its
+/// policies twice. Values traversal does not reach, such as map keys, redact
+/// themselves in the same way.
+#[derive(Clone, Copy, Debug)]
+#[doc(hidden)]
+pub struct SerializeMapper;
+
+impl RedactableMapper for SerializeMapper {
+    fn map_sensitive<V, P>(&self, value: V) -> V
+    where
+        V: SensitiveWithPolicy<P>,
+        P: RedactionPolicy,
+    {
+        PolicyMapper.map_sensitive::<V, P>(value)
+    }
+
+    fn map_scalar<S>(&self, value: S) -> S
+    where
+        S: Default + ScalarRedaction,
+    {
+        PolicyMapper.map_scalar(value)
+    }
+
+    fn defers_serialize_redaction(&self) -> bool {
+        true
+    }
+}
+
 // =============================================================================
 // ScalarRedaction - Helper for scalar defaults
 // =============================================================================
 
-/// Helper trait to handle scalar redaction, with special cases.
-#[doc(hidden)]
+/// Scalar types that `#[sensitive(Secret)]` can redact.
+///
+/// [`redact`](ScalarRedaction::redact) returns the type's default, except for
+/// `char`, which becomes `'*'`. Custom [`RedactableMapper`]s call it from
+/// `map_scalar` to keep the default scalar behavior.
 pub trait ScalarRedaction: Default {
+    /// Returns the redacted form of the scalar.
     #[must_use]
     fn redact(self) -> Self {
         Self::default()
@@ -173,6 +381,19 @@ impl ScalarRedaction for char {
     }
 }
 
+/// Passthrough leaves that [`RedactEverything`] can replace.
+///
+/// These types have no redaction policy and pass through the default mapper
+/// unchanged. [`redact`](PassthroughRedaction::redact) returns a fixed
+/// placeholder of the same type: one for `NonZero*` integers, the unspecified
+/// address (port 0) for IP and socket addresses, zero for durations, the Unix
+/// epoch for timestamps, and the nil UUID.
+pub trait PassthroughRedaction {
+    /// Returns the placeholder that replaces the value.
+    #[must_use]
+    fn redact(self) -> Self;
+}
+
 // =============================================================================
 // redact() - Entry point function
 // =============================================================================
@@ -192,6 +413,28 @@ where
     value.redact_with(&mapper)
 }
 
+/// Redacts a value with a custom [`RedactableMapper`].
+///
+/// The value is traversed exactly as [`redact`] would traverse it, but every
+/// leaf is handed to `mapper`. See [`RedactableMapper`] for an example.
+pub fn redact_with<W, M>(value: W, mapper: &M) -> W
+where
+    W: RedactableWithMapper,
+    M: RedactableMapper,
+{
+    value.redact_with(mapper)
+}
+
+/// Redacts every leaf of a value except `#[not_sensitive]` fields.
+///
+/// See [`RedactEverything`] for which leaves are affected.
+pub fn redact_everything<W>(value: W) -> W
+where
+    W: RedactableWithMapper,
+{
+    value.redact_with(&RedactEverything)
+}
+
 /// Applies a redaction policy using its kind-aware field dispatch.
 ///
 /// Typed IP values therefore follow the same bare-field and fail-closed
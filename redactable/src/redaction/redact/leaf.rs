@@ -1,13 +1,14 @@
 //! Leaf implementations: the base cases of policy traversal.
 //!
-//! `String`, `Cow<'_, str>`, and `&str` terminate recursive policy traversal.
-//! Owned `String` and `Cow` traversal invokes the mapper; borrowed traversal
-//! applies the policy directly. This module contains those implementations,
-//! plus the formatting markers that let generated code treat these string-like
-//! leaves — and, with the `json` feature, `serde_json::Value` — as directly
-//! formattable leaves. Policy application for `serde_json::Value` itself lives
-//! in the `json` module of `redaction`, which treats it as an opaque leaf that
-//! fully redacts and deliberately ignores the mapper.
+//! `String`, `Cow<'_, str>`, `Box<str>`, `Box<dyn SensitiveText>`, and `&str`
+//! terminate recursive policy traversal. Owned `String`, `Cow`, and boxed
+//! traversal invokes the mapper; borrowed traversal applies the policy
+//! directly. This module contains those implementations, plus the formatting
+//! markers that let generated code treat these string-like leaves — and, with
+//! the `json` feature, `serde_json::Value` — as directly formattable leaves.
+//! Policy application for `serde_json::Value` itself lives in the `json` module
+//! of `redaction`, which treats it as an opaque leaf that fully redacts and
+//! deliberately ignores the mapper.
 
 use std::borrow::Cow;
 
@@ -17,6 +18,7 @@ use crate::{
         PolicyFormattingOutput,
     },
     policy::{RecursivePolicyKind, RedactionPolicy},
+    redaction::traits::SensitiveText,
 };
 
 use super::core::{PolicyApplicable, PolicyApplicableRef, RedactableMapper};
@@ -47,6 +49,28 @@ impl PolicyApplicable for Cow<'_, str> {
     }
 }
 
+impl PolicyApplicable for Box<str> {
+    fn apply_policy<P, M>(self, mapper: &M) -> Self
+    where
+        P: RedactionPolicy,
+        P::Kind: RecursivePolicyKind,
+        M: RedactableMapper,
+    {
+        mapper.map_sensitive::<_, P>(self)
+    }
+}
+
+impl PolicyApplicable for Box<dyn SensitiveText> {
+    fn apply_policy<P, M>(self, mapper: &M) -> Self
+    where
+        P: RedactionPolicy,
+        P::Kind: RecursivePolicyKind,
+        M: RedactableMapper,
+    {
+        mapper.map_sensitive::<_, P>(self)
+    }
+}
+
 // =============================================================================
 // PolicyApplicableRef: Base case implementations (leaf types)
 // =============================================================================
@@ -80,6 +104,34 @@ impl PolicyApplicableRef for Cow<'_, str> {
     }
 }
 
+impl PolicyApplicableRef for Box<str> {
+    type Output = Box<str>;
+
+    fn apply_policy_ref<P, M>(&self, _mapper: &M) -> Self::Output
+    where
+        P: RedactionPolicy,
+        P::Kind: RecursivePolicyKind,
+        M: RedactableMapper,
+    {
+        let policy = P::policy();
+        policy.apply_to(self).into_boxed_str()
+    }
+}
+
+impl PolicyApplicableRef for Box<dyn SensitiveText> {
+    type Output = Box<dyn SensitiveText>;
+
+    fn apply_policy_ref<P, M>(&self, _mapper: &M) -> Self::Output
+    where
+        P: RedactionPolicy,
+        P::Kind: RecursivePolicyKind,
+        M: RedactableMapper,
+    {
+        let policy = P::policy();
+        self.from_redacted(policy.apply_to(self.as_str()))
+    }
+}
+
 impl PolicyApplicableRef for &str {
     type Output = String;
 
@@ -114,11 +166,31 @@ macro_rules! impl_policy_ref_formatting_leaf {
     )+ };
 }
 
-impl_policy_ref_formatting_leaf!(String, Cow<'_, str>, &str);
+impl_policy_ref_formatting_leaf!(String, Cow<'_, str>, Box<str>, &str);
+
+// Formatting renders the redacted text itself, so `{:?}` quotes it like a `String` leaf
+// rather than printing the rebuilt value's `Debug`.
+impl PolicyApplicableRefForGeneratedFormatting for Box<dyn SensitiveText> {
+    type FormattingOutput = String;
+
+    fn apply_policy_ref_for_generated_formatting<P, M>(
+        &self,
+        _mapper: &M,
+    ) -> PolicyFormattingOutput<Self::FormattingOutput>
+    where
+        P: RedactionPolicy,
+        P::Kind: RecursivePolicyKind,
+        M: RedactableMapper,
+    {
+        PolicyFormattingOutput::Value(P::policy().apply_to(self.as_str()))
+    }
+}
 
 impl PolicyApplicableRefForFormatting for String {}
 impl PolicyApplicableRefForFormatting for Cow<'_, str> {}
+impl PolicyApplicableRefForFormatting for Box<str> {}
 impl PolicyApplicableRefForFormatting for &str {}
+impl PolicyApplicableRefForFormatting for Box<dyn SensitiveText> {}
 
 #[cfg(feature = "json")]
 impl_policy_ref_formatting_leaf!(serde_json::Value);
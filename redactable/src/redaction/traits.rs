@@ -3,13 +3,18 @@
 //! This module defines the fundamental traits:
 //!
 //! - [`SensitiveWithPolicy`]: Policy-aware leaf redaction
+//! - [`SensitiveText`]: Object-safe string leaf for `Box<dyn SensitiveText>` fields
+//! - [`TryRedactableWithPolicy`]: Fallible policy-aware leaf redaction
 //! - [`RedactableWithMapper`]: Types that participate in redaction traversal
 //! - [`Redactable`]: User-facing `.redact()` method
 
-use std::{borrow::Cow, collections::VecDeque};
+use std::{
+    borrow::Cow,
+    collections::{BinaryHeap, LinkedList, VecDeque},
+};
 
 use super::redact::RedactableMapper;
-use crate::policy::{RedactionPolicy, TextRedactionPolicy};
+use crate::policy::{RedactionError, RedactionPolicy, TextRedactionPolicy};
 
 // =============================================================================
 // SensitiveWithPolicy - Policy-aware leaf redaction
@@ -18,13 +23,14 @@ use crate::policy::{RedactionPolicy, TextRedactionPolicy};
 /// A type that can be redacted using a specific policy.
 ///
 /// Implement this for your types when you need them to work with
-/// [`crate::SensitiveValue<T, P>`]. This trait alone does not make a bare
-/// `#[sensitive(P)]` field work; direct annotated fields use the separate
-/// policy-application traits. The orphan rule is satisfied when the policy
-/// `P` is local to your crate.
+/// [`crate::SensitiveValue<T, P>`]. A bare `#[sensitive(P)]` field of such a
+/// type is redacted the same way, as a single leaf, but only when the type is
+/// the whole field type; inside containers, wrap it in `SensitiveValue`. The
+/// orphan rule is satisfied when the policy `P` is local to your crate.
 ///
-/// `String` and `Cow<str>` have built-in implementations for all policies.
-/// For your own types, implement this trait for the specific policy you need:
+/// `String`, `Cow<str>`, and `Box<str>` have built-in implementations for all
+/// policies. For your own types, implement this trait for the specific policy
+/// you need:
 ///
 /// ```ignore
 /// impl SensitiveWithPolicy<MyPolicy> for MyType {
@@ -64,6 +70,144 @@ impl<P: RedactionPolicy> SensitiveWithPolicy<P> for Cow<'_, str> {
     }
 }
 
+impl<P: RedactionPolicy> SensitiveWithPolicy<P> for Box<str> {
+    fn redact_with_policy(self, policy: &TextRedactionPolicy) -> Self {
+        policy.apply_to(&self).into_boxed_str()
+    }
+
+    fn redacted_string(&self, policy: &TextRedactionPolicy) -> String {
+        policy.apply_to(self)
+    }
+}
+
+// =============================================================================
+// SensitiveText - Object-safe string leaf
+// =============================================================================
+
+/// An object-safe string leaf, for sensitive values whose type is only known at runtime.
+///
+/// [`SensitiveWithPolicy`] consumes `self` and requires `Sized`, so it cannot be
+/// used as a trait object. `SensitiveText` exposes the text through a reference
+/// and rebuilds the value from its redacted form, which lets a
+/// `Box<dyn SensitiveText>` field take any `#[sensitive(Policy)]` annotation
+/// that `String` accepts. The `Debug` supertrait backs the unredacted `Debug`
+/// output that derived types print in tests.
+///
+/// ```
+/// # #![allow(hidden_glob_reexports)]
+/// # pub use redactable::*;
+/// use redactable::{Redactable, Secret, Sensitive, SensitiveText};
+///
+/// #[derive(Debug)]
+/// struct ApiKey(String);
+///
+/// impl SensitiveText for ApiKey {
+///     fn as_str(&self) -> &str {
+///         &self.0
+///     }
+///
+///     fn from_redacted(&self, redacted: String) -> Box<dyn SensitiveText> {
+///         Box::new(ApiKey(redacted))
+///     }
+/// }
+///
+/// #[derive(Sensitive)]
+/// struct Connection {
+///     #[sensitive(Secret)]
+///     credential: Box<dyn SensitiveText>,
+/// }
+///
+/// # fn main() {
+/// let connection = Connection { credential: Box::new(ApiKey("sk-live-1234".into())) };
+/// assert_eq!(connection.redact().credential.as_str(), "[REDACTED]");
+/// # }
+/// ```
+pub trait SensitiveText: std::fmt::Debug {
+    /// Returns the raw text the policy is applied to.
+    fn as_str(&self) -> &str;
+
+    /// Builds the boxed value that replaces `self` once it has been redacted.
+    #[allow(clippy::wrong_self_convention)] // Takes `&self` so it can be called through `dyn`
+    fn from_redacted(&self, redacted: String) -> Box<dyn SensitiveText>;
+}
+
+impl<P: RedactionPolicy> SensitiveWithPolicy<P> for Box<dyn SensitiveText> {
+    fn redact_with_policy(self, policy: &TextRedactionPolicy) -> Self {
+        self.from_redacted(policy.apply_to(self.as_str()))
+    }
+
+    fn redacted_string(&self, policy: &TextRedactionPolicy) -> String {
+        policy.apply_to(self.as_str())
+    }
+}
+
+// =============================================================================
+// TryRedactableWithPolicy - Fallible policy-aware leaf redaction
+// =============================================================================
+
+/// A leaf type whose policy redaction can reject malformed input.
+///
+/// [`SensitiveWithPolicy`] is total: structural policies (URLs, IP addresses,
+/// card numbers) that cannot parse their input have to fully redact it. This
+/// trait is the opt-in alternative for callers that would rather surface the
+/// malformed value as an error. Call it through
+/// [`crate::SensitiveValue::try_redact`]; derived traversal (`.redact()`)
+/// stays infallible.
+///
+/// `String`, `Cow<str>`, and `Box<str>` implement it through
+/// [`TextRedactionPolicy::try_apply_to`], so input that an IP address, date,
+/// segment, or JSON string policy cannot parse is reported rather than
+/// replaced with a placeholder.
+///
+/// ```
+/// use redactable::{
+///     CreditCard, IpAddress, RedactionError, SensitiveValue, TextRedactionPolicy,
+///     TryRedactableWithPolicy,
+/// };
+///
+/// struct CardNumber(String);
+///
+/// impl TryRedactableWithPolicy<CreditCard> for CardNumber {
+///     fn try_redact_with_policy(self, policy: &TextRedactionPolicy) -> Result<Self, RedactionError> {
+///         if !self.0.bytes().all(|byte| byte.is_ascii_digit()) {
+///             return Err(RedactionError::new("not a card number"));
+///         }
+///         Ok(Self(policy.apply_to(&self.0)))
+///     }
+/// }
+///
+/// let card = SensitiveValue::<CardNumber, CreditCard>::from(CardNumber("4111-oops".into()));
+/// assert!(card.try_redact().is_err());
+///
+/// let ip = SensitiveValue::<String, IpAddress>::from("garbage".to_string());
+/// assert!(ip.try_redact().is_err());
+/// ```
+pub trait TryRedactableWithPolicy<P>: Sized {
+    /// Returns a redacted version of `self`, or an error for malformed input.
+    ///
+    /// # Errors
+    /// Returns [`RedactionError`] when `self` cannot be redacted with `policy`.
+    fn try_redact_with_policy(self, policy: &TextRedactionPolicy) -> Result<Self, RedactionError>;
+}
+
+impl<P: RedactionPolicy> TryRedactableWithPolicy<P> for String {
+    fn try_redact_with_policy(self, policy: &TextRedactionPolicy) -> Result<Self, RedactionError> {
+        policy.try_apply_to(self.as_str())
+    }
+}
+
+impl<P: RedactionPolicy> TryRedactableWithPolicy<P> for Cow<'_, str> {
+    fn try_redact_with_policy(self, policy: &TextRedactionPolicy) -> Result<Self, RedactionError> {
+        policy.try_apply_to(self.as_ref()).map(Cow::Owned)
+    }
+}
+
+impl<P: RedactionPolicy> TryRedactableWithPolicy<P> for Box<str> {
+    fn try_redact_with_policy(self, policy: &TextRedactionPolicy) -> Result<Self, RedactionError> {
+        policy.try_apply_to(&self).map(String::into_boxed_str)
+    }
+}
+
 // =============================================================================
 // RedactableWithMapper - Types that CONTAIN sensitive data (containers)
 // =============================================================================
@@ -85,6 +229,18 @@ pub trait RedactableWithMapper: Sized {
     /// Applies redaction to this value using the provided mapper.
     #[must_use]
     fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self;
+
+    /// Applies redaction in place using the provided mapper.
+    ///
+    /// The default moves the value out with [`std::mem::take`], redacts it, and
+    /// writes the result back. If redaction panics, `self` is left as
+    /// `Default::default()`.
+    fn redact_mut_with<M: RedactableMapper>(&mut self, mapper: &M)
+    where
+        Self: Default,
+    {
+        *self = std::mem::take(self).redact_with(mapper);
+    }
 }
 
 // =============================================================================
@@ -138,6 +294,38 @@ pub trait Redactable: RedactableWithMapper {
     fn redact(self) -> Self {
         super::redact::redact(self)
     }
+
+    /// Returns a redacted copy and leaves `self` untouched.
+    ///
+    /// Equivalent to `self.clone().redact()`, for call sites that log a
+    /// redacted value and keep using the original.
+    #[must_use]
+    fn redacted_clone(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.clone().redact()
+    }
+
+    /// Redacts the value in place using policy-bound redaction.
+    ///
+    /// Equivalent to `*self = std::mem::take(self).redact()`, for values held
+    /// behind `&mut` (a log buffer entry, a field of a larger struct).
+    fn redact_mut(&mut self)
+    where
+        Self: Default,
+    {
+        self.redact_mut_with(&super::redact::PolicyMapper);
+    }
+
+    /// Redacts every leaf except `#[not_sensitive]` fields.
+    ///
+    /// See [`RedactEverything`](crate::RedactEverything) for which leaves are
+    /// affected.
+    #[must_use]
+    fn redact_everything(self) -> Self {
+        super::redact::redact_everything(self)
+    }
 }
 
 // Containers forward the certification exactly like redaction traversal walks
@@ -153,6 +341,10 @@ impl<T: Redactable> Redactable for Vec<T> {}
 
 impl<T: Redactable> Redactable for VecDeque<T> {}
 
+impl<T: Redactable> Redactable for LinkedList<T> {}
+
+impl<T: Redactable + Ord> Redactable for BinaryHeap<T> {}
+
 impl<T: Redactable, const N: usize> Redactable for [T; N] {}
 
 impl<T: Redactable> Redactable for Box<T> {}
@@ -188,6 +380,23 @@ where
 
 impl<T: Redactable + Ord> Redactable for std::collections::BTreeSet<T> {}
 
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Redactable for indexmap::IndexMap<K, V, S>
+where
+    K: std::hash::Hash + Eq,
+    V: Redactable,
+    S: std::hash::BuildHasher + Clone,
+{
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> Redactable for indexmap::IndexSet<T, S>
+where
+    T: Redactable + std::hash::Hash + Eq,
+    S: std::hash::BuildHasher + Clone,
+{
+}
+
 macro_rules! impl_tuple_redactable {
     ($($name:ident),+ $(,)?) => {
         impl<$($name),+> Redactable for ($($name,)+)
@@ -202,6 +411,14 @@ impl_tuple_redactable!(T0);
 impl_tuple_redactable!(T0, T1);
 impl_tuple_redactable!(T0, T1, T2);
 impl_tuple_redactable!(T0, T1, T2, T3);
+impl_tuple_redactable!(T0, T1, T2, T3, T4);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5, T6);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5, T6, T7);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5, T6, T7, T8);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
+impl_tuple_redactable!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11);
 
 // =============================================================================
 // Tests
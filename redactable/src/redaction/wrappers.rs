@@ -4,17 +4,23 @@
 //!
 //! - [`SensitiveValue<T, P>`]: Wraps a value and applies a redaction policy
 //! - [`NotSensitiveValue<T>`]: Wraps a value that should pass through unchanged
+//! - [`RedactKeys<M, P>`]: Wraps a map whose keys are redacted with a policy
+//! - [`Redacted<T>`]: Holds a value that has already been redacted
 
-use std::marker::PhantomData;
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
 
 #[cfg(feature = "json")]
 use serde::{Deserialize, Serialize};
 
 use super::{
     redact::RedactableMapper,
-    traits::{Redactable, RedactableWithMapper, SensitiveWithPolicy},
+    traits::{Redactable, RedactableWithMapper, SensitiveWithPolicy, TryRedactableWithPolicy},
 };
-use crate::policy::RedactionPolicy;
+use crate::policy::{RedactionError, RedactionPolicy};
 use crate::{
     __private::{
         PolicyApplicableRefForFormatting, PolicyApplicableRefForGeneratedFormatting,
@@ -61,6 +67,24 @@ where
     }
 }
 
+impl<T, P> SensitiveValue<T, P>
+where
+    T: TryRedactableWithPolicy<P>,
+    P: RedactionPolicy,
+{
+    /// Redacts the inner value with the policy `P`, reporting malformed input.
+    ///
+    /// Unlike [`Redactable::redact`], which always succeeds, this surfaces the
+    /// leaf's [`RedactionError`] instead of silently fully redacting.
+    ///
+    /// # Errors
+    /// Returns the error produced by [`TryRedactableWithPolicy::try_redact_with_policy`].
+    pub fn try_redact(self) -> Result<Self, RedactionError> {
+        let policy = P::policy();
+        self.0.try_redact_with_policy(&policy).map(Self::from)
+    }
+}
+
 impl<T, P> RedactableWithMapper for SensitiveValue<T, P>
 where
     T: SensitiveWithPolicy<P>,
@@ -189,6 +213,18 @@ where
     }
 }
 
+/// Formats the redacted form, so the wrapper can be used directly in format
+/// strings without exposing the inner value.
+impl<T, P> std::fmt::Display for SensitiveValue<T, P>
+where
+    T: SensitiveWithPolicy<P>,
+    P: RedactionPolicy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.redacted())
+    }
+}
+
 #[cfg(feature = "json")]
 impl<T, P> Serialize for SensitiveValue<T, P>
 where
@@ -305,3 +341,285 @@ where
         T::deserialize(deserializer).map(Self::from)
     }
 }
+
+// =============================================================================
+// RedactKeys - Wrapper for maps whose keys are sensitive
+// =============================================================================
+
+/// Wrapper for a `HashMap` or `BTreeMap` whose keys are redacted with policy `P`.
+///
+/// Maps normally redact only their values and leave keys untouched. Wrap a map
+/// in `RedactKeys` when the keys themselves are sensitive (account IDs, email
+/// addresses): redaction applies `P` to every key, walks every value as usual,
+/// and re-inserts the results into a fresh map.
+///
+/// ## Redaction can collapse entries
+///
+/// Distinct keys may redact to the same value (e.g. several keys becoming
+/// `"[REDACTED]"`). The redacted map keeps one entry per distinct redacted key:
+/// for `BTreeMap` the entry with the greatest original key wins, and for
+/// `HashMap` an arbitrary one of the colliding entries does.
+///
+/// `Debug` and redacted display formatting show the redacted keys.
+///
+/// **Serialization:** when the `json` feature is enabled, `serde::Serialize`
+/// emits the raw inner map unchanged, as with [`SensitiveValue`].
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use redactable::{NotSensitiveValue, RedactKeys, Redactable, Token};
+///
+/// let balances = BTreeMap::from([("acct_0012345678".to_string(), NotSensitiveValue(10))]);
+/// let redacted = RedactKeys::<_, Token>::from(balances).redact();
+/// assert_eq!(*redacted.expose()["***********5678"], 10);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct RedactKeys<M, P>(M, PhantomData<P>);
+
+impl<M, P> From<M> for RedactKeys<M, P> {
+    fn from(map: M) -> Self {
+        Self(map, PhantomData)
+    }
+}
+
+impl<M, P> RedactKeys<M, P> {
+    /// Explicitly access the inner map, including its raw keys.
+    #[must_use]
+    pub fn expose(&self) -> &M {
+        &self.0
+    }
+
+    /// Explicitly access the inner map mutably.
+    pub fn expose_mut(&mut self) -> &mut M {
+        &mut self.0
+    }
+
+    /// Consume the wrapper and return the inner map.
+    #[must_use]
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<K, V, S, P> RedactableWithMapper for RedactKeys<HashMap<K, V, S>, P>
+where
+    K: SensitiveWithPolicy<P> + Hash + Eq,
+    V: RedactableWithMapper,
+    S: BuildHasher + Clone,
+    P: RedactionPolicy,
+{
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        let hasher = self.0.hasher().clone();
+        let mut result = HashMap::with_capacity_and_hasher(self.0.len(), hasher);
+        result.extend(
+            self.0
+                .into_iter()
+                .map(|(k, v)| (mapper.map_sensitive::<K, P>(k), v.redact_with(mapper))),
+        );
+        Self::from(result)
+    }
+}
+
+impl<K, V, P> RedactableWithMapper for RedactKeys<BTreeMap<K, V>, P>
+where
+    K: SensitiveWithPolicy<P> + Ord,
+    V: RedactableWithMapper,
+    P: RedactionPolicy,
+{
+    fn redact_with<M: RedactableMapper>(self, mapper: &M) -> Self {
+        Self::from(
+            self.0
+                .into_iter()
+                .map(|(k, v)| (mapper.map_sensitive::<K, P>(k), v.redact_with(mapper)))
+                .collect::<BTreeMap<_, _>>(),
+        )
+    }
+}
+
+impl<K, V, S, P> Redactable for RedactKeys<HashMap<K, V, S>, P>
+where
+    K: SensitiveWithPolicy<P> + Hash + Eq,
+    V: Redactable,
+    S: BuildHasher + Clone,
+    P: RedactionPolicy,
+{
+}
+
+impl<K, V, P> Redactable for RedactKeys<BTreeMap<K, V>, P>
+where
+    K: SensitiveWithPolicy<P> + Ord,
+    V: Redactable,
+    P: RedactionPolicy,
+{
+}
+
+impl<K, V, S, P> std::fmt::Debug for RedactKeys<HashMap<K, V, S>, P>
+where
+    K: SensitiveWithPolicy<P>,
+    V: std::fmt::Debug,
+    P: RedactionPolicy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let policy = P::policy();
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k.redacted_string(&policy), v)))
+            .finish()
+    }
+}
+
+impl<K, V, P> std::fmt::Debug for RedactKeys<BTreeMap<K, V>, P>
+where
+    K: SensitiveWithPolicy<P>,
+    V: std::fmt::Debug,
+    P: RedactionPolicy,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let policy = P::policy();
+        f.debug_map()
+            .entries(self.0.iter().map(|(k, v)| (k.redacted_string(&policy), v)))
+            .finish()
+    }
+}
+
+impl<K, V, S, P> RedactableWithFormatter for RedactKeys<HashMap<K, V, S>, P>
+where
+    K: SensitiveWithPolicy<P>,
+    V: RedactableWithFormatter,
+    P: RedactionPolicy,
+{
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let policy = P::policy();
+        let mut map = f.debug_map();
+        for (key, value) in &self.0 {
+            map.entry(&key.redacted_string(&policy), &value.redacted_display());
+        }
+        map.finish()
+    }
+}
+
+impl<K, V, P> RedactableWithFormatter for RedactKeys<BTreeMap<K, V>, P>
+where
+    K: SensitiveWithPolicy<P>,
+    V: RedactableWithFormatter,
+    P: RedactionPolicy,
+{
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let policy = P::policy();
+        let mut map = f.debug_map();
+        for (key, value) in &self.0 {
+            map.entry(&key.redacted_string(&policy), &value.redacted_display());
+        }
+        map.finish()
+    }
+}
+
+#[cfg(feature = "json")]
+impl<M: Serialize, P> Serialize for RedactKeys<M, P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+// =============================================================================
+// Redacted - Proof that a value has already been redacted
+// =============================================================================
+
+/// A value that has already been redacted.
+///
+/// `Redacted<T>` is produced by redaction: the companion types generated by
+/// `#[sensitive(gen_redacted_type)]` wrap each policy field in it. There is no
+/// `From` or `Deserialize` impl. The only constructor is a `#[doc(hidden)]`
+/// hook that generated code calls right after redacting; it is not part of the
+/// public API, so an API that accepts `Redacted<T>` (or a generated `*Redacted`
+/// type) only receives values that went through their policy unless a caller
+/// reaches into the crate's internals.
+///
+/// ```ignore
+/// use redactable::{Redacted, Secret, Sensitive};
+///
+/// #[derive(Clone, Sensitive)]
+/// #[sensitive(gen_redacted_type)]
+/// struct Login {
+///     username: String,
+///     #[sensitive(Secret)]
+///     password: String,
+/// }
+///
+/// fn audit(login: LoginRedacted) {
+///     let password: &Redacted<String> = &login.password;
+///     assert_eq!(password.as_str(), "[REDACTED]");
+/// }
+/// ```
+///
+/// Traversal passes the value through unchanged, and `Debug`, `Display`, and
+/// (with the `json` feature) `Serialize` forward to the redacted value.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Redacted<T>(T);
+
+impl<T> Redacted<T> {
+    /// Wraps a value the caller has just redacted.
+    pub(crate) fn from_redacted(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns the redacted value.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> RedactableWithMapper for Redacted<T> {
+    fn redact_with<M: RedactableMapper>(self, _mapper: &M) -> Self {
+        self
+    }
+}
+
+// The value already went through its policy, so traversal is a declared no-op.
+impl<T> Redactable for Redacted<T> {}
+
+impl<T> std::ops::Deref for Redacted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Redacted<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<T: std::fmt::Display> RedactableWithFormatter for Redacted<T> {
+    fn fmt_redacted(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: Serialize> Serialize for Redacted<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
@@ -15,16 +15,20 @@
 
 use std::fmt;
 
-use serde::Serialize;
-use slog::{Key, Record, Result as SlogResult, Serializer, Value as SlogValue};
+use serde::{Serialize, Serializer as SerdeSerializer};
+use serde_json::Value as JsonValue;
+use slog::{
+    BytesKind, Key, Record, Result as SlogResult, SerdeValue, Serializer, Value as SlogValue,
+};
 
 pub use crate::redaction::RedactedJson;
 use crate::{
     policy::RedactionPolicy,
     redaction::{
-        NotSensitive, NotSensitiveDebug, NotSensitiveDisplay, NotSensitiveJson, Redactable,
-        RedactableWithFormatter, RedactedJsonRef, RedactedOutput, RedactedOutputRef,
-        SensitiveValue, SensitiveWithPolicy, ToRedactedOutput, serialize_redacted_json,
+        IntoRedactedJsonExt, NotSensitive, NotSensitiveBytes, NotSensitiveDebug,
+        NotSensitiveDisplay, NotSensitiveJson, Redactable, RedactableWithFormatter,
+        RedactedJsonRef, RedactedOutput, RedactedOutputRef, SensitiveValue, SensitiveWithPolicy,
+        ToRedactedOutput, serialize_redacted_json,
     },
 };
 
@@ -48,6 +52,40 @@ pub trait SlogRedacted: SlogValue {}
 
 impl<T: SlogRedacted + ?Sized> SlogRedacted for &T {}
 
+/// Borrowed redacted JSON handed to `slog` as a nested value.
+///
+/// `slog::Serde` owns its value, so emitting through it would copy the whole
+/// document on every log call. Serializers read this view in place; only
+/// drains that move the record to another thread (`to_sendable`) copy it.
+struct BorrowedJson<'a>(&'a JsonValue);
+
+impl Serialize for BorrowedJson<'_> {
+    fn serialize<S: SerdeSerializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl SlogValue for BorrowedJson<'_> {
+    fn serialize(
+        &self,
+        _record: &Record<'_>,
+        key: Key,
+        serializer: &mut dyn Serializer,
+    ) -> SlogResult {
+        serializer.emit_serde(key, self)
+    }
+}
+
+impl SerdeValue for BorrowedJson<'_> {
+    fn as_serde(&self) -> &dyn erased_serde::Serialize {
+        self.0
+    }
+
+    fn to_sendable(&self) -> Box<dyn SerdeValue + Send + 'static> {
+        Box::new(slog::Serde(self.0.clone()))
+    }
+}
+
 impl SlogValue for RedactedJson {
     fn serialize(
         &self,
@@ -55,8 +93,7 @@ impl SlogValue for RedactedJson {
         key: Key,
         serializer: &mut dyn Serializer,
     ) -> SlogResult {
-        let nested = slog::Serde(self.value().clone());
-        SlogValue::serialize(&nested, record, key, serializer)
+        SlogValue::serialize(&BorrowedJson(self.value()), record, key, serializer)
     }
 }
 
@@ -72,9 +109,9 @@ fn emit_output(
         RedactedOutput::Text(text) => serializer.emit_str(key, text),
         #[cfg(feature = "json")]
         RedactedOutput::Json(json) => {
-            let nested = slog::Serde(json.clone());
-            SlogValue::serialize(&nested, record, key, serializer)
+            SlogValue::serialize(&BorrowedJson(json), record, key, serializer)
         }
+        RedactedOutput::Bytes(bytes) => serializer.emit_bytes(key, bytes, BytesKind::PlainValue),
     }
 }
 
@@ -124,6 +161,7 @@ impl_slog_redacted!(@ [T, P] SensitiveValue<T, P> where T: SensitiveWithPolicy<P
 impl_slog_redacted!(@ [T] NotSensitiveDisplay<T> where T: fmt::Display);
 impl_slog_redacted!(@ [T] NotSensitiveDebug<T> where T: fmt::Debug);
 impl_slog_redacted!(@ [T] NotSensitiveJson<'_, T> where T: Serialize + ?Sized);
+impl_slog_redacted!(@ [T] NotSensitiveBytes<'_, T> where T: AsRef<[u8]> + ?Sized);
 impl_slog_redacted!(@ [T] RedactedOutputRef<'_, T> where T: Redactable + Clone + fmt::Debug);
 impl_slog_redacted!(@ [T] RedactedJsonRef<'_, T> where T: Redactable + Clone + Serialize);
 
@@ -139,6 +177,10 @@ impl_slog_redacted!(@ [T] RedactedJsonRef<'_, T> where T: Redactable + Clone + S
 /// using `#[redactable(recursive)]`. `Debug` is not required because this path
 /// only redacts and serializes the resulting value.
 ///
+/// The value is never cloned, and the returned [`RedactedJson`] is handed to
+/// `slog` by reference: only drains that send records to another thread copy
+/// the redacted document.
+///
 /// # Panics
 ///
 /// The adapter does not clone the value before redacting (unlike the borrowed adapters), but a type's own `.redact()` may clone internally: traversal through
@@ -154,15 +196,18 @@ impl_slog_redacted!(@ [T] RedactedJsonRef<'_, T> where T: Redactable + Clone + S
 ///
 /// info!(logger, "event"; "data" => event.slog_redacted_json());
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be logged as redacted slog JSON",
+    label = "requires `Redactable + Serialize`",
+    note = "raw values such as `String` have no redaction behavior; wrap them in `SensitiveValue` to redact them or `NotSensitiveValue` to log them as-is, or derive `Sensitive` and `Serialize` on your own types"
+)]
 pub trait SlogRedactedExt: Redactable + Serialize + Sized {
     /// Redacts `self` and returns a `slog::Value` that serializes as structured JSON.
     ///
     /// If converting the redacted output into `serde_json::Value` fails, the
     /// returned value stores the fixed JSON string `"[REDACTED]"`.
     fn slog_redacted_json(self) -> RedactedJson {
-        let redacted = self.redact();
-        let json_value = serialize_redacted_json(redacted);
-        RedactedJson::new(json_value)
+        self.into_redacted_json()
     }
 }
 
@@ -256,6 +301,11 @@ impl<T> SlogRedacted for RedactedDisplayValue<'_, T> where
 ///
 /// info!(logger, "event"; "data" => event.slog_redacted_display());
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be logged as redacted slog display output",
+    label = "requires `RedactableWithFormatter + ToRedactedOutput`",
+    note = "raw values such as `String` format unchanged; derive `SensitiveDisplay` on your own types, or wrap values in `NotSensitiveDisplay` to log them as-is"
+)]
 pub trait SlogRedactedDisplayExt: RedactableWithFormatter {
     /// Wraps `&self` for slog logging using `RedactableWithFormatter` formatting.
     fn slog_redacted_display(&self) -> RedactedDisplayValue<'_, Self>
@@ -6,7 +6,9 @@
 //!   before handing its `Debug` form to tracing.
 //!
 //! - **[`TracingRedactedExt`]**: logs `ToRedactedOutput` values as display
-//!   strings. Works with any tracing subscriber but loses structure.
+//!   strings. Works with any tracing subscriber but loses structure. Its
+//!   `as_tracing_field` method, like the free [`field`] function, records a
+//!   `SensitiveDisplay` value's redacted display without an intermediate `String`.
 //!
 //! - **`TracingValuableExt`** (requires the `tracing-valuable` feature and
 //!   `RUSTFLAGS="--cfg tracing_unstable"`): logs redacted values as structured
@@ -50,9 +52,9 @@ use tracing::field::{DebugValue, DisplayValue, debug, display};
 use crate::{
     policy::RedactionPolicy,
     redaction::{
-        NotSensitive, NotSensitiveDebug, NotSensitiveDisplay, NotSensitiveJson, Redactable,
-        RedactedJson, RedactedJsonRef, RedactedOutput, RedactedOutputRef, SensitiveValue,
-        SensitiveWithPolicy, ToRedactedOutput,
+        NotSensitive, NotSensitiveBytes, NotSensitiveDebug, NotSensitiveDisplay, NotSensitiveJson,
+        Redactable, RedactableWithFormatter, RedactedFormatterRef, RedactedJson, RedactedJsonRef,
+        RedactedOutput, RedactedOutputRef, SensitiveValue, SensitiveWithPolicy, ToRedactedOutput,
     },
 };
 
@@ -143,6 +145,51 @@ pub trait TracingRedactedExt {
     /// cloning their complete value panics, including a traversed
     /// [`std::cell::RefCell`] with a live mutable borrow.
     fn tracing_redacted(&self) -> DisplayValue<String>;
+
+    /// Wraps the value as a `tracing` display field rendered by
+    /// [`RedactableWithFormatter::fmt_redacted`].
+    ///
+    /// Unlike [`Self::tracing_redacted`], nothing is cloned or collected into a
+    /// `String` up front; the subscriber formats the redacted display when it
+    /// records the field. See [`field`].
+    fn as_tracing_field(&self) -> DisplayValue<RedactedFormatterRef<'_, Self>>
+    where
+        Self: RedactableWithFormatter + TracingRedacted + Sized,
+    {
+        field(self)
+    }
+}
+
+/// Wraps a value as a `tracing` display field rendered by
+/// [`RedactableWithFormatter::fmt_redacted`].
+///
+/// The `TracingRedacted` bound limits this to types whose redacted display is
+/// logging-safe, which `SensitiveDisplay` and `SensitiveDual` derive for you.
+/// Structural `Sensitive` types have no redacted display; log them with
+/// [`TracingRedactedDebugExt::tracing_redacted_debug`] instead.
+///
+/// ```
+/// # #![allow(hidden_glob_reexports)]
+/// # pub use redactable::*;
+/// use redactable::{Secret, SensitiveDisplay};
+///
+/// #[derive(SensitiveDisplay)]
+/// #[error("login failed for {user}")]
+/// struct LoginError {
+///     #[sensitive(Secret)]
+///     user: String,
+/// }
+///
+/// # fn main() {
+/// let error = LoginError { user: "alice".to_owned() };
+/// ::tracing::info!(error = redactable::tracing::field(&error));
+/// # }
+/// ```
+pub fn field<T>(value: &T) -> DisplayValue<RedactedFormatterRef<'_, T>>
+where
+    T: RedactableWithFormatter + TracingRedacted,
+{
+    display(value.redacted_display())
 }
 
 impl<T> TracingRedactedExt for T
@@ -150,13 +197,7 @@ where
     T: ToRedactedOutput,
 {
     fn tracing_redacted(&self) -> DisplayValue<String> {
-        let output = self.to_redacted_output();
-        let text = match output {
-            RedactedOutput::Text(text) => text,
-            #[cfg(feature = "json")]
-            RedactedOutput::Json(json) => json.to_string(),
-        };
-        display(text)
+        display(self.to_redacted_output().to_string_compact())
     }
 }
 
@@ -178,6 +219,8 @@ impl<T> TracingRedacted for NotSensitiveDebug<T> where T: fmt::Debug {}
 
 impl<T> TracingRedacted for NotSensitive<T> {}
 
+impl<T> TracingRedacted for NotSensitiveBytes<'_, T> where T: AsRef<[u8]> + ?Sized {}
+
 impl<T> TracingRedacted for RedactedOutputRef<'_, T> where T: Redactable + Clone + fmt::Debug {}
 
 #[cfg(feature = "json")]
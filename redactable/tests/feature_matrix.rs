@@ -0,0 +1,337 @@
+//! Feature-combination matrix for the derive expansion.
+//!
+//! `redactable-derive` emits extra impls behind `cfg(feature = ...)` branches
+//! (`serde`, `slog`, `tracing`), and `redactable` gates the traits those impls
+//! name. This file derives the same representative types under whichever
+//! features are enabled and asserts that every active branch compiles and
+//! redacts. Run it once per combination: no integration features, each of
+//! `json`, `slog`, `tracing`, and `log` alone, and all features together. The
+//! `feature-matrix` CI job does exactly that.
+
+#![cfg(feature = "redaction")]
+
+use redactable::{
+    Redactable, RedactableWithFormatter, Secret, Sensitive, SensitiveDisplay, SensitiveDual, Token,
+};
+
+const PASSWORD: &str = "feature-matrix-password-5e2a";
+const API_KEY: &str = "sk_live_feature1234";
+
+#[derive(Clone, Sensitive)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+struct Account {
+    username: String,
+    #[sensitive(Secret)]
+    password: String,
+    #[sensitive(Token)]
+    api_key: String,
+    attempts: u32,
+}
+
+#[derive(Clone, Sensitive)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+enum AuthEvent {
+    Login {
+        user: String,
+        #[sensitive(Secret)]
+        password: String,
+    },
+    Logout,
+}
+
+#[derive(SensitiveDisplay)]
+#[error("login failed for {user} with {password}")]
+struct LoginError {
+    user: String,
+    #[sensitive(Secret)]
+    password: String,
+}
+
+#[derive(Clone, SensitiveDual)]
+#[cfg_attr(feature = "json", derive(serde::Serialize))]
+#[error("{label}: {password}")]
+struct Credential {
+    label: String,
+    #[sensitive(Secret)]
+    password: String,
+}
+
+fn account() -> Account {
+    Account {
+        username: "alice".into(),
+        password: PASSWORD.into(),
+        api_key: API_KEY.into(),
+        attempts: 3,
+    }
+}
+
+fn login_error() -> LoginError {
+    LoginError {
+        user: "alice".into(),
+        password: PASSWORD.into(),
+    }
+}
+
+fn credential() -> Credential {
+    Credential {
+        label: "primary".into(),
+        password: PASSWORD.into(),
+    }
+}
+
+// =============================================================================
+// Every combination
+// =============================================================================
+
+#[test]
+fn structural_derive_redacts() {
+    let redacted = account().redact();
+
+    assert_eq!(redacted.username, "alice");
+    assert_eq!(redacted.password, "[REDACTED]");
+    assert_eq!(redacted.api_key, "***************1234");
+    assert_eq!(redacted.attempts, 3);
+    assert_eq!(Account::REDACTED_FIELDS, ["password", "api_key"]);
+}
+
+#[test]
+fn enum_derive_redacts_each_variant() {
+    let login = AuthEvent::Login {
+        user: "alice".into(),
+        password: PASSWORD.into(),
+    };
+
+    let AuthEvent::Login { user, password } = login.redact() else {
+        panic!("redaction must keep the variant");
+    };
+    assert_eq!(user, "alice");
+    assert_eq!(password, "[REDACTED]");
+    assert!(matches!(AuthEvent::Logout.redact(), AuthEvent::Logout));
+}
+
+#[test]
+fn display_derive_redacts_template() {
+    assert_eq!(
+        login_error().redacted_display().to_string(),
+        "login failed for alice with [REDACTED]"
+    );
+}
+
+#[test]
+fn dual_derive_redacts_structure_and_template() {
+    let credential = credential();
+
+    assert_eq!(
+        credential.redacted_display().to_string(),
+        "primary: [REDACTED]"
+    );
+    assert_eq!(credential.redact().password, "[REDACTED]");
+}
+
+// =============================================================================
+// No integration features
+// =============================================================================
+
+#[cfg(not(any(
+    feature = "json",
+    feature = "slog",
+    feature = "tracing",
+    feature = "log"
+)))]
+#[test]
+fn derives_need_no_integration_feature() {
+    use redactable::{RedactedOutput, RedactedOutputExt, ToRedactedOutput};
+
+    assert!(matches!(
+        account().redacted_output().to_redacted_output(),
+        RedactedOutput::Text(text) if !text.contains(PASSWORD)
+    ));
+}
+
+// =============================================================================
+// json
+// =============================================================================
+
+#[cfg(feature = "json")]
+mod json {
+    use redactable::{IntoRedactedJsonExt, RedactedJsonExt, RedactedOutput, ToRedactedOutput};
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[sensitive(serde_redact)]
+    struct SerializedAccount {
+        username: String,
+        #[sensitive(Secret)]
+        password: String,
+    }
+
+    #[test]
+    fn redacted_json_serializes_redacted_fields() {
+        let expected = json!({
+            "username": "alice",
+            "password": "[REDACTED]",
+            "api_key": "***************1234",
+            "attempts": 3,
+        });
+
+        assert_eq!(
+            account().redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected.clone())
+        );
+        assert_eq!(
+            account().into_redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected)
+        );
+    }
+
+    #[test]
+    fn serde_redact_emits_redacted_serialize_impl() {
+        let account = SerializedAccount {
+            username: "alice".into(),
+            password: PASSWORD.into(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&account).unwrap(),
+            json!({ "username": "alice", "password": "[REDACTED]" })
+        );
+    }
+}
+
+// =============================================================================
+// slog
+// =============================================================================
+
+#[cfg(feature = "slog")]
+#[path = "support/slog_capture.rs"]
+mod slog_capture;
+
+#[cfg(feature = "slog")]
+mod slog_integration {
+    use redactable::slog::{SlogRedacted, SlogRedactedDisplayExt, SlogRedactedExt};
+
+    use super::{
+        slog_capture::{CapturedValue, CapturingSerializer, serialize_to_capture},
+        *,
+    };
+
+    fn assert_slog_redacted<T: SlogRedacted>(_: &T) {}
+
+    fn capture<V: slog::Value>(value: &V) -> Option<CapturedValue> {
+        let mut serializer = CapturingSerializer::new();
+        serialize_to_capture(value, "event", &mut serializer);
+        serializer.get("event")
+    }
+
+    #[test]
+    fn generated_slog_values_are_redacted() {
+        let account = account();
+        let error = login_error();
+        let credential = credential();
+        assert_slog_redacted(&account);
+        assert_slog_redacted(&error);
+        assert_slog_redacted(&credential);
+
+        assert_eq!(
+            capture(&account),
+            Some(CapturedValue::Serde(serde_json::json!("[REDACTED]")))
+        );
+        assert_eq!(
+            capture(&error),
+            Some(CapturedValue::Str(
+                "login failed for alice with [REDACTED]".into()
+            ))
+        );
+        assert_eq!(
+            capture(&credential.slog_redacted_display()),
+            Some(CapturedValue::Str("primary: [REDACTED]".into()))
+        );
+    }
+
+    #[test]
+    fn slog_redacted_json_serializes_redacted_fields() {
+        let Some(CapturedValue::Serde(json)) = capture(&account().slog_redacted_json()) else {
+            panic!("slog_redacted_json must emit a nested value");
+        };
+
+        assert_eq!(json["password"], "[REDACTED]");
+        assert_eq!(json["username"], "alice");
+    }
+}
+
+// =============================================================================
+// tracing
+// =============================================================================
+
+#[cfg(feature = "tracing")]
+mod tracing_integration {
+    use redactable::tracing::{TracingRedacted, TracingRedactedDebugExt, TracingRedactedExt};
+
+    use super::*;
+
+    fn assert_tracing_redacted<T: TracingRedacted>(_: &T) {}
+
+    #[test]
+    fn generated_tracing_markers_are_present() {
+        assert_tracing_redacted(&account());
+        assert_tracing_redacted(&login_error());
+        assert_tracing_redacted(&credential());
+    }
+
+    #[test]
+    fn tracing_adapters_render_redacted_values() {
+        let error = login_error();
+
+        assert_eq!(
+            error.as_tracing_field().to_string(),
+            "login failed for alice with [REDACTED]"
+        );
+        assert!(!error.tracing_redacted().to_string().contains(PASSWORD));
+        assert!(!format!("{:?}", account().tracing_redacted_debug()).contains(PASSWORD));
+    }
+}
+
+// =============================================================================
+// log
+// =============================================================================
+
+#[cfg(feature = "log")]
+mod log_integration {
+    use redactable::log::LogRedactedExt;
+
+    use super::*;
+
+    #[test]
+    fn log_adapter_renders_redacted_display() {
+        assert_eq!(
+            login_error().as_log().to_string(),
+            "login failed for alice with [REDACTED]"
+        );
+        assert_eq!(credential().as_log().to_string(), "primary: [REDACTED]");
+    }
+}
+
+// =============================================================================
+// All integration features together
+// =============================================================================
+
+#[cfg(all(
+    feature = "json",
+    feature = "slog",
+    feature = "tracing",
+    feature = "log"
+))]
+#[test]
+fn every_text_adapter_agrees_when_all_features_are_enabled() {
+    use redactable::{log::LogRedactedExt, tracing::TracingRedactedExt};
+
+    let error = login_error();
+    let expected = error.redacted_display().to_string();
+
+    assert_eq!(error.as_log().to_string(), expected);
+    assert_eq!(error.as_tracing_field().to_string(), expected);
+    assert_eq!(error.tracing_redacted().to_string(), expected);
+    assert!(!expected.contains(PASSWORD));
+}
@@ -207,3 +207,113 @@ fn const_generic_key_does_not_capture_generated_slog_key() {
     let output = format!("{:?}", value.redact());
     assert!(!output.contains(CANARY));
 }
+
+/// A caller-scope module named `redactable` must not capture generated paths.
+///
+/// Every generated reference to the runtime crate is rooted (`::redactable`), so
+/// the local module below is never consulted even though its items shadow the
+/// names the expansion uses.
+mod local_redactable_module {
+    use ::redactable::{
+        NotSensitive, Redactable, RedactableWithFormatter, Secret, Sensitive, SensitiveDisplay,
+        SensitiveDual, Token,
+    };
+    use serde::Serialize;
+
+    use super::CANARY;
+
+    mod redactable {
+        pub struct Secret;
+        pub trait RedactableWithMapper {}
+        pub trait RedactableMapper {}
+        pub trait Redactable {}
+        pub trait RedactableWithFormatter {}
+        pub const REDACTED_PLACEHOLDER: &str = "captured";
+        pub mod __private {}
+    }
+
+    #[derive(Clone, Serialize, Sensitive)]
+    struct Named {
+        #[sensitive(Secret)]
+        password: String,
+        #[sensitive(Token)]
+        token: Option<String>,
+        label: String,
+    }
+
+    #[derive(Clone, Serialize, Sensitive)]
+    struct Tuple(#[sensitive(Secret)] String, u32);
+
+    #[derive(Clone, Serialize, Sensitive)]
+    enum Credential {
+        Password {
+            #[sensitive(Secret)]
+            value: String,
+        },
+        Anonymous,
+    }
+
+    #[derive(SensitiveDisplay)]
+    #[error("login {user} with {password}")]
+    struct Login {
+        user: String,
+        #[sensitive(Secret)]
+        password: String,
+    }
+
+    #[derive(Clone, Serialize, SensitiveDual)]
+    #[error("dual {value}")]
+    struct Dual {
+        #[sensitive(Secret)]
+        value: String,
+    }
+
+    #[derive(Clone, Serialize, NotSensitive)]
+    struct Public {
+        label: String,
+    }
+
+    #[test]
+    fn derives_resolve_the_runtime_crate_from_the_extern_prelude() {
+        let named = Named {
+            password: CANARY.into(),
+            token: Some("tok_12345678".into()),
+            label: "visible".into(),
+        }
+        .redact();
+        assert_eq!(named.password, "[REDACTED]");
+        assert_eq!(named.token.as_deref(), Some("********5678"));
+        assert_eq!(named.label, "visible");
+
+        let tuple = Tuple(CANARY.into(), 7).redact();
+        assert_eq!(tuple.0, "[REDACTED]");
+        assert_eq!(tuple.1, 7);
+
+        let credential = Credential::Password {
+            value: CANARY.into(),
+        }
+        .redact();
+        assert!(matches!(credential, Credential::Password { value } if value == "[REDACTED]"));
+        let _ = Credential::Anonymous.redact();
+
+        let login = Login {
+            user: "alice".into(),
+            password: CANARY.into(),
+        }
+        .redacted_display()
+        .to_string();
+        assert_eq!(login, "login alice with [REDACTED]");
+
+        let dual = Dual {
+            value: CANARY.into(),
+        };
+        assert_eq!(dual.redacted_display().to_string(), "dual [REDACTED]");
+        assert_eq!(dual.redact().value, "[REDACTED]");
+
+        let public = Public {
+            label: "visible".into(),
+        }
+        .redact();
+        assert_eq!(public.label, "visible");
+    }
+}
@@ -123,20 +123,20 @@ where
 
 #[test]
 fn bare_alias_and_supported_workarounds_redact_without_canaries() {
-    const BARE: &str = "203.0.113.71";
-    const WRAPPED: &str = "203.0.113.72";
-    const NESTED: &str = "203.0.113.73";
-    const SET: &str = "203.0.113.74";
+    const BARE: &str = "203.71.113.5";
+    const WRAPPED: &str = "203.72.113.5";
+    const NESTED: &str = "203.73.113.5";
+    const SET: &str = "203.74.113.5";
     const TEXT: &str = "203.0.113.173";
 
     let bare = BareAlias { value: ip(BARE) }.redact();
-    assert_eq!(bare.value, ip("0.0.0.71"));
+    assert_eq!(bare.value, ip("203.71.0.0"));
     assert!(!format!("{bare:?}").contains(BARE));
 
     let display = BareAliasDisplay { value: ip(BARE) }
         .redacted_display()
         .to_string();
-    assert_eq!(display, "0.0.0.71");
+    assert_eq!(display, "203.71.0.0");
     assert!(!display.contains(BARE));
 
     let recursive = SupportedRecursiveRoutes {
@@ -146,19 +146,19 @@ fn bare_alias_and_supported_workarounds_redact_without_canaries() {
         text_values: HashMap::from([(1, TEXT.to_owned())]),
     }
     .redact();
-    assert_eq!(recursive.wrapped[0].expose(), &ip("0.0.0.72"));
+    assert_eq!(recursive.wrapped[0].expose(), &ip("203.72.0.0"));
     assert_eq!(
         recursive.nested[&7][0]
             .as_ref()
             .expect("wrapped IP")
             .expose(),
-        &ip("0.0.0.73")
+        &ip("203.73.0.0")
     );
     assert_eq!(
         recursive.set.first().expect("wrapped IP").expose(),
-        &ip("0.0.0.74")
+        &ip("203.74.0.0")
     );
-    assert_eq!(recursive.text_values[&1], "*********.173");
+    assert_eq!(recursive.text_values[&1], "203.0.*.*");
     let rendered = format!("{recursive:?}");
     assert!(!rendered.contains(WRAPPED));
     assert!(!rendered.contains(NESTED));
@@ -171,8 +171,8 @@ fn bare_alias_and_supported_workarounds_redact_without_canaries() {
     }
     .redacted_display()
     .to_string();
-    assert!(display.contains("0.0.0.73"));
-    assert!(display.contains("0.0.0.74"));
+    assert!(display.contains("203.73.0.0"));
+    assert!(display.contains("203.74.0.0"));
     assert!(!display.contains(NESTED));
     assert!(!display.contains(SET));
 }
@@ -185,28 +185,28 @@ fn generic_policy_dispatch_waits_for_the_concrete_kind() {
         marker: PhantomData,
     }
     .redact();
-    assert_eq!(value.text_values[&true], "**********.199");
+    assert_eq!(value.text_values[&true], "198.51.*.*");
     assert!(!format!("{value:?}").contains(CANARY));
 }
 
 #[test]
 fn free_policy_functions_use_kind_aware_dispatch() {
-    const BARE: &str = "203.0.113.81";
-    const WRAPPED: &str = "203.0.113.82";
+    const BARE: &str = "203.81.113.5";
+    const WRAPPED: &str = "203.82.113.5";
     const TEXT: &str = "203.0.113.183";
 
     let bare = generic_apply::<IpAddress, _>(ip(BARE));
-    assert_eq!(bare, ip("0.0.0.81"));
+    assert_eq!(bare, ip("203.81.0.0"));
     let bare_ref = generic_apply_ref::<IpAddress, _>(&ip(BARE));
-    assert_eq!(bare_ref, "0.0.0.81");
+    assert_eq!(bare_ref, "203.81.0.0");
 
     let wrapped = HashMap::from([(1_u8, SensitiveValue::from(ip(WRAPPED)))]);
     let redacted = generic_apply::<IpAddress, _>(wrapped);
-    assert_eq!(redacted[&1].expose(), &ip("0.0.0.82"));
+    assert_eq!(redacted[&1].expose(), &ip("203.82.0.0"));
 
     let text = HashMap::from([(true, TEXT.to_owned())]);
     let redacted_ref = generic_apply_ref::<IpAddress, _>(&text);
-    assert_eq!(redacted_ref[&true], "*********.183");
+    assert_eq!(redacted_ref[&true], "203.0.*.*");
     assert!(!format!("{redacted_ref:?}").contains(TEXT));
 }
 
@@ -261,8 +261,8 @@ fn annotated_wrappers_stay_redacted_through_slog() {
     use redactable::slog::SlogRedactedExt;
     use support::slog_capture::{CapturedValue, CapturingSerializer, serialize_to_capture};
 
-    const NESTED: &str = "198.51.100.201";
-    const SET: &str = "198.51.100.202";
+    const NESTED: &str = "198.201.100.7";
+    const SET: &str = "198.202.100.7";
     let event = SupportedRecursiveRoutes {
         wrapped: vec![SensitiveValue::from(ip("198.51.100.200"))],
         nested: HashMap::from([(7, vec![Some(SensitiveValue::from(ip(NESTED)))])]),
@@ -276,8 +276,8 @@ fn annotated_wrappers_stay_redacted_through_slog() {
         panic!("expected structured slog JSON");
     };
     let rendered = json.to_string();
-    assert!(rendered.contains("0.0.0.201"));
-    assert!(rendered.contains("0.0.0.202"));
+    assert!(rendered.contains("198.201.0.0"));
+    assert!(rendered.contains("198.202.0.0"));
     assert!(!rendered.contains(NESTED));
     assert!(!rendered.contains(SET));
 }
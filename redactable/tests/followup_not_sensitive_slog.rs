@@ -5,7 +5,8 @@
 use std::cell::RefCell;
 
 use redactable::{
-    NotSensitiveExt, NotSensitiveJsonExt, RedactedOutput, ToRedactedOutput, slog::SlogRedacted,
+    NotSensitiveBytesExt, NotSensitiveExt, NotSensitiveJsonExt, RedactedOutput, ToRedactedOutput,
+    slog::SlogRedacted,
 };
 use serde::Serialize;
 
@@ -116,6 +117,27 @@ fn not_sensitive_wrapper_is_slog_certified_and_delegates_raw_output() {
     );
 }
 
+#[test]
+fn not_sensitive_bytes_are_slog_certified_and_emitted_as_bytes() {
+    fn assert_slog_certified<T: SlogRedacted>(_: &T) {}
+
+    let payload = vec![0x00_u8, 0x7f, 0xff];
+    let wrapped = payload.not_sensitive_bytes();
+    assert_slog_certified(&wrapped);
+
+    let mut serializer = CapturingSerializer::new();
+    serialize_to_capture(&wrapped, "payload", &mut serializer);
+    serialize_to_capture(&wrapped.to_redacted_output(), "output", &mut serializer);
+    assert_eq!(
+        serializer.get("payload"),
+        Some(CapturedValue::Bytes(payload.clone()))
+    );
+    assert_eq!(
+        serializer.get("output"),
+        Some(CapturedValue::Bytes(payload))
+    );
+}
+
 #[test]
 fn generated_not_sensitive_slog_preserves_raw_values_and_fail_closes_borrow_conflicts() {
     const RAW_VALUE: &str = "declared-public-refcell-value-4b19";
@@ -12,8 +12,8 @@ use std::collections::{BTreeMap, HashMap};
 use redactable::{
     NotSensitive, NotSensitiveDebugExt, NotSensitiveDisplayExt, NotSensitiveExt, Redactable,
     RedactedOutput, RedactedOutputExt, RedactionPolicy, Secret, Sensitive, SensitiveDisplay,
-    SensitiveValue, SensitiveWithPolicy, TextPolicyKind, TextRedactionPolicy, ToRedactedOutput,
-    Token,
+    SensitiveText, SensitiveValue, SensitiveWithPolicy, TextPolicyKind, TextRedactionPolicy,
+    ToRedactedOutput, Token,
 };
 
 fn log_redacted<T: ToRedactedOutput>(value: &T) -> RedactedOutput {
@@ -26,6 +26,8 @@ mod container_traversal;
 mod custom_policy;
 #[path = "integration_basic/external_types.rs"]
 mod external_types;
+#[path = "integration_basic/generic_types.rs"]
+mod generic_types;
 #[path = "integration_basic/mixed_fields.rs"]
 mod mixed_fields;
 #[path = "integration_basic/not_sensitive_attribute.rs"]
@@ -20,6 +20,35 @@ fn traverses_btreemap_values() {
     assert_eq!(redacted.get("key").unwrap().value, "[REDACTED]");
 }
 
+#[test]
+fn applies_field_policies_to_btreemap_values() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Directory {
+        #[sensitive(redactable::Email)]
+        contacts: BTreeMap<String, String>,
+    }
+
+    let directory = Directory {
+        contacts: BTreeMap::from([
+            ("alice".to_string(), "alice@example.com".to_string()),
+            ("bob".to_string(), "bob@company.io".to_string()),
+            ("carol".to_string(), "carol@example.org".to_string()),
+        ]),
+    };
+
+    let redacted = directory.redact();
+
+    assert_eq!(
+        redacted.contacts,
+        BTreeMap::from([
+            ("alice".to_string(), "al***@example.com".to_string()),
+            ("bob".to_string(), "bo*@company.io".to_string()),
+            ("carol".to_string(), "ca***@example.org".to_string()),
+        ])
+    );
+}
+
 #[test]
 fn traverses_box_contents() {
     #[derive(Clone, Sensitive)]
@@ -37,6 +66,44 @@ fn traverses_box_contents() {
     assert_eq!(redacted.value, "[REDACTED]");
 }
 
+#[test]
+fn traverses_rc_fields_without_touching_other_owners() {
+    use std::rc::Rc;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Inner {
+        #[sensitive(Secret)]
+        secret: String,
+        label: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Node {
+        // serde's `Rc` support is behind its `rc` feature, which this crate
+        // does not enable.
+        #[cfg_attr(feature = "slog", serde(skip))]
+        inner: Rc<Inner>,
+    }
+
+    let shared = Rc::new(Inner {
+        secret: "hunter2".into(),
+        label: "primary".into(),
+    });
+    let node = Node {
+        inner: Rc::clone(&shared),
+    };
+
+    let redacted = node.redact();
+
+    assert_eq!(redacted.inner.secret, "[REDACTED]");
+    assert_eq!(redacted.inner.label, "primary");
+    // The inner value is cloned out, so other owners keep the raw value.
+    assert_eq!(shared.secret, "hunter2");
+    assert!(!Rc::ptr_eq(&redacted.inner, &shared));
+}
+
 #[test]
 fn traverses_nested_boxes() {
     #[derive(Clone, Sensitive)]
@@ -117,3 +184,272 @@ fn traverses_option_vec_nesting() {
     assert_eq!(items[0].value, "[REDACTED]");
     assert_eq!(items[1].value, "[REDACTED]");
 }
+
+#[test]
+fn applies_field_policies_to_fixed_size_arrays() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Account {
+        #[sensitive(Secret)]
+        recovery_codes: [String; 3],
+        labels: [String; 2],
+    }
+
+    let account = Account {
+        recovery_codes: ["1111-2222".into(), "3333-4444".into(), "5555-6666".into()],
+        labels: ["primary".into(), "backup".into()],
+    };
+
+    let redacted = account.redact();
+
+    assert_eq!(redacted.recovery_codes, ["[REDACTED]"; 3]);
+    assert_eq!(redacted.labels, ["primary", "backup"]);
+}
+
+#[test]
+fn traverses_deque_list_and_heap_fields() {
+    use std::collections::{BinaryHeap, LinkedList, VecDeque};
+
+    #[derive(Clone, Sensitive, PartialEq, Eq, PartialOrd, Ord)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Job {
+        priority: u8,
+        #[sensitive(Secret)]
+        payload: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Queues {
+        pending: VecDeque<Job>,
+        history: LinkedList<Job>,
+        scheduled: BinaryHeap<Job>,
+    }
+
+    let job = |priority: u8, payload: &str| Job {
+        priority,
+        payload: payload.into(),
+    };
+    let queues = Queues {
+        pending: VecDeque::from([job(1, "a"), job(2, "b")]),
+        history: LinkedList::from([job(3, "c")]),
+        scheduled: BinaryHeap::from([job(5, "e"), job(9, "i"), job(7, "g")]),
+    };
+
+    let redacted = queues.redact();
+
+    let pending: Vec<_> = redacted.pending.iter().map(|job| job.priority).collect();
+    assert_eq!(pending, [1, 2]);
+    assert!(
+        redacted
+            .pending
+            .iter()
+            .all(|job| job.payload == "[REDACTED]")
+    );
+    assert_eq!(redacted.history.front().unwrap().payload, "[REDACTED]");
+    let scheduled: Vec<_> = redacted
+        .scheduled
+        .into_sorted_vec()
+        .into_iter()
+        .map(|job| (job.priority, job.payload))
+        .collect();
+    assert_eq!(
+        scheduled,
+        [
+            (5, "[REDACTED]".to_string()),
+            (7, "[REDACTED]".to_string()),
+            (9, "[REDACTED]".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn re_heapifies_binary_heaps_ordered_by_sensitive_fields() {
+    use std::collections::BinaryHeap;
+
+    #[derive(Clone, Sensitive, PartialEq, Eq, PartialOrd, Ord)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Bid {
+        #[sensitive(Secret)]
+        amount: u64,
+        bidder: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Auction {
+        bids: BinaryHeap<Bid>,
+    }
+
+    let bid = |amount: u64, bidder: &str| Bid {
+        amount,
+        bidder: bidder.into(),
+    };
+    let auction = Auction {
+        bids: BinaryHeap::from([bid(900, "ana"), bid(100, "zoe"), bid(500, "max")]),
+    };
+    assert_eq!(auction.bids.peek().unwrap().bidder, "ana");
+
+    let redacted = auction.redact();
+
+    // Every amount redacts to 0, so the bidder now decides the order.
+    assert_eq!(redacted.bids.peek().unwrap().bidder, "zoe");
+    let max = redacted.bids.iter().max().cloned();
+    assert!(redacted.bids.peek().cloned() == max);
+    let drained: Vec<_> = redacted
+        .bids
+        .into_sorted_vec()
+        .into_iter()
+        .map(|bid| (bid.amount, bid.bidder))
+        .collect();
+    assert_eq!(
+        drained,
+        [
+            (0, "ana".to_string()),
+            (0, "max".to_string()),
+            (0, "zoe".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn traverses_lock_fields_and_recovers_poisoned_locks() {
+    use std::{
+        panic::{AssertUnwindSafe, catch_unwind},
+        sync::{Mutex, RwLock},
+    };
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Secrets {
+        #[sensitive(Secret)]
+        api_key: String,
+        region: String,
+    }
+
+    #[derive(Sensitive)]
+    struct SharedConfig {
+        secrets: Mutex<Secrets>,
+        cache: RwLock<Secrets>,
+    }
+
+    let secrets = |api_key: &str| Secrets {
+        api_key: api_key.into(),
+        region: "eu-west-1".into(),
+    };
+    let config = SharedConfig {
+        secrets: Mutex::new(secrets("sk_live_mutex")),
+        cache: RwLock::new(secrets("sk_live_rwlock")),
+    };
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        let _guard = config.secrets.lock().unwrap();
+        panic!("poison the mutex");
+    }));
+    assert!(config.secrets.is_poisoned());
+
+    let redacted = config.redact();
+
+    let secrets = redacted.secrets.into_inner().unwrap();
+    assert_eq!(secrets.api_key, "[REDACTED]");
+    assert_eq!(secrets.region, "eu-west-1");
+    let cache = redacted.cache.into_inner().unwrap();
+    assert_eq!(cache.api_key, "[REDACTED]");
+    assert_eq!(cache.region, "eu-west-1");
+}
+
+#[test]
+fn traverses_tuple_fields_element_wise() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Settings {
+        credentials: (String, SensitiveValue<String, Secret>),
+        #[allow(clippy::type_complexity)]
+        wide: (
+            u8,
+            u16,
+            u32,
+            u64,
+            i8,
+            i16,
+            i32,
+            i64,
+            bool,
+            char,
+            String,
+            SensitiveValue<String, Secret>,
+        ),
+    }
+
+    let settings = Settings {
+        credentials: ("alice".into(), SensitiveValue::from("hunter2".to_string())),
+        wide: (
+            1,
+            2,
+            3,
+            4,
+            -1,
+            -2,
+            -3,
+            -4,
+            true,
+            'x',
+            "visible".into(),
+            SensitiveValue::from("hidden".to_string()),
+        ),
+    };
+
+    let redacted = settings.redact();
+
+    assert_eq!(redacted.credentials.0, "alice");
+    assert_eq!(redacted.credentials.1.expose(), "[REDACTED]");
+    assert_eq!(redacted.wide.0, 1);
+    assert_eq!(redacted.wide.10, "visible");
+    assert_eq!(redacted.wide.11.expose(), "[REDACTED]");
+}
+
+#[test]
+fn redacted_hash_eq_struct_is_a_consistent_map_key() {
+    use std::hash::{BuildHasher, RandomState};
+
+    #[derive(Clone, PartialEq, Eq, Hash, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct SessionKey {
+        tenant: String,
+        #[sensitive(Secret)]
+        user: String,
+        #[sensitive(Secret)]
+        shard: u32,
+    }
+
+    let first = SessionKey {
+        tenant: "acme".into(),
+        user: "alice".into(),
+        shard: 7,
+    }
+    .redact();
+    let second = SessionKey {
+        tenant: "acme".into(),
+        user: "bob".into(),
+        shard: 9,
+    }
+    .redact();
+
+    // Values that redact to the same form are equal and hash identically.
+    assert_eq!(first, second);
+    let hasher = RandomState::new();
+    assert_eq!(hasher.hash_one(&first), hasher.hash_one(&second));
+
+    let mut sessions = HashMap::new();
+    sessions.insert(first.clone(), "first");
+    sessions.insert(second, "second");
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions.get(&first), Some(&"second"));
+    assert_eq!(
+        sessions.get(&SessionKey {
+            tenant: "acme".into(),
+            user: "[REDACTED]".into(),
+            shard: 0,
+        }),
+        Some(&"second")
+    );
+}
@@ -152,6 +152,13 @@ fn chooses_trait_based_on_wrapper_usage() {
         user_id: SensitiveValue<UserId, Token>,
     }
 
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct AccountAnnotated {
+        #[sensitive(Token)]
+        user_id: UserId,
+    }
+
     let user_id = UserId {
         prefix: "usr".into(),
         value: "12345678".into(),
@@ -165,11 +172,17 @@ fn chooses_trait_based_on_wrapper_usage() {
     assert_eq!(redacted_traversed.user_id.prefix, "usr");
     assert_eq!(redacted_traversed.user_id.value, "[REDACTED]");
 
-    // SensitiveValue<T, Policy> wrapper uses SensitiveWithPolicy (redacts as unit)
+    // SensitiveValue<T, Policy> wrapper uses SensitiveWithPolicy (redacts as unit).
     let account_as_leaf = AccountAsLeaf {
         user_id: SensitiveValue::from(user_id.clone()),
     };
     let redacted_as_leaf = account_as_leaf.redact();
     assert_eq!(redacted_as_leaf.user_id.expose().prefix, "redacted");
     assert_eq!(redacted_as_leaf.user_id.expose().value, "****5678");
+
+    // A bare `#[sensitive(Token)]` selects the same leaf path.
+    let account_annotated = AccountAnnotated { user_id };
+    let redacted_annotated = account_annotated.redact();
+    assert_eq!(redacted_annotated.user_id.prefix, "redacted");
+    assert_eq!(redacted_annotated.user_id.value, "****5678");
 }
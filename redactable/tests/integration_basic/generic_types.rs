@@ -0,0 +1,268 @@
+use super::*;
+
+use redactable::{Pii, PolicyApplicable, RedactableMapper, policy::RecursivePolicyKind};
+
+/// Custom policy leaf that forwards redaction to its inner string.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "slog", derive(serde::Serialize))]
+struct CustomLeaf(String);
+
+impl PolicyApplicable for CustomLeaf {
+    fn apply_policy<P, M>(self, mapper: &M) -> Self
+    where
+        P: RedactionPolicy,
+        P::Kind: RecursivePolicyKind,
+        M: RedactableMapper,
+    {
+        Self(self.0.apply_policy::<P, M>(mapper))
+    }
+}
+
+mod default_type_parameters {
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Holder<T = String> {
+        #[sensitive(Secret)]
+        value: T,
+        label: String,
+    }
+
+    #[test]
+    fn redacts_with_the_default_type_argument() {
+        let holder: Holder = Holder {
+            value: "secret".into(),
+            label: "primary".into(),
+        };
+
+        let redacted = holder.redact();
+
+        assert_eq!(redacted.value, "[REDACTED]");
+        assert_eq!(redacted.label, "primary");
+    }
+
+    #[test]
+    fn redacts_with_an_explicit_custom_leaf() {
+        let holder = Holder::<CustomLeaf> {
+            value: CustomLeaf("secret".into()),
+            label: "custom".into(),
+        };
+
+        let redacted = holder.redact();
+
+        assert_eq!(redacted.value, CustomLeaf("[REDACTED]".into()));
+        assert_eq!(redacted.label, "custom");
+    }
+
+    #[test]
+    fn redacts_enum_with_default_type_argument() {
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        enum Credential<T = String> {
+            Token(#[sensitive(Token)] T),
+        }
+
+        let Credential::Token(token) = Credential::<String>::Token("abcdef1234".into()).redact();
+        assert_eq!(token, "******1234");
+
+        let Credential::Token(token) = Credential::Token(CustomLeaf("abcdef1234".into())).redact();
+        assert_eq!(token, CustomLeaf("******1234".into()));
+    }
+}
+
+mod associated_type_fields {
+    use super::*;
+
+    trait Credentials {
+        type Secret;
+        type Profile;
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Profile {
+        #[sensitive(Pii)]
+        name: String,
+    }
+
+    struct Service;
+
+    impl Credentials for Service {
+        type Secret = String;
+        type Profile = Profile;
+    }
+
+    // Std's `Clone` derive bounds only `T`, so the owner derives `Sensitive` alone.
+    #[derive(Sensitive)]
+    struct Account<T: Credentials> {
+        #[sensitive(Secret)]
+        secret: <T as Credentials>::Secret,
+        profile: <T as Credentials>::Profile,
+    }
+
+    #[test]
+    fn bounds_associated_type_fields_by_their_complete_type() {
+        let account = Account::<Service> {
+            secret: "hunter2".into(),
+            profile: Profile {
+                name: "Alice".into(),
+            },
+        };
+
+        let redacted = account.redact();
+
+        assert_eq!(redacted.secret, "[REDACTED]");
+        assert_eq!(redacted.profile.name, "***ce");
+    }
+}
+
+mod type_alias_fields {
+    use super::*;
+
+    type UserId = u64;
+    type Pin = u32;
+    type Names = Vec<String>;
+    type Labels<T> = Vec<T>;
+    type Id = String;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Account {
+        id: UserId,
+        #[sensitive(Secret)]
+        pin: Pin,
+        #[sensitive(Pii)]
+        names: Names,
+        tags: Names,
+    }
+
+    #[test]
+    fn scalar_and_container_aliases_redact_like_their_targets() {
+        let account = Account {
+            id: 42,
+            pin: 1234,
+            names: vec!["Alice".into(), "Bob".into()],
+            tags: vec!["vip".into()],
+        };
+
+        let redacted = account.redact();
+
+        assert_eq!(redacted.id, 42);
+        assert_eq!(redacted.pin, 0);
+        assert_eq!(redacted.names, vec!["***ce", "*ob"]);
+        assert_eq!(redacted.tags, vec!["vip"]);
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Tagged<T> {
+        id: UserId,
+        #[sensitive(Secret)]
+        labels: Labels<T>,
+        owner: Id,
+    }
+
+    #[test]
+    fn aliases_in_generic_types_only_bound_their_targets() {
+        let tagged = Tagged::<String> {
+            id: 7,
+            labels: vec!["internal".into()],
+            owner: "alice".into(),
+        };
+
+        let redacted = tagged.redact();
+
+        assert_eq!(redacted.id, 7);
+        assert_eq!(redacted.labels, vec!["[REDACTED]"]);
+        assert_eq!(redacted.owner, "alice");
+    }
+
+    // The type parameter shadows the `Id` alias, so the field is generic.
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Shadowed<Id> {
+        #[sensitive(Secret)]
+        id: Id,
+    }
+
+    #[test]
+    fn type_parameters_shadowing_an_alias_bind_as_generics() {
+        assert_eq!(
+            Shadowed::<String> { id: "x".into() }.redact().id,
+            "[REDACTED]"
+        );
+        assert_eq!(
+            Shadowed {
+                id: CustomLeaf("x".into())
+            }
+            .redact()
+            .id,
+            CustomLeaf("[REDACTED]".into())
+        );
+    }
+}
+
+mod vec_only_generic {
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Inner {
+        #[sensitive(Secret)]
+        token: String,
+        name: String,
+    }
+
+    // `T` only appears inside `Vec<T>`; the derive bounds the complete field
+    // type, so `Vec<T>: RedactableWithMapper` carries the requirement on `T`.
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Batch<T> {
+        items: Vec<T>,
+        size: usize,
+    }
+
+    #[test]
+    fn walks_items_of_a_generic_only_used_in_a_vec() {
+        let batch = Batch {
+            items: vec![
+                Inner {
+                    token: "tok-1".into(),
+                    name: "first".into(),
+                },
+                Inner {
+                    token: "tok-2".into(),
+                    name: "second".into(),
+                },
+            ],
+            size: 2,
+        };
+
+        let redacted = batch.redact();
+
+        assert_eq!(redacted.size, 2);
+        let tokens: Vec<_> = redacted
+            .items
+            .iter()
+            .map(|item| item.token.as_str())
+            .collect();
+        let names: Vec<_> = redacted
+            .items
+            .iter()
+            .map(|item| item.name.as_str())
+            .collect();
+        assert_eq!(tokens, ["[REDACTED]", "[REDACTED]"]);
+        assert_eq!(names, ["first", "second"]);
+    }
+
+    #[test]
+    fn passes_through_plain_items() {
+        let batch = Batch::<String> {
+            items: vec!["a".into(), "b".into()],
+            size: 2,
+        };
+
+        assert_eq!(batch.redact().items, ["a", "b"]);
+    }
+}
@@ -114,3 +114,134 @@ fn works_on_enum_variant_fields() {
         _ => panic!("wrong variant"),
     }
 }
+
+#[test]
+fn passes_through_borrowed_fields_in_lifetime_generic_structs() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Log<'a> {
+        #[sensitive(Secret)]
+        token: String,
+        #[not_sensitive]
+        msg: &'a str,
+    }
+
+    let message = String::from("user signed in");
+    let log = Log {
+        token: "tok_live_abc".into(),
+        msg: &message,
+    };
+
+    let redacted = log.redact();
+
+    assert_eq!(redacted.token, "[REDACTED]");
+    // The borrow is returned as-is rather than copied.
+    assert!(std::ptr::eq(redacted.msg, message.as_str()));
+}
+
+#[test]
+fn redact_everything_keeps_only_not_sensitive_fields() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Address {
+        #[not_sensitive]
+        country: String,
+        street: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Customer {
+        #[not_sensitive]
+        id: u64,
+        #[not_sensitive]
+        plan: String,
+        name: String,
+        age: u8,
+        active: bool,
+        nicknames: Vec<String>,
+        address: Option<Address>,
+        scores: HashMap<String, u32>,
+        #[sensitive(Token)]
+        api_key: String,
+    }
+
+    let customer = Customer {
+        id: 42,
+        plan: "pro".into(),
+        name: "Alice".into(),
+        age: 31,
+        active: true,
+        nicknames: vec!["ali".into()],
+        address: Some(Address {
+            country: "NZ".into(),
+            street: "1 Queen St".into(),
+        }),
+        scores: HashMap::from([("quiz".to_string(), 9)]),
+        api_key: "sk_live_abcd1234".into(),
+    };
+
+    let everything = customer.clone().redact_everything();
+
+    assert_eq!(everything.id, 42);
+    assert_eq!(everything.plan, "pro");
+    assert_eq!(everything.name, "[REDACTED]");
+    assert_eq!(everything.age, 0);
+    assert!(!everything.active);
+    assert_eq!(everything.nicknames, vec!["[REDACTED]"]);
+    let address = everything.address.expect("address is kept");
+    assert_eq!(address.country, "NZ");
+    assert_eq!(address.street, "[REDACTED]");
+    assert_eq!(everything.scores, HashMap::from([("quiz".to_string(), 0)]));
+    assert_eq!(everything.api_key, "************1234");
+
+    let redacted =
+        redactable::RedactableWithMapper::redact_with(customer, &redactable::RedactEverything);
+    assert_eq!(redacted.plan, "pro");
+    assert_eq!(redacted.name, "[REDACTED]");
+
+    // The default mapper still passes unannotated leaves through.
+    let default = Customer {
+        id: 1,
+        plan: "free".into(),
+        name: "Bob".into(),
+        age: 40,
+        active: true,
+        nicknames: Vec::new(),
+        address: None,
+        scores: HashMap::new(),
+        api_key: "sk_test_wxyz".into(),
+    }
+    .redact();
+    assert_eq!(default.name, "Bob");
+    assert_eq!(default.age, 40);
+}
+
+#[test]
+fn moves_function_pointer_and_closure_fields_without_bounds() {
+    #[derive(Sensitive)]
+    struct Handler {
+        #[not_sensitive]
+        transform: fn(u32) -> u32,
+        #[not_sensitive]
+        callback: Box<dyn Fn(u32) -> String>,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    let handler = Handler {
+        transform: |value| value + 1,
+        callback: Box::new(|value| format!("called with {value}")),
+        token: "hunter2".into(),
+    }
+    .redact();
+
+    // The closure has no `Debug`, so generated `Debug` prints a placeholder for it.
+    let debug = format!("{handler:?}");
+    assert!(debug.contains("callback: <opaque>"), "{debug}");
+    assert!(debug.contains("transform: 0x"), "{debug}");
+
+    assert_eq!((handler.transform)(41), 42);
+    assert_eq!((handler.callback)(7), "called with 7");
+    assert_eq!(handler.token, "[REDACTED]");
+}
@@ -57,6 +57,51 @@ fn applies_policy_to_deeply_nested_containers() {
     assert_eq!(values[1], None);
 }
 
+#[test]
+fn applies_policy_to_every_cell_of_nested_vecs() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Matrix {
+        #[sensitive(Secret)]
+        rows: Vec<Vec<String>>,
+        #[sensitive(Token)]
+        keys: Vec<Vec<String>>,
+    }
+
+    let matrix = Matrix {
+        rows: vec![
+            vec!["a1".into(), "a2".into(), "a3".into()],
+            vec![],
+            vec!["c1".into()],
+        ],
+        keys: vec![
+            vec!["sk_live_abcd1234".into()],
+            vec!["sk_test_wxyz9876".into()],
+        ],
+    };
+    let redacted = matrix.redact();
+
+    assert_eq!(
+        redacted.rows,
+        vec![
+            vec![
+                "[REDACTED]".to_string(),
+                "[REDACTED]".into(),
+                "[REDACTED]".into()
+            ],
+            vec![],
+            vec!["[REDACTED]".into()],
+        ]
+    );
+    assert_eq!(
+        redacted.keys,
+        vec![
+            vec!["************1234".to_string()],
+            vec!["************9876".to_string()]
+        ]
+    );
+}
+
 #[test]
 fn applies_policy_to_hashmap_vec() {
     #[derive(Clone, Sensitive)]
@@ -77,3 +122,140 @@ fn applies_policy_to_hashmap_vec() {
         Some(&vec!["[REDACTED]".to_string(), "[REDACTED]".to_string()])
     );
 }
+
+#[test]
+fn applies_policy_to_option_cow() {
+    use std::borrow::Cow;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Profile {
+        #[sensitive(Token)]
+        name: Option<Cow<'static, str>>,
+        #[sensitive(Token)]
+        alias: Option<Cow<'static, str>>,
+        #[sensitive(Token)]
+        handle: Option<Cow<'static, str>>,
+    }
+
+    let profile = Profile {
+        name: Some(Cow::Borrowed("sk_live_abcd1234")),
+        alias: Some(Cow::Owned("alias-5678".to_string())),
+        handle: None,
+    };
+    let redacted = profile.redact();
+
+    assert_eq!(redacted.name.as_deref(), Some("************1234"));
+    assert_eq!(redacted.alias.as_deref(), Some("******5678"));
+    assert_eq!(redacted.handle, None);
+}
+
+#[test]
+fn applies_policy_to_option_boxed_str() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Credentials {
+        #[sensitive(Token)]
+        api_key: Option<Box<str>>,
+        #[sensitive(Token)]
+        backup_key: Option<Box<str>>,
+        #[sensitive(Secret)]
+        password: Box<str>,
+        label: Box<str>,
+    }
+
+    let credentials = Credentials {
+        api_key: Some("sk_live_abcd1234".into()),
+        backup_key: None,
+        password: "hunter2".into(),
+        label: "primary".into(),
+    };
+    let redacted = credentials.redact();
+
+    assert_eq!(redacted.api_key.as_deref(), Some("************1234"));
+    assert_eq!(redacted.backup_key, None);
+    assert_eq!(&*redacted.password, "[REDACTED]");
+    assert_eq!(&*redacted.label, "primary");
+}
+
+#[test]
+fn formats_option_boxed_str_with_policy() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("key={api_key:?} label={label}")]
+    struct Credentials {
+        #[sensitive(Token)]
+        api_key: Option<Box<str>>,
+        #[not_sensitive]
+        label: Box<str>,
+    }
+
+    let credentials = Credentials {
+        api_key: Some("sk_live_abcd1234".into()),
+        label: "primary".into(),
+    };
+
+    assert_eq!(
+        credentials.redacted_display().to_string(),
+        "key=Some(\"************1234\") label=primary"
+    );
+}
+
+/// A runtime-typed secret stored behind `Box<dyn SensitiveText>`.
+#[derive(Debug)]
+struct ApiKey(String);
+
+impl SensitiveText for ApiKey {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn from_redacted(&self, redacted: String) -> Box<dyn SensitiveText> {
+        Box::new(ApiKey(redacted))
+    }
+}
+
+#[test]
+fn applies_policy_to_boxed_dyn_sensitive_text() {
+    #[derive(Sensitive)]
+    struct Connection {
+        #[sensitive(Secret)]
+        credential: Box<dyn SensitiveText>,
+        #[sensitive(Token)]
+        fallback: Option<Box<dyn SensitiveText>>,
+    }
+
+    let connection = Connection {
+        credential: Box::new(ApiKey("sk_live_abcd1234".into())),
+        fallback: Some(Box::new(ApiKey("sk_test_wxyz9876".into()))),
+    };
+    let redacted = connection.redact();
+
+    assert_eq!(redacted.credential.as_str(), "[REDACTED]");
+    assert_eq!(
+        redacted.fallback.as_deref().map(SensitiveText::as_str),
+        Some("************9876")
+    );
+}
+
+#[test]
+fn formats_boxed_dyn_sensitive_text_with_policy() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("connecting with {credential}")]
+    struct ConnectError {
+        #[sensitive(Secret)]
+        credential: Box<dyn SensitiveText>,
+    }
+
+    let error = ConnectError {
+        credential: Box::new(ApiKey("sk_live_abcd1234".into())),
+    };
+
+    assert_eq!(
+        error.redacted_display().to_string(),
+        "connecting with [REDACTED]"
+    );
+}
@@ -29,6 +29,39 @@ fn redacts_numeric_types_to_zero() {
     assert_eq!(redacted.public_number, 100);
 }
 
+#[test]
+fn full_redact_matches_secret_for_scalars_and_strings() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct FullyHidden {
+        #[sensitive(redactable::FullRedact)]
+        number: u64,
+        #[sensitive(redactable::FullRedact)]
+        ratio: f64,
+        #[sensitive(redactable::FullRedact)]
+        flag: bool,
+        #[sensitive(redactable::FullRedact)]
+        initial: char,
+        #[sensitive(redactable::FullRedact)]
+        label: String,
+    }
+
+    let redacted = FullyHidden {
+        number: 42,
+        ratio: 0.5,
+        flag: true,
+        initial: 'a',
+        label: "internal".into(),
+    }
+    .redact();
+
+    assert_eq!(redacted.number, 0);
+    assert_eq!(redacted.ratio, 0.0);
+    assert!(!redacted.flag);
+    assert_eq!(redacted.initial, '*');
+    assert_eq!(redacted.label, "[REDACTED]");
+}
+
 #[test]
 fn redacts_all_scalar_types() {
     #[derive(Clone, Sensitive)]
@@ -90,3 +123,179 @@ fn redacts_all_scalar_types() {
     assert!(!redacted.bool_val);
     assert_eq!(redacted.char_val, '*');
 }
+
+#[test]
+fn zeroes_secret_byte_arrays() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct SigningKey {
+        #[sensitive(Secret)]
+        key: [u8; 16],
+        key_id: u32,
+    }
+
+    let key = SigningKey {
+        key: [0xAB; 16],
+        key_id: 7,
+    };
+
+    let redacted = key.redact();
+
+    assert_eq!(redacted.key, [0; 16]);
+    assert_eq!(redacted.key_id, 7);
+}
+
+#[test]
+fn displays_secret_byte_arrays_as_placeholder() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("key {key_id}: {key} ({key:?})")]
+    struct SigningKey {
+        #[sensitive(Secret)]
+        key: [u8; 16],
+        key_id: u32,
+    }
+
+    let key = SigningKey {
+        key: [0xAB; 16],
+        key_id: 7,
+    };
+
+    let display = key.redacted_display().to_string();
+
+    assert_eq!(display, "key 7: [REDACTED] (\"[REDACTED]\")");
+    assert!(!display.contains("171"));
+}
+
+#[test]
+fn clears_secret_byte_buffers() {
+    use std::borrow::Cow;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Envelope<'a> {
+        #[sensitive(Secret)]
+        ciphertext: Vec<u8>,
+        #[sensitive(Secret)]
+        nonce: Cow<'a, [u8]>,
+        header: Vec<u8>,
+    }
+
+    let nonce = [0xCD; 12];
+    let envelope = Envelope {
+        ciphertext: vec![0xAB; 32],
+        nonce: Cow::Borrowed(&nonce),
+        header: vec![1, 2, 3],
+    };
+
+    let redacted = envelope.redact();
+
+    assert!(redacted.ciphertext.is_empty());
+    assert!(redacted.nonce.is_empty());
+    assert_eq!(redacted.header, vec![1, 2, 3]);
+}
+
+#[test]
+fn displays_secret_byte_buffers_as_placeholder() {
+    use std::borrow::Cow;
+
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("{ciphertext} {nonce:?}")]
+    struct Envelope<'a> {
+        #[sensitive(Secret)]
+        ciphertext: Vec<u8>,
+        #[sensitive(Secret)]
+        nonce: Cow<'a, [u8]>,
+    }
+
+    let envelope = Envelope {
+        ciphertext: vec![0xAB; 32],
+        nonce: Cow::Owned(vec![0xCD; 12]),
+    };
+
+    let display = envelope.redacted_display().to_string();
+
+    assert_eq!(display, "[REDACTED] \"[REDACTED]\"");
+    assert!(!display.contains("171"));
+}
+
+#[test]
+fn passes_through_primitive_fields_unchanged() {
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    enum Reading {
+        Sample {
+            sequence: u128,
+            offset: isize,
+            celsius: f64,
+            valid: bool,
+            unit: char,
+            #[sensitive(Secret)]
+            device: String,
+        },
+        Gap(u8, i16),
+    }
+
+    let Reading::Sample {
+        sequence,
+        offset,
+        celsius,
+        valid,
+        unit,
+        device,
+    } = Reading::Sample {
+        sequence: u128::MAX,
+        offset: -3,
+        celsius: 21.5,
+        valid: true,
+        unit: 'C',
+        device: "sensor-7".into(),
+    }
+    .redact()
+    else {
+        panic!("expected a sample");
+    };
+
+    assert_eq!(sequence, u128::MAX);
+    assert_eq!(offset, -3);
+    assert!((celsius - 21.5).abs() < f64::EPSILON);
+    assert!(valid);
+    assert_eq!(unit, 'C');
+    assert_eq!(device, "[REDACTED]");
+
+    let Reading::Gap(count, delta) = Reading::Gap(4, -9).redact() else {
+        panic!("expected a gap");
+    };
+    assert_eq!((count, delta), (4, -9));
+}
+
+#[test]
+fn walks_local_types_that_shadow_primitive_names() {
+    mod shadowed {
+        use redactable::{Secret, Sensitive};
+
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        pub struct u32 {
+            #[sensitive(Secret)]
+            pub pin: String,
+        }
+    }
+
+    use shadowed::u32;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Card {
+        code: u32,
+    }
+
+    let card = Card {
+        code: u32 { pin: "1234".into() },
+    };
+    assert_eq!(card.redact().code.pin, "[REDACTED]");
+}
@@ -61,6 +61,81 @@ mod structs {
         assert_eq!(redacted.username, "john_doe");
     }
 
+    #[test]
+    fn passes_unannotated_ip_addresses_through_unchanged() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Connection {
+            peer: IpAddr,
+            v4: Ipv4Addr,
+            v6: Ipv6Addr,
+            listen: SocketAddr,
+            #[sensitive(Secret)]
+            token: String,
+        }
+
+        let connection = Connection {
+            peer: "192.0.2.7".parse().unwrap(),
+            v4: Ipv4Addr::new(198, 51, 100, 1),
+            v6: Ipv6Addr::LOCALHOST,
+            listen: "203.0.113.9:8443".parse().unwrap(),
+            token: "secret".into(),
+        };
+
+        let redacted = connection.redact();
+
+        assert_eq!(redacted.peer, "192.0.2.7".parse::<IpAddr>().unwrap());
+        assert_eq!(redacted.v4, Ipv4Addr::new(198, 51, 100, 1));
+        assert_eq!(redacted.v6, Ipv6Addr::LOCALHOST);
+        assert_eq!(
+            redacted.listen,
+            "203.0.113.9:8443".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(redacted.token, "[REDACTED]");
+    }
+
+    #[cfg(feature = "ip-address")]
+    #[test]
+    fn masks_the_host_of_annotated_ip_addresses() {
+        use std::net::{IpAddr, SocketAddr};
+
+        use redactable::IpAddress;
+
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Connection {
+            #[sensitive(IpAddress)]
+            peer: IpAddr,
+            #[sensitive(IpAddress)]
+            peer_v6: IpAddr,
+            #[sensitive(IpAddress)]
+            listen: SocketAddr,
+            gateway: IpAddr,
+        }
+
+        let connection = Connection {
+            peer: "192.0.2.7".parse().unwrap(),
+            peer_v6: "2001:db8:85a3::8a2e:370:7334".parse().unwrap(),
+            listen: "203.0.113.9:8443".parse().unwrap(),
+            gateway: "192.0.2.1".parse().unwrap(),
+        };
+
+        let redacted = connection.redact();
+
+        assert_eq!(redacted.peer, "192.0.0.0".parse::<IpAddr>().unwrap());
+        assert_eq!(
+            redacted.peer_v6,
+            "2001:db8:85a3::".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(
+            redacted.listen,
+            "203.0.0.0:8443".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(redacted.gateway, "192.0.2.1".parse::<IpAddr>().unwrap());
+    }
+
     #[test]
     fn walks_nested_structs_automatically() {
         #[derive(Clone, Sensitive)]
@@ -104,6 +179,77 @@ mod structs {
         let redacted = marker.redact();
         let _ = redacted;
     }
+
+    #[test]
+    fn honors_cfg_attr_expanded_sensitive_attributes() {
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Credentials {
+            #[cfg_attr(all(), sensitive(Secret))]
+            enabled: String,
+            #[cfg_attr(any(), sensitive(Secret))]
+            disabled: String,
+            #[cfg_attr(feature = "json", sensitive(Secret))]
+            feature_gated: String,
+        }
+
+        let redacted = Credentials {
+            enabled: "hunter2".into(),
+            disabled: "visible".into(),
+            feature_gated: "gated".into(),
+        }
+        .redact();
+
+        assert_eq!(redacted.enabled, "[REDACTED]");
+        assert_eq!(redacted.disabled, "visible");
+        let expected = if cfg!(feature = "json") {
+            "[REDACTED]"
+        } else {
+            "gated"
+        };
+        assert_eq!(redacted.feature_gated, expected);
+        assert_eq!(
+            Credentials::REDACTED_FIELDS.contains(&"feature_gated"),
+            cfg!(feature = "json")
+        );
+    }
+
+    #[test]
+    fn preserves_repr_attributes() {
+        use std::mem::{align_of, offset_of, size_of};
+
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        #[repr(C)]
+        struct Header {
+            flag: u8,
+            #[sensitive(Secret)]
+            key: u64,
+            #[sensitive(Secret)]
+            label: String,
+        }
+
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        #[repr(transparent)]
+        struct ApiKey(#[sensitive(Token)] String);
+
+        assert_eq!(offset_of!(Header, flag), 0);
+        assert_eq!(offset_of!(Header, key), align_of::<u64>());
+        assert_eq!(size_of::<ApiKey>(), size_of::<String>());
+
+        let header = Header {
+            flag: 1,
+            key: 42,
+            label: "internal".into(),
+        }
+        .redact();
+        assert_eq!((header.flag, header.key), (1, 0));
+        assert_eq!(header.label, "[REDACTED]");
+
+        let key = ApiKey("sk_live_abcdef123456".into()).redact();
+        assert_eq!(key.0, "****************3456");
+    }
 }
 
 mod tuple_structs {
@@ -217,6 +363,264 @@ mod enums {
             _ => panic!("Wrong variant"),
         }
     }
+
+    // Every variant repeats the same sensitive field shape, which the derive
+    // applies through one shared helper instead of once per arm.
+    #[test]
+    fn redacts_shared_field_across_many_variants() {
+        macro_rules! session_event {
+            ($($variant:ident),+ $(,)?) => {
+                #[derive(Clone, Sensitive)]
+                #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+                enum SessionEvent {
+                    $($variant {
+                        #[sensitive(Secret)]
+                        token: String,
+                        id: u32,
+                    },)+
+                    Refreshed(#[sensitive(Secret)] String, #[sensitive(Token)] String),
+                }
+
+                impl SessionEvent {
+                    fn all() -> Vec<Self> {
+                        let mut id = 0;
+                        vec![$({
+                            id += 1;
+                            SessionEvent::$variant { token: format!("tok_{id}"), id }
+                        }),+]
+                    }
+
+                    fn parts(&self) -> (&str, u32) {
+                        match self {
+                            $(SessionEvent::$variant { token, id } => (token, *id),)+
+                            SessionEvent::Refreshed(..) => unreachable!(),
+                        }
+                    }
+                }
+            };
+        }
+
+        session_event!(
+            Created, Renewed, Expired, Revoked, Suspended, Resumed, Elevated, Demoted, Locked,
+            Unlocked, Verified, Rejected, Migrated, Imported, Exported, Rotated, Pinned, Unpinned,
+            Audited, Closed,
+        );
+
+        let events = SessionEvent::all();
+        assert_eq!(events.len(), 20);
+        assert_eq!(SessionEvent::REDACTED_FIELDS.len(), 22);
+        for (index, event) in events.into_iter().enumerate() {
+            let redacted = event.redact();
+            assert_eq!(redacted.parts(), ("[REDACTED]", index as u32 + 1));
+        }
+
+        let refreshed = SessionEvent::Refreshed("old".into(), "sk_live_abcdef".into()).redact();
+        match refreshed {
+            SessionEvent::Refreshed(old, new) => {
+                assert_eq!(old, "[REDACTED]");
+                assert_eq!(new, "**********cdef");
+            }
+            _ => panic!("Wrong variant"),
+        }
+    }
+
+    #[test]
+    fn opaque_variant_collapses_every_variant_to_the_default() {
+        #[derive(Clone, Default, PartialEq, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        #[sensitive(opaque_variant)]
+        enum Diagnosis {
+            Flu {
+                #[sensitive(Secret)]
+                notes: String,
+            },
+            Chronic(String, u8),
+            #[default]
+            Redacted,
+        }
+
+        let values = [
+            Diagnosis::Flu {
+                notes: "mild fever".into(),
+            },
+            Diagnosis::Chronic("asthma".into(), 3),
+            Diagnosis::Redacted,
+        ];
+        for value in values {
+            assert!(value.redact() == Diagnosis::Redacted);
+        }
+    }
+}
+
+mod prod_only_fields {
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Session {
+        #[sensitive(Token, prod_only)]
+        user_id: String,
+        #[sensitive(Secret, prod_only)]
+        attempts: u32,
+        #[sensitive(Secret)]
+        password: String,
+    }
+
+    fn session() -> Session {
+        Session {
+            user_id: "user_5f2a9c81".into(),
+            attempts: 3,
+            password: "hunter2".into(),
+        }
+    }
+
+    #[test]
+    fn redacts_only_without_debug_assertions() {
+        let redacted = session().redact();
+
+        if cfg!(debug_assertions) {
+            assert_eq!(redacted.user_id, "user_5f2a9c81");
+            assert_eq!(redacted.attempts, 3);
+        } else {
+            assert_eq!(redacted.user_id, "*********9c81");
+            assert_eq!(redacted.attempts, 0);
+        }
+        assert_eq!(redacted.password, "[REDACTED]");
+    }
+
+    #[test]
+    fn redact_everything_still_covers_debug_builds() {
+        let redacted =
+            redactable::RedactableWithMapper::redact_with(session(), &redactable::RedactEverything);
+
+        assert_ne!(redacted.user_id, "user_5f2a9c81");
+        assert_eq!(redacted.attempts, 0);
+        assert_eq!(redacted.password, "[REDACTED]");
+    }
+}
+
+mod in_place {
+    use super::*;
+
+    #[derive(Clone, Default, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Event {
+        id: u64,
+        #[sensitive(Secret)]
+        password: String,
+        #[sensitive(Token)]
+        api_key: Option<String>,
+        tags: Vec<String>,
+    }
+
+    fn event() -> Event {
+        Event {
+            id: 42,
+            password: "hunter2".into(),
+            api_key: Some("sk_live_abcdef".into()),
+            tags: vec!["login".into()],
+        }
+    }
+
+    #[test]
+    fn redact_mut_matches_consuming_redaction() {
+        let mut event = event();
+        event.redact_mut();
+
+        let expected = self::event().redact();
+        assert_eq!(event.id, expected.id);
+        assert_eq!(event.password, "[REDACTED]");
+        assert_eq!(event.api_key, expected.api_key);
+        assert_eq!(event.tags, vec!["login"]);
+    }
+
+    #[test]
+    fn redact_mut_reaches_values_behind_references() {
+        let mut batch = vec![event(), event()];
+        for event in &mut batch {
+            event.redact_mut();
+        }
+        assert!(batch.iter().all(|event| event.password == "[REDACTED]"));
+
+        batch.redact_mut();
+        assert_eq!(batch.len(), 2);
+    }
+
+    #[test]
+    fn redacted_clone_leaves_the_original_untouched() {
+        let original = event();
+        let redacted = original.redacted_clone();
+
+        assert_eq!(redacted.password, "[REDACTED]");
+        assert_eq!(redacted.api_key, self::event().redact().api_key);
+        assert_eq!(original.password, "hunter2");
+        assert_eq!(original.api_key.as_deref(), Some("sk_live_abcdef"));
+
+        let batch = vec![event()];
+        let redacted_batch = batch.redacted_clone();
+        assert_eq!(redacted_batch[0].password, "[REDACTED]");
+        assert_eq!(batch[0].password, "hunter2");
+    }
+
+    #[test]
+    fn redact_mut_with_uses_the_given_mapper() {
+        let mut event = event();
+        redactable::RedactableWithMapper::redact_mut_with(
+            &mut event,
+            &redactable::RedactEverything,
+        );
+
+        assert_eq!(event.id, 0);
+        assert_eq!(event.password, "[REDACTED]");
+        assert_eq!(event.tags, vec!["[REDACTED]"]);
+    }
+}
+
+mod wide_structs {
+    use super::*;
+
+    macro_rules! wide_struct {
+        ($($plain:ident),* ; $($secret:ident),*) => {
+            #[derive(Clone, Default, Sensitive)]
+            #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+            struct Wide {
+                $($plain: u64,)*
+                $(#[sensitive(Secret)] $secret: String,)*
+            }
+
+            fn wide() -> Wide {
+                let mut value = Wide::default();
+                $(value.$plain = 7;)*
+                $(value.$secret = "hunter2".into();)*
+                value
+            }
+
+            fn plain_values(value: &Wide) -> Vec<u64> {
+                vec![$(value.$plain),*]
+            }
+
+            fn secret_values(value: &Wide) -> Vec<&str> {
+                vec![$(value.$secret.as_str()),*]
+            }
+        };
+    }
+
+    wide_struct!(
+        p00, p01, p02, p03, p04, p05, p06, p07, p08, p09, p10, p11, p12, p13, p14, p15, p16, p17,
+        p18, p19, p20, p21, p22, p23, p24, p25, p26, p27, p28, p29;
+        s00, s01, s02, s03, s04, s05, s06, s07, s08, s09, s10, s11, s12, s13, s14, s15, s16, s17,
+        s18, s19, s20, s21, s22, s23, s24, s25, s26, s27, s28, s29
+    );
+
+    #[test]
+    fn redacts_every_policy_field_of_a_sixty_field_struct() {
+        let redacted = wide().redact();
+
+        assert_eq!(plain_values(&redacted), vec![7; 30]);
+        assert_eq!(secret_values(&redacted), vec!["[REDACTED]"; 30]);
+        assert_eq!(Wide::REDACTED_FIELDS.len(), 30);
+        assert!(!format!("{:?}", redacted).contains("hunter2"));
+    }
 }
 
 mod nested_fields {
@@ -296,3 +700,408 @@ mod nested_fields {
         assert_eq!(redacted.label, "test");
     }
 }
+
+mod redacted_fields {
+    use super::*;
+
+    #[test]
+    fn lists_policy_fields_of_a_mixed_struct() {
+        #[allow(dead_code)]
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Account {
+            id: u64,
+            #[sensitive(Secret)]
+            password: String,
+            username: String,
+            #[sensitive(Token)]
+            api_key: String,
+            #[not_sensitive]
+            region: String,
+        }
+
+        assert_eq!(Account::REDACTED_FIELDS, &["password", "api_key"]);
+    }
+
+    #[test]
+    fn lists_tuple_indices_and_enum_variant_fields() {
+        #[allow(dead_code)]
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Pair(String, #[sensitive(Secret)] String);
+
+        #[allow(dead_code)]
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        enum Credential {
+            Password {
+                user: String,
+                #[sensitive(Secret)]
+                password: String,
+            },
+            Key(#[sensitive(Token)] String),
+            Anonymous,
+        }
+
+        #[allow(dead_code)]
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Plain {
+            label: String,
+        }
+
+        assert_eq!(Pair::REDACTED_FIELDS, &["1"]);
+        assert_eq!(Credential::REDACTED_FIELDS, &["Password.password", "Key.0"]);
+        assert!(Plain::REDACTED_FIELDS.is_empty());
+    }
+}
+
+mod redacted_types {
+    use redactable::Redacted;
+
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Address {
+        #[sensitive(Secret)]
+        street: String,
+        city: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(gen_redacted_type)]
+    struct Customer {
+        id: u64,
+        #[sensitive(Secret)]
+        password: String,
+        #[sensitive(Token)]
+        api_key: String,
+        address: Address,
+        #[not_sensitive]
+        region: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(gen_redacted_type)]
+    struct Pair<T>(T, #[sensitive(Secret)] String);
+
+    fn audit(customer: &CustomerRedacted) -> String {
+        format!(
+            "{}:{}:{}",
+            customer.id,
+            *customer.password,
+            customer.api_key.as_str()
+        )
+    }
+
+    #[test]
+    fn converts_into_the_generated_redacted_type() {
+        let customer = Customer {
+            id: 7,
+            password: "hunter2".into(),
+            api_key: "sk_live_abcdef".into(),
+            address: Address {
+                street: "1 Main St".into(),
+                city: "Springfield".into(),
+            },
+            region: "eu-west-1".into(),
+        };
+
+        let redacted = CustomerRedacted::from(customer);
+        let password: &Redacted<String> = &redacted.password;
+
+        assert_eq!(password.as_str(), "[REDACTED]");
+        assert_eq!(audit(&redacted), "7:[REDACTED]:**********cdef");
+        assert_eq!(redacted.address.street, "[REDACTED]");
+        assert_eq!(redacted.address.city, "Springfield");
+        assert_eq!(redacted.region, "eu-west-1");
+        assert_eq!(redacted.api_key.clone().into_inner(), "**********cdef");
+        assert!(!format!("{redacted:?}").contains("hunter2"));
+    }
+
+    #[test]
+    fn converts_generic_tuple_structs() {
+        let redacted: PairRedacted<u32> = Pair(3, "hunter2".into()).into();
+
+        assert_eq!(redacted.0, 3);
+        assert_eq!(redacted.1.to_string(), "[REDACTED]");
+        assert_eq!(format!("{redacted:?}"), "PairRedacted(3, \"[REDACTED]\")");
+    }
+
+    #[test]
+    fn debug_prints_not_sensitive_fields_without_debug_as_opaque() {
+        #[derive(Sensitive)]
+        #[sensitive(gen_redacted_type)]
+        struct Handler {
+            #[not_sensitive]
+            callback: Box<dyn Fn(u32) -> u32>,
+            #[sensitive(Secret)]
+            token: String,
+        }
+
+        let redacted = HandlerRedacted::from(Handler {
+            callback: Box::new(|value| value + 1),
+            token: "hunter2".into(),
+        });
+
+        assert_eq!((redacted.callback)(41), 42);
+        assert_eq!(
+            format!("{redacted:?}"),
+            "HandlerRedacted { callback: <opaque>, token: \"[REDACTED]\" }"
+        );
+    }
+}
+
+mod custom_mappers {
+    use std::cell::{Cell, RefCell};
+
+    use redactable::{RedactableMapper, ScalarRedaction, redact_with};
+
+    use super::*;
+
+    /// Replaces every policy leaf with `[MAPPED]`, recording the policy of
+    /// each leaf and counting the unannotated text leaves it passes through.
+    #[derive(Default)]
+    struct RecordingMapper {
+        policies: RefCell<Vec<&'static str>>,
+        unannotated: Cell<usize>,
+    }
+
+    impl RedactableMapper for RecordingMapper {
+        fn map_sensitive<V, P>(&self, value: V) -> V
+        where
+            V: SensitiveWithPolicy<P>,
+            P: RedactionPolicy,
+        {
+            let name = std::any::type_name::<P>().rsplit("::").next().unwrap_or("");
+            self.policies.borrow_mut().push(name);
+            value.redact_with_policy(&TextRedactionPolicy::full_with("[MAPPED]"))
+        }
+
+        fn map_scalar<S>(&self, value: S) -> S
+        where
+            S: Default + ScalarRedaction,
+        {
+            ScalarRedaction::redact(value)
+        }
+
+        fn map_unannotated_text<V>(&self, value: V) -> V
+        where
+            V: SensitiveWithPolicy<Secret>,
+        {
+            self.unannotated.set(self.unannotated.get() + 1);
+            value
+        }
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Credentials {
+        #[sensitive(Token)]
+        api_key: String,
+        #[sensitive(Secret)]
+        pin: u16,
+        label: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Account {
+        #[sensitive(Secret)]
+        passwords: Vec<String>,
+        #[sensitive(Token)]
+        recovery: Option<String>,
+        credentials: Credentials,
+        #[not_sensitive]
+        region: String,
+    }
+
+    fn account() -> Account {
+        Account {
+            passwords: vec!["hunter2".into(), "correct horse".into()],
+            recovery: Some("rk_0123456789".into()),
+            credentials: Credentials {
+                api_key: "sk_live_abcdef".into(),
+                pin: 1234,
+                label: "primary".into(),
+            },
+            region: "eu-west-1".into(),
+        }
+    }
+
+    #[test]
+    fn custom_mapper_redacts_nested_leaves_end_to_end() {
+        let mapper = RecordingMapper::default();
+        let redacted = redact_with(account(), &mapper);
+
+        assert_eq!(redacted.passwords, vec!["[MAPPED]", "[MAPPED]"]);
+        assert_eq!(redacted.recovery.as_deref(), Some("[MAPPED]"));
+        assert_eq!(redacted.credentials.api_key, "[MAPPED]");
+        assert_eq!(redacted.credentials.pin, 0);
+        // Each string leaf reaches the mapper with its own field's policy.
+        assert_eq!(
+            *mapper.policies.borrow(),
+            ["Secret", "Secret", "Token", "Token"]
+        );
+        assert_eq!(redacted.credentials.label, "primary");
+        assert_eq!(redacted.region, "eu-west-1");
+        // Only `label` reaches the mapper unannotated; `region` is never walked.
+        assert_eq!(mapper.unannotated.get(), 1);
+    }
+
+    #[test]
+    fn redact_with_and_the_method_form_agree() {
+        let free = redact_with(account(), &RecordingMapper::default());
+        let method =
+            redactable::RedactableWithMapper::redact_with(account(), &RecordingMapper::default());
+
+        assert_eq!(free.passwords, method.passwords);
+        assert_eq!(free.recovery, method.recovery);
+        assert_eq!(free.credentials.api_key, method.credentials.api_key);
+    }
+}
+
+mod default_policy {
+    use std::borrow::Cow;
+
+    use redactable::Pii;
+
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    struct Address {
+        street: String,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+    #[sensitive(default_policy = Pii)]
+    struct Customer<'a> {
+        name: String,
+        city: Cow<'a, str>,
+        #[sensitive(Token)]
+        card: String,
+        #[not_sensitive]
+        account_id: String,
+        nickname: Option<String>,
+        aliases: Vec<String>,
+        address: Address,
+        visits: u32,
+    }
+
+    fn customer() -> Customer<'static> {
+        Customer {
+            name: "John Doe".into(),
+            city: Cow::Borrowed("Lisbon"),
+            card: "4111111111111234".into(),
+            account_id: "acct-42".into(),
+            nickname: Some("johnny".into()),
+            aliases: vec!["Jack".into(), "JD".into()],
+            address: Address {
+                street: "1 Main St".into(),
+            },
+            visits: 7,
+        }
+    }
+
+    #[test]
+    fn unannotated_string_fields_inherit_the_default() {
+        let redacted = customer().redact();
+
+        assert_eq!(redacted.name, "******oe");
+        assert_eq!(redacted.city, "****on");
+        assert_eq!(
+            Customer::REDACTED_FIELDS,
+            ["name", "city", "card", "nickname", "aliases"]
+        );
+    }
+
+    #[test]
+    fn explicit_annotations_override_the_default() {
+        let redacted = customer().redact();
+
+        assert_eq!(redacted.card, "************1234");
+        assert_eq!(redacted.account_id, "acct-42");
+    }
+
+    #[test]
+    fn optional_string_fields_inherit_the_default() {
+        let redacted = customer().redact();
+
+        assert_eq!(redacted.nickname.as_deref(), Some("****ny"));
+    }
+
+    #[test]
+    fn string_vec_fields_inherit_the_default() {
+        let redacted = customer().redact();
+
+        assert_eq!(redacted.aliases, ["**ck", "**"]);
+    }
+
+    #[test]
+    fn nested_sensitive_types_and_scalars_are_not_defaulted() {
+        let redacted = customer().redact();
+
+        assert_eq!(redacted.address.street, "1 Main St");
+        assert_eq!(redacted.visits, 7);
+    }
+
+    #[test]
+    fn enum_variant_fields_inherit_the_default() {
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        #[sensitive(default_policy = Secret)]
+        enum Contact {
+            Email(String),
+            Phone {
+                number: String,
+                #[not_sensitive]
+                country: String,
+            },
+        }
+
+        let Contact::Email(email) = Contact::Email("alice@example.com".into()).redact() else {
+            panic!("redaction must keep the variant");
+        };
+        assert_eq!(email, "[REDACTED]");
+
+        let Contact::Phone { number, country } = Contact::Phone {
+            number: "555-0100".into(),
+            country: "PT".into(),
+        }
+        .redact() else {
+            panic!("redaction must keep the variant");
+        };
+        assert_eq!(number, "[REDACTED]");
+        assert_eq!(country, "PT");
+    }
+
+    #[test]
+    fn dual_derive_applies_the_default_to_both_halves() {
+        use redactable::{RedactableWithFormatter, SensitiveDual};
+
+        #[derive(Clone, SensitiveDual)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        #[sensitive(default_policy = Secret)]
+        #[error("{user} from {host}")]
+        struct Visit {
+            user: String,
+            #[not_sensitive]
+            host: String,
+        }
+
+        let visit = Visit {
+            user: "alice".into(),
+            host: "example.com".into(),
+        };
+        assert_eq!(
+            visit.redacted_display().to_string(),
+            "[REDACTED] from example.com"
+        );
+        assert_eq!(visit.redact().user, "[REDACTED]");
+    }
+}
@@ -100,3 +100,353 @@ mod dual_derive {
         assert_eq!(display, "notification to *************.com");
     }
 }
+
+#[test]
+fn formats_cow_slices_like_slices() {
+    use std::borrow::Cow;
+
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("recipients {recipients:?} via {channel}")]
+    struct Broadcast<'a> {
+        recipients: Cow<'a, [String]>,
+        channel: String,
+    }
+
+    let recipients = ["alice".to_owned(), "bob".to_owned()];
+    let borrowed = Broadcast {
+        recipients: Cow::Borrowed(&recipients),
+        channel: "email".into(),
+    };
+    let owned = Broadcast {
+        recipients: Cow::Owned(recipients.to_vec()),
+        channel: "email".into(),
+    };
+
+    // Same rendering as the slice: elements use their redacted display.
+    let expected = format!("recipients {} via email", recipients.redacted_display());
+    assert_eq!(expected, "recipients [alice, bob] via email");
+    assert_eq!(borrowed.redacted_display().to_string(), expected);
+    assert_eq!(owned.redacted_display().to_string(), expected);
+}
+
+#[test]
+fn formats_tuple_fields_element_wise() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("pair {0:?}")]
+    struct PairError((String, String));
+
+    #[derive(SensitiveDisplay)]
+    #[error("{wide} by {token}")]
+    struct Wide {
+        wide: (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    let err = PairError(("alice".into(), "bob".into()));
+    assert_eq!(err.redacted_display().to_string(), "pair (alice, bob)");
+
+    let wide = Wide {
+        wide: (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12),
+        token: "hunter2".into(),
+    };
+    assert_eq!(
+        wide.redacted_display().to_string(),
+        "(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12) by [REDACTED]"
+    );
+}
+
+#[test]
+fn formats_unannotated_ip_addresses_unchanged() {
+    use std::net::{IpAddr, SocketAddr};
+
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("{peer} via {listen} with {token}")]
+    struct Handshake {
+        peer: IpAddr,
+        listen: SocketAddr,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    let handshake = Handshake {
+        peer: "192.0.2.7".parse().unwrap(),
+        listen: "203.0.113.9:8443".parse().unwrap(),
+        token: "secret".into(),
+    };
+    assert_eq!(
+        handshake.redacted_display().to_string(),
+        "192.0.2.7 via 203.0.113.9:8443 with [REDACTED]"
+    );
+}
+
+#[cfg(feature = "ip-address")]
+#[test]
+fn formats_annotated_ip_addresses_with_the_host_masked() {
+    use std::net::{IpAddr, SocketAddr};
+
+    use redactable::{IpAddress, RedactableWithFormatter};
+
+    #[derive(SensitiveDisplay)]
+    #[error("{peer} via {listen}")]
+    struct Handshake {
+        #[sensitive(IpAddress)]
+        peer: IpAddr,
+        #[sensitive(IpAddress)]
+        listen: SocketAddr,
+    }
+
+    let handshake = Handshake {
+        peer: "192.0.2.7".parse().unwrap(),
+        listen: "203.0.113.9:8443".parse().unwrap(),
+    };
+    assert_eq!(
+        handshake.redacted_display().to_string(),
+        "192.0.0.0 via 203.0.0.0:8443"
+    );
+}
+
+#[test]
+fn outer_width_pads_the_whole_rendered_template() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("user {user} token {token}")]
+    struct Session {
+        #[not_sensitive]
+        user: String,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    let session = Session {
+        user: "alice".into(),
+        token: "hunter2".into(),
+    };
+    let display = session.redacted_display();
+
+    assert_eq!(display.to_string(), "user alice token [REDACTED]");
+    assert_eq!(
+        format!("{display:>32}|"),
+        "     user alice token [REDACTED]|"
+    );
+    assert_eq!(
+        format!("{display:-^32}|"),
+        "--user alice token [REDACTED]---|"
+    );
+    assert_eq!(format!("{display:8}"), "user alice token [REDACTED]");
+}
+
+#[test]
+fn placeholder_specs_pad_each_redacted_value() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("[{0:>8}] [{1:<10}] [{2:*^12}]")]
+    struct Positional(#[not_sensitive] String, String, #[sensitive(Secret)] String);
+
+    #[derive(SensitiveDisplay)]
+    #[error("[{name:<10}] [{code:0>6}] [{token:.3}]")]
+    struct Named {
+        #[not_sensitive]
+        name: String,
+        code: u32,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    let positional = Positional("alice".into(), "bob".into(), "hunter2".into());
+    assert_eq!(
+        positional.redacted_display().to_string(),
+        "[   alice] [bob       ] [*[REDACTED]*]"
+    );
+
+    let named = Named {
+        name: "alice".into(),
+        code: 42,
+        token: "hunter2".into(),
+    };
+    assert_eq!(
+        named.redacted_display().to_string(),
+        "[alice     ] [000042] [[RE]"
+    );
+}
+
+#[test]
+fn placeholder_precision_truncates_nested_templates() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("user {user} token {token}")]
+    struct Session {
+        user: String,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    #[derive(SensitiveDisplay)]
+    #[error("[{0:.10}] [{0:>12.4}]")]
+    struct Wrapper(Session);
+
+    let wrapper = Wrapper(Session {
+        user: "alice".into(),
+        token: "hunter2".into(),
+    });
+    assert_eq!(
+        wrapper.redacted_display().to_string(),
+        "[user alice] [        user]"
+    );
+}
+
+#[test]
+fn placeholder_specs_pad_whole_container_fields() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("[{opt:>18}] [{items:<12}] [{items:.4}] [{none:^8}]")]
+    struct Report {
+        #[sensitive(Secret)]
+        opt: Option<String>,
+        items: Vec<String>,
+        none: Option<String>,
+    }
+
+    let report = Report {
+        opt: Some("hunter2".into()),
+        items: vec!["a".into(), "b".into()],
+        none: None,
+    };
+    assert_eq!(
+        report.redacted_display().to_string(),
+        "[  Some([REDACTED])] [[a, b]      ] [[a, ] [  None  ]"
+    );
+}
+
+#[test]
+fn not_sensitive_numeric_fields_accept_numeric_specifiers() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[error("code {code:#x} ({code}) flags {flags:08b} mode {mode:o} token {token}")]
+    struct Status {
+        #[not_sensitive]
+        code: u32,
+        #[not_sensitive]
+        flags: u8,
+        #[not_sensitive]
+        mode: u16,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    #[derive(SensitiveDisplay)]
+    #[error("id {0:X}")]
+    struct Generic<T>(#[not_sensitive] T);
+
+    let status = Status {
+        code: 255,
+        flags: 5,
+        mode: 0o644,
+        token: "hunter2".into(),
+    };
+    assert_eq!(
+        status.redacted_display().to_string(),
+        "code 0xff (255) flags 00000101 mode 644 token [REDACTED]"
+    );
+    assert_eq!(
+        Generic(48_879_u32).redacted_display().to_string(),
+        "id BEEF"
+    );
+}
+
+#[test]
+fn display_attribute_supplies_templates_for_non_error_types() {
+    use redactable::RedactableWithFormatter;
+
+    #[derive(SensitiveDisplay)]
+    #[display("{user} signed in with {token}")]
+    struct SessionSummary {
+        user: String,
+        #[sensitive(Secret)]
+        token: String,
+    }
+
+    #[derive(SensitiveDisplay)]
+    #[display(fmt = "key {0}")]
+    struct ApiKeyLabel(#[sensitive(Token)] String);
+
+    #[derive(SensitiveDisplay)]
+    enum Event {
+        #[display("login {user}")]
+        Login { user: String },
+        /// logout {0}
+        Logout(String),
+    }
+
+    let summary = SessionSummary {
+        user: "alice".into(),
+        token: "hunter2".into(),
+    };
+    assert_eq!(
+        summary.redacted_display().to_string(),
+        "alice signed in with [REDACTED]"
+    );
+    assert_eq!(
+        ApiKeyLabel("sk_live_abcd1234".into())
+            .redacted_display()
+            .to_string(),
+        "key ************1234"
+    );
+    assert_eq!(
+        Event::Login {
+            user: "alice".into()
+        }
+        .redacted_display()
+        .to_string(),
+        "login alice"
+    );
+    assert_eq!(
+        Event::Logout("bob".into()).redacted_display().to_string(),
+        "logout bob"
+    );
+}
+
+#[test]
+fn display_attribute_takes_precedence_over_error_and_doc_templates() {
+    use redactable::RedactableWithFormatter;
+
+    /// doc {secret}
+    #[derive(SensitiveDisplay)]
+    #[error("error {secret}")]
+    #[display("display {secret}")]
+    struct AllThree {
+        #[sensitive(Secret)]
+        secret: String,
+    }
+
+    /// doc {secret}
+    #[derive(SensitiveDisplay)]
+    #[error("error {secret}")]
+    struct ErrorAndDoc {
+        #[sensitive(Secret)]
+        secret: String,
+    }
+
+    let all = AllThree {
+        secret: "hunter2".into(),
+    };
+    let error_and_doc = ErrorAndDoc {
+        secret: "hunter2".into(),
+    };
+    assert_eq!(all.redacted_display().to_string(), "display [REDACTED]");
+    assert_eq!(
+        error_and_doc.redacted_display().to_string(),
+        "error [REDACTED]"
+    );
+}
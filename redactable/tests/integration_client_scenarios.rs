@@ -8,7 +8,7 @@
 use std::marker::PhantomData;
 
 use redactable::{
-    Email, PhoneNumber, Pii, Redactable, RedactionPolicy, Secret, Sensitive, TextPolicyKind,
+    Email, PhoneNumber, Pii, Redactable, RedactionPolicy, Secret, Sensitive, Ssn, TextPolicyKind,
     TextRedactionPolicy,
 };
 
@@ -66,7 +66,7 @@ mod wrapper_types_with_policy {
         let redacted = profile.redact();
         assert_eq!(redacted.username, "johndoe");
         assert_eq!(redacted.email, Some("jo**@example.com".into()));
-        assert_eq!(redacted.phone, Some("***********4567".into()));
+        assert_eq!(redacted.phone, Some("+1*********4567".into()));
 
         let profile_no_contact = UserProfile {
             username: "janedoe".into(),
@@ -320,7 +320,7 @@ mod real_world_scenarios {
         assert!(redacted.is_active);
         assert_eq!(redacted.full_name, "******oe");
         assert_eq!(redacted.email, "jo******@example.com");
-        assert_eq!(redacted.phone, Some("***********4567".into()));
+        assert_eq!(redacted.phone, Some("+1*********4567".into()));
         assert_eq!(redacted.password_hash, "[REDACTED]");
         assert_eq!(
             redacted.recovery_codes,
@@ -391,4 +391,34 @@ mod real_world_scenarios {
             vec!["[REDACTED]", "[REDACTED]", "[REDACTED]"]
         );
     }
+
+    #[test]
+    fn loan_applicant_ssn() {
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct LoanApplicant {
+            #[sensitive(Pii)]
+            full_name: String,
+            #[sensitive(Ssn)]
+            ssn: String,
+            #[sensitive(Ssn)]
+            co_signer_ssn: Option<String>,
+            #[sensitive(Ssn)]
+            previous_ssn: String,
+        }
+
+        let applicant = LoanApplicant {
+            full_name: "John Doe".into(),
+            ssn: "123-45-6789".into(),
+            co_signer_ssn: Some("987654321".into()),
+            previous_ssn: String::new(),
+        };
+
+        let redacted = applicant.redact();
+
+        assert_eq!(redacted.full_name, "******oe");
+        assert_eq!(redacted.ssn, "*******6789");
+        assert_eq!(redacted.co_signer_ssn, Some("*****4321".into()));
+        assert_eq!(redacted.previous_ssn, "[REDACTED]");
+    }
 }
@@ -0,0 +1,142 @@
+//! Integration tests for the log module.
+//!
+//! These tests install a capturing `log::Log` implementation and assert the
+//! formatted messages that reach it.
+
+#![cfg(feature = "log")]
+
+use std::{cell::RefCell, fmt, sync::Once};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use redactable::{NotSensitiveDisplay, Secret, SensitiveDisplay, log::LogRedactedExt};
+use redactable_test_fixtures::{FixtureError, GenericDualFixture};
+
+thread_local! {
+    static CAPTURED: RefCell<Vec<(Level, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records messages per thread so parallel tests do not observe each other.
+struct CapturingLogger;
+
+impl Log for CapturingLogger {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        CAPTURED.with(|captured| {
+            captured
+                .borrow_mut()
+                .push((record.level(), record.args().to_string()));
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger;
+static INIT: Once = Once::new();
+
+fn capture_messages(record: impl FnOnce()) -> Vec<(Level, String)> {
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).expect("capturing logger installs once");
+        log::set_max_level(LevelFilter::Trace);
+    });
+    CAPTURED.with(|captured| captured.borrow_mut().clear());
+    record();
+    CAPTURED.with(|captured| captured.take())
+}
+
+fn login_error() -> FixtureError {
+    FixtureError {
+        user: "alice".to_owned(),
+        password: "raw-password".to_owned(),
+    }
+}
+
+#[test]
+fn as_log_renders_redacted_display() {
+    let error = login_error();
+
+    let messages = capture_messages(|| log::info!("request rejected: {}", error.as_log()));
+
+    assert_eq!(
+        messages,
+        vec![(
+            Level::Info,
+            "request rejected: login failed for alice with [REDACTED]".to_owned()
+        )]
+    );
+}
+
+#[test]
+fn redacted_log_wraps_every_positional_argument() {
+    const CANARY: &str = "log-macro-canary-3d81";
+    let error = login_error();
+    let dual = GenericDualFixture {
+        label: String::from("event"),
+        secret: String::from(CANARY),
+    };
+
+    let messages = capture_messages(|| {
+        redactable::redacted_log!(Level::Warn, "{} / {}", error, dual);
+    });
+
+    assert_eq!(messages.len(), 1);
+    let (level, message) = &messages[0];
+    assert_eq!(*level, Level::Warn);
+    assert!(message.starts_with("login failed for alice with [REDACTED] / "));
+    assert!(message.contains("[REDACTED]"));
+    assert!(!message.contains("raw-password"));
+    assert!(!message.contains(CANARY));
+}
+
+#[test]
+fn redacted_log_accepts_not_sensitive_display_types() {
+    #[derive(Clone, NotSensitiveDisplay)]
+    struct RequestId(u32);
+
+    impl fmt::Display for RequestId {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "req-{}", self.0)
+        }
+    }
+
+    #[derive(SensitiveDisplay)]
+    #[error("card {number}")]
+    struct Payment {
+        #[sensitive(Secret)]
+        number: String,
+    }
+
+    let payment = Payment {
+        number: "4111111111111234".to_owned(),
+    };
+
+    let messages = capture_messages(|| {
+        redactable::redacted_log!(Level::Debug, "{}: {}", RequestId(42), payment);
+    });
+
+    assert_eq!(
+        messages,
+        vec![(Level::Debug, "req-42: card [REDACTED]".to_owned())]
+    );
+}
+
+#[test]
+fn redacted_log_accepts_escaped_braces_and_positional_specs() {
+    let error = login_error();
+    let width = 40;
+
+    let messages = capture_messages(|| {
+        redactable::redacted_log!(Level::Info, "{{user}} [{0:>width$}]", error);
+    });
+
+    assert_eq!(
+        messages,
+        vec![(
+            Level::Info,
+            "{user} [  login failed for alice with [REDACTED]]".to_owned()
+        )]
+    );
+}
@@ -7,8 +7,9 @@ use std::{
 };
 
 use redactable::{
-    NotSensitiveDebug, NotSensitiveDebugExt, NotSensitiveDisplay, NotSensitiveDisplayExt,
-    RedactedOutput, ToRedactedOutput,
+    NotSensitiveBytesExt, NotSensitiveDebug, NotSensitiveDebugExt, NotSensitiveDisplay,
+    NotSensitiveDisplayExt, RedactedOutput, RedactedRecord, Secret, SensitiveValue,
+    ToRedactedOutput,
 };
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -219,3 +220,53 @@ mod serde_contract {
         assert!(!nested_output.contains("secret"));
     }
 }
+
+#[test]
+fn display_wrapper_around_sensitive_value_emits_redacted_form() {
+    let sensitive = SensitiveValue::<String, Secret>::from(String::from("secret"));
+    assert_eq!(
+        output(&NotSensitiveDisplay(sensitive)),
+        RedactedOutput::Text(String::from("[REDACTED]"))
+    );
+}
+
+#[test]
+fn bytes_wrapper_round_trips_payload_through_redacted_output() {
+    let payload: Vec<u8> = (0..=255).collect();
+    let output = payload.not_sensitive_bytes().to_redacted_output();
+
+    assert_eq!(output, RedactedOutput::Bytes(payload.clone()));
+    let RedactedOutput::Bytes(bytes) = output.to_redacted_output() else {
+        panic!("bytes output must stay bytes");
+    };
+    assert_eq!(bytes, payload);
+    assert_ne!(
+        b"abc".not_sensitive_bytes().to_redacted_output(),
+        RedactedOutput::Text("abc".to_owned())
+    );
+}
+
+#[test]
+fn bytes_output_renders_as_lowercase_hex_and_truncates_by_byte() {
+    let output = [0xde_u8, 0xad, 0xbe, 0xef]
+        .not_sensitive_bytes()
+        .to_redacted_output();
+
+    assert_eq!(output.to_string_compact(), "deadbeef");
+    assert_eq!(output.to_string_pretty(), "deadbeef");
+    assert_eq!(output.to_string(), "deadbeef");
+    assert_eq!(
+        output.clone().truncated(2),
+        RedactedOutput::Bytes(vec![0xde, 0xad])
+    );
+    assert_eq!(
+        RedactedRecord::new()
+            .field("digest", &"\x01\x02".not_sensitive_bytes())
+            .to_text(),
+        "digest=0102"
+    );
+    assert_eq!(
+        format!("{:?}", "hi".not_sensitive_bytes()),
+        "NotSensitiveBytes([104, 105])"
+    );
+}
@@ -8,7 +8,9 @@
 //! compiled with `cfg(test)`.
 
 use redactable::{RedactableWithFormatter, ToRedactedOutput};
-use redactable_test_fixtures::{FixtureError, FixtureEvent, FixtureUser, GenericDualFixture};
+use redactable_test_fixtures::{
+    FixtureDiagnosis, FixtureError, FixtureEvent, FixtureUser, GenericDualFixture,
+};
 
 /// True when `redactable` itself was built with the `testing` feature, which
 /// flips derived `Debug` to raw output by design.
@@ -121,6 +123,20 @@ fn sensitive_enum_debug_uses_compact_variant_names_in_production_builds() {
     }
 }
 
+#[test]
+fn opaque_enum_debug_hides_the_variant_in_production_builds() {
+    let output = format!("{:?}", FixtureDiagnosis::Chronic("asthma".to_string()));
+
+    if TESTING_MODE {
+        assert!(
+            output.contains("asthma"),
+            "testing feature should reveal raw Debug output, got: {output}"
+        );
+    } else {
+        assert_eq!(output, "FixtureDiagnosis(<redacted>)");
+    }
+}
+
 #[test]
 fn redacted_display_is_unaffected_by_debug_mode() {
     let err = FixtureError {
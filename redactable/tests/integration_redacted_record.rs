@@ -0,0 +1,96 @@
+//! Rendering contracts for `RedactedRecord` key-value log records.
+
+use redactable::{NotSensitiveDisplay, RedactedRecord, Secret, SensitiveValue, Token};
+
+fn login_record() -> RedactedRecord {
+    let token = SensitiveValue::<String, Token>::from("sk_live_abc123def".to_owned());
+    let password = SensitiveValue::<String, Secret>::from("hunter2".to_owned());
+    RedactedRecord::new()
+        .field("event", &NotSensitiveDisplay("login"))
+        .field("attempt", &NotSensitiveDisplay(3))
+        .field("token", &token)
+        .field("password", &password)
+}
+
+#[test]
+fn renders_key_value_text_in_insertion_order() {
+    assert_eq!(
+        login_record().to_text(),
+        "event=login attempt=3 token=*************3def password=[REDACTED]"
+    );
+}
+
+#[test]
+fn quotes_text_values_that_would_break_the_line() {
+    let mut record = RedactedRecord::new();
+    record.push("message", &NotSensitiveDisplay("user signed in"));
+    record.push("query", &NotSensitiveDisplay("a=b"));
+    record.push("quoted", &NotSensitiveDisplay("say \"hi\""));
+    record.push("empty", &NotSensitiveDisplay(""));
+    assert_eq!(
+        record.to_text(),
+        r#"message="user signed in" query="a=b" quoted="say \"hi\"" empty="""#
+    );
+}
+
+#[test]
+fn exposes_fields_and_renders_empty_records() {
+    let record = login_record();
+    let keys: Vec<_> = record.fields().iter().map(|(key, _)| *key).collect();
+    assert_eq!(keys, ["event", "attempt", "token", "password"]);
+    assert_eq!(RedactedRecord::new().to_text(), "");
+}
+
+#[cfg(feature = "json")]
+mod json {
+    use redactable::NotSensitiveJsonExt;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn renders_the_same_record_as_a_json_object() {
+        assert_eq!(
+            login_record().to_json(),
+            json!({
+                "event": "login",
+                "attempt": "3",
+                "token": "*************3def",
+                "password": "[REDACTED]",
+            })
+        );
+    }
+
+    #[test]
+    fn embeds_structured_json_values() {
+        let tags = vec!["beta", "admin"];
+        let record = RedactedRecord::new()
+            .field("event", &NotSensitiveDisplay("login"))
+            .field("tags", &tags.not_sensitive_json());
+        assert_eq!(
+            record.to_json(),
+            json!({"event": "login", "tags": ["beta", "admin"]})
+        );
+        assert_eq!(
+            record.to_text(),
+            r#"event=login tags="[\"beta\",\"admin\"]""#
+        );
+    }
+
+    #[test]
+    fn renders_byte_outputs_as_hex_strings() {
+        use redactable::NotSensitiveBytesExt;
+
+        let record = RedactedRecord::new().field("digest", &[0xca_u8, 0xfe].not_sensitive_bytes());
+        assert_eq!(record.to_json(), json!({"digest": "cafe"}));
+    }
+
+    #[test]
+    fn later_duplicate_keys_win_in_json() {
+        let mut record = RedactedRecord::new();
+        record.push("user", &NotSensitiveDisplay("first"));
+        record.push("user", &NotSensitiveDisplay("second"));
+        assert_eq!(record.to_text(), "user=first user=second");
+        assert_eq!(record.to_json(), json!({"user": "second"}));
+    }
+}
@@ -5,7 +5,10 @@
 
 #![cfg(feature = "json")]
 
-use redactable::{Redactable, RedactableWithFormatter, Secret, Sensitive, Token};
+use redactable::{
+    FullRedact, JsonString, Redactable, RedactableWithFormatter, RedactedOutput, Secret, Sensitive,
+    Token,
+};
 use serde_json::{Value, json};
 
 mod policy_application {
@@ -28,6 +31,27 @@ mod policy_application {
         assert_eq!(redacted.data, Value::String("[REDACTED]".to_string()));
     }
 
+    #[test]
+    fn redacts_with_full_redact_policy() {
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Payload {
+            #[sensitive(FullRedact)]
+            data: Value,
+            #[sensitive(FullRedact)]
+            count: u32,
+        }
+
+        let payload = Payload {
+            data: json!({"secret": "password123"}),
+            count: 7,
+        };
+
+        let redacted = payload.redact();
+        assert_eq!(redacted.data, Value::String("[REDACTED]".to_string()));
+        assert_eq!(redacted.count, 0);
+    }
+
     #[test]
     fn redacts_with_token_policy() {
         #[derive(Clone, Sensitive)]
@@ -534,3 +558,549 @@ mod real_world_scenarios {
         assert_eq!(redacted.payload, Value::String("[REDACTED]".to_string()));
     }
 }
+
+mod redacted_output_text {
+    use super::*;
+
+    #[test]
+    fn renders_json_compact_and_pretty() {
+        let output = RedactedOutput::Json(json!({"user": "alice", "token": "[REDACTED]"}));
+
+        assert_eq!(
+            output.to_string_compact(),
+            r#"{"token":"[REDACTED]","user":"alice"}"#
+        );
+        assert_eq!(
+            output.to_string_pretty(),
+            "{\n  \"token\": \"[REDACTED]\",\n  \"user\": \"alice\"\n}"
+        );
+    }
+
+    #[test]
+    fn returns_text_unchanged() {
+        let output = RedactedOutput::Text("user=alice token=[REDACTED]".into());
+
+        assert_eq!(output.to_string_compact(), "user=alice token=[REDACTED]");
+        assert_eq!(output.to_string_pretty(), "user=alice token=[REDACTED]");
+    }
+
+    #[test]
+    fn display_writes_text_and_compact_json() {
+        let text = RedactedOutput::Text("user=alice token=[REDACTED]".into());
+        let json = RedactedOutput::Json(json!({"user": "alice", "token": "[REDACTED]"}));
+
+        assert_eq!(text.to_string(), "user=alice token=[REDACTED]");
+        assert_eq!(
+            format!("event: {json}"),
+            r#"event: {"token":"[REDACTED]","user":"alice"}"#
+        );
+        assert_eq!(json.to_string(), json.to_string_compact());
+    }
+}
+
+mod redacted_output_equality {
+    use serde_json::Map;
+
+    use super::*;
+
+    fn object(entries: &[(&str, Value)]) -> Value {
+        let mut map = Map::new();
+        for (key, value) in entries {
+            map.insert((*key).to_owned(), value.clone());
+        }
+        Value::Object(map)
+    }
+
+    #[test]
+    fn json_outputs_ignore_key_insertion_order() {
+        let forward = object(&[
+            ("user", json!("alice")),
+            ("token", json!("[REDACTED]")),
+            ("meta", object(&[("a", json!(1)), ("b", json!([1, 2]))])),
+        ]);
+        let reversed = object(&[
+            ("meta", object(&[("b", json!([1, 2])), ("a", json!(1))])),
+            ("token", json!("[REDACTED]")),
+            ("user", json!("alice")),
+        ]);
+
+        assert_eq!(
+            RedactedOutput::Json(forward),
+            RedactedOutput::Json(reversed)
+        );
+    }
+
+    #[test]
+    fn json_outputs_still_compare_arrays_in_order() {
+        assert_ne!(
+            RedactedOutput::Json(json!({"ids": [1, 2]})),
+            RedactedOutput::Json(json!({"ids": [2, 1]}))
+        );
+        assert_ne!(
+            RedactedOutput::Json(json!("alice")),
+            RedactedOutput::Text("\"alice\"".into())
+        );
+    }
+}
+
+mod redacted_output_truncation {
+    use super::*;
+
+    #[test]
+    fn truncates_text_on_char_boundaries() {
+        let output = RedactedOutput::Text("user=alice token=[REDACTED]".into());
+        assert_eq!(
+            output.clone().truncated(13),
+            RedactedOutput::Text("user=alice\u{2026}".into())
+        );
+        assert_eq!(output.clone().truncated(64), output);
+
+        // `é` spans bytes 3..5, so a 4-byte prefix budget backs off to 3 bytes.
+        let output = RedactedOutput::Text("caféteria".into());
+        assert_eq!(
+            output.clone().truncated(7),
+            RedactedOutput::Text("caf\u{2026}".into())
+        );
+        assert_eq!(output.truncated(2), RedactedOutput::Text("ca".into()));
+    }
+
+    #[test]
+    fn truncates_json_string_leaves() {
+        let output = RedactedOutput::Json(json!({
+            "message": "a long message body",
+            "count": 12345678,
+            "tags": ["short", "much longer tag"],
+        }));
+
+        assert_eq!(
+            output.truncated(8),
+            RedactedOutput::Json(json!({
+                "message": "a lon\u{2026}",
+                "count": 12345678,
+                "tags": ["short", "much \u{2026}"],
+            }))
+        );
+    }
+}
+
+mod json_string_policy {
+    use super::*;
+
+    #[test]
+    fn redacts_secret_keys_inside_string_fields() {
+        #[derive(Clone, Sensitive)]
+        #[cfg_attr(feature = "slog", derive(serde::Serialize))]
+        struct Request {
+            #[sensitive(JsonString)]
+            body: String,
+            #[sensitive(JsonString)]
+            raw: Option<String>,
+        }
+
+        let request = Request {
+            body: r#"{"user":"alice","password":"hunter2","meta":{"token":"abc123"}}"#.into(),
+            raw: Some("password=hunter2".into()),
+        };
+
+        let redacted = request.redact();
+
+        let body: Value = serde_json::from_str(&redacted.body).unwrap();
+        assert_eq!(
+            body,
+            json!({"user": "alice", "password": "[REDACTED]", "meta": {"token": "[REDACTED]"}})
+        );
+        // Not JSON: fails closed to full redaction.
+        assert_eq!(redacted.raw.as_deref(), Some("[REDACTED]"));
+    }
+}
+
+mod serde_redact {
+    use super::*;
+
+    #[test]
+    fn serializes_redacted_struct_fields() {
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Account {
+            id: u64,
+            #[sensitive(Secret)]
+            password: String,
+            #[sensitive(Token)]
+            api_key: String,
+            r#type: String,
+        }
+
+        let account = Account {
+            id: 7,
+            password: "hunter2".into(),
+            api_key: "sk_live_abcdef1234".into(),
+            r#type: "admin".into(),
+        };
+
+        let value = serde_json::to_value(&account).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "id": 7,
+                "password": "[REDACTED]",
+                "api_key": "**************1234",
+                "type": "admin",
+            })
+        );
+        // Serialization redacts a clone; the original keeps its values.
+        assert_eq!(account.password, "hunter2");
+    }
+
+    #[test]
+    fn serializes_redacted_enum_variants() {
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        enum Credential {
+            Anonymous,
+            Password(#[sensitive(Secret)] String),
+            Pair(String, #[sensitive(Secret)] String),
+            Key {
+                label: String,
+                #[sensitive(Secret)]
+                secret: String,
+            },
+        }
+
+        let values = [
+            Credential::Anonymous,
+            Credential::Password("hunter2".into()),
+            Credential::Pair("alice".into(), "hunter2".into()),
+            Credential::Key {
+                label: "deploy".into(),
+                secret: "hunter2".into(),
+            },
+        ]
+        .map(|credential| serde_json::to_value(&credential).unwrap());
+
+        assert_eq!(
+            values,
+            [
+                json!("Anonymous"),
+                json!({"Password": "[REDACTED]"}),
+                json!({"Pair": ["alice", "[REDACTED]"]}),
+                json!({"Key": {"label": "deploy", "secret": "[REDACTED]"}}),
+            ]
+        );
+    }
+
+    #[test]
+    fn serializes_nested_and_generic_types() {
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Inner(#[sensitive(Secret)] String);
+
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Envelope<T> {
+            inner: T,
+            items: Vec<Inner>,
+        }
+
+        let envelope = Envelope {
+            inner: Inner("hunter2".into()),
+            items: vec![Inner("a".into()), Inner("b".into())],
+        };
+
+        let value = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(
+            value,
+            json!({"inner": "[REDACTED]", "items": ["[REDACTED]", "[REDACTED]"]})
+        );
+    }
+
+    #[test]
+    fn round_trips_into_redacted_values() {
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Login {
+            user: String,
+            #[sensitive(Secret)]
+            password: String,
+        }
+
+        #[derive(serde::Deserialize, PartialEq, Debug)]
+        struct LoginRecord {
+            user: String,
+            password: String,
+        }
+
+        let json = serde_json::to_string(&Login {
+            user: "alice".into(),
+            password: "hunter2".into(),
+        })
+        .unwrap();
+        let record: LoginRecord = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(
+            record,
+            LoginRecord {
+                user: "alice".into(),
+                password: "[REDACTED]".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn redacting_json_adapters_apply_policies_once() {
+        use redactable::{
+            IntoRedactedJsonExt, RedactSerializeExt, RedactedJsonExt, RedactionPolicy,
+            TextPolicyKind, TextRedactionPolicy, ToRedactedOutput,
+        };
+
+        #[derive(Clone, Copy)]
+        struct Fingerprint;
+
+        impl RedactionPolicy for Fingerprint {
+            type Kind = TextPolicyKind;
+
+            fn policy() -> TextRedactionPolicy {
+                TextRedactionPolicy::token_hash(8)
+            }
+        }
+
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Session {
+            #[sensitive(Fingerprint)]
+            token: String,
+        }
+
+        let session = Session {
+            token: "sk_live_abcdef1234".into(),
+        };
+        let expected = serde_json::to_value(&session).unwrap();
+
+        // Hashing is not idempotent, so a second pass would change the value.
+        let token = TextRedactionPolicy::token_hash(8).apply_to("sk_live_abcdef1234");
+        assert_eq!(expected, json!({ "token": token }));
+        assert_eq!(
+            serde_json::to_value(session.redact_serialize()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            session.redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected.clone())
+        );
+        assert_eq!(
+            session.clone().into_redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected.clone())
+        );
+        assert_eq!(
+            serde_json::to_value(vec![session]).unwrap(),
+            json!([expected])
+        );
+    }
+
+    #[test]
+    fn nested_serde_redact_types_apply_policies_once() {
+        use redactable::{
+            IntoRedactedJsonExt, NotSensitiveValue, RedactSerializeExt, RedactedJsonExt,
+            RedactionPolicy, TextPolicyKind, TextRedactionPolicy, ToRedactedOutput,
+        };
+
+        #[derive(Clone, Copy)]
+        struct Angled;
+
+        impl RedactionPolicy for Angled {
+            type Kind = TextPolicyKind;
+
+            fn policy() -> TextRedactionPolicy {
+                TextRedactionPolicy::custom(|value| format!("<{value}>"))
+            }
+        }
+
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Session {
+            #[sensitive(Angled)]
+            token: String,
+        }
+
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Outer {
+            session: Session,
+            sessions: Vec<Session>,
+            #[not_sensitive]
+            unwalked: Session,
+            wrapped: NotSensitiveValue<Session>,
+        }
+
+        let session = || Session {
+            token: "sk_live_abcdef1234".into(),
+        };
+        let outer = Outer {
+            session: session(),
+            sessions: vec![session()],
+            unwalked: session(),
+            wrapped: NotSensitiveValue(session()),
+        };
+
+        // The custom policy is not idempotent, so a second pass would nest the
+        // brackets. Fields the outer redaction skipped still redact themselves.
+        let expected = json!({
+            "session": {"token": "<sk_live_abcdef1234>"},
+            "sessions": [{"token": "<sk_live_abcdef1234>"}],
+            "unwalked": {"token": "<sk_live_abcdef1234>"},
+            "wrapped": {"token": "<sk_live_abcdef1234>"},
+        });
+        assert_eq!(serde_json::to_value(&outer).unwrap(), expected);
+        assert_eq!(
+            outer.into_redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected)
+        );
+
+        // A parent with a plain derived `Serialize` redacts before serializing,
+        // so the nested types must not redact a second time either.
+        #[derive(Clone, Sensitive, serde::Serialize)]
+        struct Plain {
+            session: Session,
+            sessions: Vec<Session>,
+        }
+
+        let plain = Plain {
+            session: session(),
+            sessions: vec![session()],
+        };
+        let expected = json!({
+            "session": {"token": "<sk_live_abcdef1234>"},
+            "sessions": [{"token": "<sk_live_abcdef1234>"}],
+        });
+        assert_eq!(
+            serde_json::to_value(plain.redact_serialize()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            plain.redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected.clone())
+        );
+        assert_eq!(
+            plain.into_redacted_json().to_redacted_output(),
+            RedactedOutput::Json(expected)
+        );
+    }
+
+    #[test]
+    fn serde_redact_map_keys_redact_themselves_inside_serde_redact_parents() {
+        use std::collections::BTreeMap;
+
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Key(#[sensitive(Secret)] String);
+
+        #[derive(Clone, Sensitive)]
+        #[sensitive(serde_redact)]
+        struct Parent {
+            map: BTreeMap<Key, String>,
+        }
+
+        let map = BTreeMap::from([(Key("hunter2".into()), "v".to_owned())]);
+        let parent = Parent { map: map.clone() };
+
+        // Traversal never reaches map keys, so they redact when serialized.
+        assert_eq!(
+            serde_json::to_value(&map).unwrap(),
+            json!({"[REDACTED]": "v"})
+        );
+        assert_eq!(
+            serde_json::to_value(&parent).unwrap(),
+            json!({"map": {"[REDACTED]": "v"}})
+        );
+    }
+}
+
+mod redact_serialize {
+    use redactable::RedactSerializeExt;
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Clone, Sensitive, Serialize)]
+    struct AuditEntry {
+        actor: String,
+        #[sensitive(Secret)]
+        password: String,
+        #[sensitive(Token)]
+        #[serde(rename = "apiKey")]
+        api_key: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    }
+
+    fn entry() -> AuditEntry {
+        AuditEntry {
+            actor: "alice".into(),
+            password: "hunter2".into(),
+            api_key: "sk_live_abcdef1234".into(),
+            note: None,
+        }
+    }
+
+    #[test]
+    fn serializes_redacted_fields_with_serde_attributes() {
+        let entry = entry();
+
+        let value = serde_json::to_value(entry.redact_serialize()).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "actor": "alice",
+                "password": "[REDACTED]",
+                "apiKey": "**************1234",
+            })
+        );
+        // The wrapper redacts a clone; the original keeps its values.
+        assert_eq!(entry.password, "hunter2");
+    }
+
+    #[test]
+    fn serializes_redacted_collections_of_entries() {
+        let entries = vec![entry(), entry()];
+
+        let json = serde_json::to_string(&entries.redact_serialize()).unwrap();
+
+        assert!(!json.contains("hunter2"));
+        assert!(!json.contains("sk_live_abcdef1234"));
+        assert_eq!(json.matches("[REDACTED]").count(), 2);
+    }
+}
+
+mod redacted_json_pretty {
+    use redactable::IntoRedactedJsonExt;
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Sensitive, Serialize)]
+    struct AuditDump {
+        actor: String,
+        #[sensitive(Secret)]
+        password: String,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn renders_indented_redacted_json() {
+        let dump = AuditDump {
+            actor: "alice".into(),
+            password: "hunter2".into(),
+            tags: vec!["login".into()],
+        };
+
+        let pretty = dump.into_redacted_json().to_pretty_string();
+
+        assert!(pretty.contains('\n'));
+        assert!(pretty.contains("\"password\": \"[REDACTED]\""));
+        assert!(!pretty.contains("hunter2"));
+        assert_eq!(
+            serde_json::from_str::<Value>(&pretty).unwrap(),
+            json!({ "actor": "alice", "password": "[REDACTED]", "tags": ["login"] })
+        );
+    }
+}
@@ -152,5 +152,7 @@ mod redacted_json;
 mod sensitive_display;
 #[path = "integration_slog/sensitive_value.rs"]
 mod sensitive_value;
+#[path = "integration_slog/slog_flatten.rs"]
+mod slog_flatten;
 #[path = "integration_slog/slog_redacted_json.rs"]
 mod slog_redacted_json;
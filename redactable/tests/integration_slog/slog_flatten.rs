@@ -0,0 +1,82 @@
+use super::*;
+
+#[derive(Clone, Sensitive)]
+#[sensitive(slog_flatten)]
+struct LoginEvent {
+    id: u64,
+    username: String,
+    #[sensitive(Secret)]
+    password: String,
+    #[sensitive(Email)]
+    email: String,
+    r#type: String,
+}
+
+fn login_event(password: &str) -> LoginEvent {
+    LoginEvent {
+        id: 42,
+        username: String::from("alice"),
+        password: password.to_owned(),
+        email: String::from("alice@example.com"),
+        r#type: String::from("login"),
+    }
+}
+
+#[test]
+fn slog_flatten_emits_one_key_per_field() {
+    const CANARY: &str = "slog-flatten-canary-5f1c";
+    let mut serializer = CapturingSerializer::new();
+
+    serialize_to_capture(&login_event(CANARY), "event", &mut serializer);
+
+    assert_eq!(serializer.get("id"), Some(CapturedValue::U64(42)));
+    assert_eq!(
+        serializer.get("username"),
+        Some(CapturedValue::Str(String::from("alice")))
+    );
+    assert_eq!(
+        serializer.get("password"),
+        Some(CapturedValue::Str(String::from("[REDACTED]")))
+    );
+    assert_eq!(
+        serializer.get("email"),
+        Some(CapturedValue::Str(String::from("al***@example.com")))
+    );
+    assert_eq!(
+        serializer.get("type"),
+        Some(CapturedValue::Str(String::from("login")))
+    );
+    assert_eq!(serializer.get("event"), None);
+}
+
+#[test]
+fn slog_flatten_bounds_generic_fields_by_their_own_slog_impls() {
+    #[derive(Clone, Sensitive)]
+    #[sensitive(slog_flatten)]
+    struct Tagged<T, S> {
+        tag: T,
+        #[sensitive(Token)]
+        token: S,
+    }
+
+    let mut serializer = CapturingSerializer::new();
+    let tagged = Tagged {
+        tag: true,
+        token: String::from("sk-secret-12345"),
+    };
+
+    serialize_to_capture(&tagged, "tagged", &mut serializer);
+
+    assert_eq!(serializer.get("tag"), Some(CapturedValue::Bool(true)));
+    assert_eq!(
+        serializer.get("token"),
+        Some(CapturedValue::Str(String::from("***********2345")))
+    );
+}
+
+#[test]
+fn slog_flatten_keeps_the_marker_trait() {
+    fn assert_slog_redacted<T: SlogRedacted>() {}
+
+    assert_slog_redacted::<LoginEvent>();
+}
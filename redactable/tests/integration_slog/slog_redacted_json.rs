@@ -40,6 +40,107 @@ fn slog_redacted_json_does_not_require_debug() {
     assert!(!format!("{:?}", serializer.get("event")).contains(CANARY));
 }
 
+/// Panics if logging ever copies the value instead of consuming it.
+#[derive(Sensitive, Serialize)]
+struct CloneTripwire {
+    #[sensitive(Secret)]
+    secret: String,
+    public: Vec<String>,
+}
+
+impl Clone for CloneTripwire {
+    fn clone(&self) -> Self {
+        panic!("slog_redacted_json must not clone the logged value");
+    }
+}
+
+#[test]
+fn slog_redacted_json_logs_without_cloning() {
+    const CANARY: &str = "slog-no-clone-canary-51c0";
+    let (logger, captured) = capturing_logger();
+    let event = CloneTripwire {
+        secret: CANARY.to_owned(),
+        public: vec!["a".to_owned(), "b".to_owned()],
+    };
+
+    slog::info!(logger, "event"; "event" => event.slog_redacted_json());
+
+    let captured = captured.lock().expect("capture lock");
+    assert_eq!(
+        captured.as_slice(),
+        [CapturedValue::Serde(serde_json::json!({
+            "secret": "[REDACTED]",
+            "public": ["a", "b"],
+        }))]
+    );
+}
+
+#[test]
+fn slog_redacted_json_stays_available_to_sendable_drains() {
+    /// Mirrors async drains, which move nested values to another thread.
+    struct SendableSerializer(Option<serde_json::Value>);
+
+    impl slog::Serializer for SendableSerializer {
+        fn emit_arguments(&mut self, _key: slog::Key, _val: &fmt::Arguments<'_>) -> slog::Result {
+            Ok(())
+        }
+
+        fn emit_serde(&mut self, _key: slog::Key, val: &dyn slog::SerdeValue) -> slog::Result {
+            let sendable = val.to_sendable();
+            self.0 =
+                Some(serde_json::to_value(sendable.as_serde()).expect("redacted json serializes"));
+            Ok(())
+        }
+    }
+
+    let redacted = CloneTripwire {
+        secret: "hunter2".to_owned(),
+        public: Vec::new(),
+    }
+    .slog_redacted_json();
+    let mut serializer = SendableSerializer(None);
+
+    serialize_to_capture(&redacted, "event", &mut serializer);
+
+    assert_eq!(
+        serializer.0,
+        Some(serde_json::json!({ "secret": "[REDACTED]", "public": [] }))
+    );
+}
+
+#[test]
+fn slog_redacted_json_serializes_serde_redact_types_once() {
+    #[derive(Clone, Copy)]
+    struct Fingerprint;
+
+    impl redactable::RedactionPolicy for Fingerprint {
+        type Kind = redactable::TextPolicyKind;
+
+        fn policy() -> redactable::TextRedactionPolicy {
+            redactable::TextRedactionPolicy::token_hash(8)
+        }
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[sensitive(serde_redact)]
+    struct Session {
+        #[sensitive(Fingerprint)]
+        token: String,
+    }
+
+    let session = Session {
+        token: "sk_live_abcdef1234".to_owned(),
+    };
+    let expected = serde_json::to_value(&session).expect("session serializes");
+
+    let mut serializer = CapturingSerializer::new();
+    serialize_to_capture(&session.slog_redacted_json(), "event", &mut serializer);
+    assert_eq!(
+        serializer.get("event"),
+        Some(CapturedValue::Serde(expected))
+    );
+}
+
 #[path = "slog_redacted_json/basic.rs"]
 mod basic;
 #[path = "slog_redacted_json/containers.rs"]
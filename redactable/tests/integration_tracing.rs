@@ -280,6 +280,44 @@ fn redacted_display_works_with_tracing_display_field() {
     );
 }
 
+#[test]
+fn field_helper_records_redacted_display_field() {
+    let error = FixtureError {
+        user: "alice".to_owned(),
+        password: "raw-password".to_owned(),
+    };
+
+    let fields = capture_fields(|| {
+        tracing::info!(
+            free = redactable::tracing::field(&error),
+            method = error.as_tracing_field()
+        );
+    });
+
+    for name in ["free", "method"] {
+        let output = debug_text(field_named(&fields, name), name);
+        assert_eq!(output, "login failed for alice with [REDACTED]");
+        assert!(
+            !output.contains("raw-password"),
+            "raw display secret must not reach tracing, got: {output}"
+        );
+    }
+}
+
+#[test]
+fn field_helper_accepts_generic_dual_values() {
+    const CANARY: &str = "generic-dual-field-canary-7c04";
+    let value = GenericDualFixture {
+        label: String::from("event"),
+        secret: String::from(CANARY),
+    };
+
+    let fields = capture_fields(|| tracing::info!(value = value.as_tracing_field()));
+    let output = debug_text(field_named(&fields, "value"), "value");
+    assert!(output.contains("[REDACTED]"));
+    assert!(!output.contains(CANARY));
+}
+
 #[cfg(all(feature = "tracing-valuable", tracing_unstable))]
 #[test]
 fn valuable_structured_output_records_redacted_fields() {
@@ -1,14 +1,16 @@
-//! Tests for wrapper types: `SensitiveValue<T, P>` and `NotSensitiveValue<T>`.
+//! Tests for wrapper types: `SensitiveValue<T, P>`, `NotSensitiveValue<T>`, and
+//! `RedactKeys<M, P>`.
 //!
 //! These tests verify:
 //! - Wrapper ergonomics (From, Deref, DerefMut, Debug)
 //! - Redaction behavior within containers
 //! - Orphan rule workarounds with `SensitiveWithPolicy`
+//! - Fallible leaves with `TryRedactableWithPolicy`
 
 use redactable::{
-    NotSensitiveValue, Redactable, RedactedOutput, RedactionPolicy, Secret, Sensitive,
-    SensitiveValue, SensitiveWithPolicy, TextPolicyKind, TextRedactionPolicy, ToRedactedOutput,
-    Token,
+    CreditCard, IpAddress, NotSensitiveValue, Redactable, RedactedOutput, RedactionError,
+    RedactionPolicy, Secret, Sensitive, SensitiveValue, SensitiveWithPolicy, TextPolicyKind,
+    TextRedactionPolicy, ToRedactedOutput, Token, TryRedactableWithPolicy,
 };
 #[cfg(feature = "slog")]
 use serde::Serialize;
@@ -55,6 +57,13 @@ mod sensitive_value {
             assert!(!debug.contains("hunter2"));
         }
 
+        #[test]
+        fn shows_redacted_in_display() {
+            let sensitive = SensitiveValue::<String, Token>::from("sk_live_abc123def".to_string());
+            assert_eq!(format!("{sensitive}"), "*************3def");
+            assert_eq!(format!("[{sensitive:>18}]"), "[ *************3def]");
+        }
+
         #[test]
         fn returns_redacted_string() {
             let sensitive = SensitiveValue::<String, Token>::from("sk_live_abc123def".to_string());
@@ -402,6 +411,72 @@ mod orphan_rule_workaround {
     }
 }
 
+mod fallible_leaves {
+    use super::*;
+
+    #[derive(Clone, PartialEq, Debug)]
+    struct CardNumber(String);
+
+    impl TryRedactableWithPolicy<CreditCard> for CardNumber {
+        fn try_redact_with_policy(
+            self,
+            policy: &TextRedactionPolicy,
+        ) -> Result<Self, RedactionError> {
+            let digits = self.0.chars().filter(|ch| *ch != ' ').count();
+            let well_formed = self.0.chars().all(|ch| ch.is_ascii_digit() || ch == ' ');
+            if !well_formed || !(12..=19).contains(&digits) {
+                return Err(RedactionError::new("not a card number"));
+            }
+            Ok(Self(policy.apply_to(&self.0)))
+        }
+    }
+
+    #[test]
+    fn redacts_well_formed_input() {
+        let card =
+            SensitiveValue::<CardNumber, CreditCard>::from(CardNumber("4111111111111111".into()));
+
+        let redacted = card.try_redact().unwrap();
+
+        assert_eq!(redacted.expose(), &CardNumber("************1111".into()));
+    }
+
+    #[test]
+    fn reports_malformed_input() {
+        let card = SensitiveValue::<CardNumber, CreditCard>::from(CardNumber("4111-oops".into()));
+
+        let Err(error) = card.try_redact() else {
+            panic!("malformed card number must be rejected");
+        };
+
+        assert_eq!(error.message(), "not a card number");
+        assert_eq!(error.to_string(), "redaction failed: not a card number");
+        assert!(!error.to_string().contains("4111"));
+    }
+
+    #[test]
+    fn strings_redact_infallibly() {
+        let token = SensitiveValue::<String, Token>::from("sk_live_abc123".to_string());
+        assert_eq!(token.try_redact().unwrap().expose(), "**********c123");
+    }
+
+    #[test]
+    fn strings_report_input_a_structural_policy_cannot_parse() {
+        let ip = SensitiveValue::<String, IpAddress>::from("garbage".to_string());
+        let Err(error) = ip.try_redact() else {
+            panic!("malformed IP address must be rejected");
+        };
+        assert_eq!(error.message(), "not an IP address");
+        assert!(!error.to_string().contains("garbage"));
+
+        let boxed = SensitiveValue::<Box<str>, IpAddress>::from(Box::from("10.0.0"));
+        assert!(boxed.try_redact().is_err());
+
+        let well_formed = SensitiveValue::<String, IpAddress>::from("192.168.1.42".to_string());
+        assert_eq!(well_formed.try_redact().unwrap().expose(), "192.168.*.*");
+    }
+}
+
 mod combined_wrappers {
     use super::*;
 
@@ -562,3 +637,80 @@ mod serde_json_round_trip {
         );
     }
 }
+
+mod redact_keys {
+    use std::collections::{BTreeMap, HashMap};
+
+    use redactable::{RedactKeys, RedactableWithFormatter};
+
+    use super::*;
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(Serialize))]
+    struct Balance {
+        #[sensitive(Secret)]
+        owner: String,
+        cents: u64,
+    }
+
+    #[derive(Clone, Sensitive)]
+    #[cfg_attr(feature = "slog", derive(Serialize))]
+    struct Ledger {
+        balances: RedactKeys<HashMap<String, Balance>, Token>,
+    }
+
+    fn balance(owner: &str, cents: u64) -> Balance {
+        Balance {
+            owner: owner.into(),
+            cents,
+        }
+    }
+
+    #[test]
+    fn redacts_keys_and_walks_values() {
+        let ledger = Ledger {
+            balances: HashMap::from([
+                ("acct_0001111".to_string(), balance("alice", 100)),
+                ("acct_0002222".to_string(), balance("bob", 200)),
+            ])
+            .into(),
+        };
+
+        let redacted = ledger.redact().balances.into_inner();
+
+        assert_eq!(redacted.len(), 2);
+        assert!(!redacted.contains_key("acct_0001111"));
+        assert_eq!(redacted["********1111"].cents, 100);
+        assert_eq!(redacted["********2222"].owner, "[REDACTED]");
+    }
+
+    #[test]
+    fn keeps_one_entry_per_distinct_redacted_key() {
+        let map = BTreeMap::from([
+            ("alice".to_string(), NotSensitiveValue(1_u32)),
+            ("bob".to_string(), NotSensitiveValue(2)),
+            ("carol".to_string(), NotSensitiveValue(3)),
+        ]);
+
+        let redacted = RedactKeys::<_, Secret>::from(map).redact().into_inner();
+
+        // Every key redacts to the placeholder; the greatest original key wins.
+        assert_eq!(
+            redacted,
+            BTreeMap::from([("[REDACTED]".to_string(), NotSensitiveValue(3))])
+        );
+    }
+
+    #[test]
+    fn formats_redacted_keys() {
+        let map = BTreeMap::from([("acct_0001111".to_string(), "open".to_string())]);
+        let wrapped = RedactKeys::<_, Token>::from(map);
+
+        assert_eq!(format!("{wrapped:?}"), r#"{"********1111": "open"}"#);
+        assert_eq!(
+            wrapped.redacted_display().to_string(),
+            r#"{"********1111": open}"#
+        );
+        assert!(wrapped.expose().contains_key("acct_0001111"));
+    }
+}
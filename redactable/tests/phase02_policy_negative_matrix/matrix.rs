@@ -4,8 +4,8 @@ use std::{
     process::Command,
 };
 
-pub(crate) const EXPECTED_NEGATIVE_CELLS: usize = 938;
-pub(crate) const EXPECTED_GRID_FINGERPRINT: u64 = 0x5fc6_befe_c3d9_4865;
+pub(crate) const EXPECTED_NEGATIVE_CELLS: usize = 934;
+pub(crate) const EXPECTED_GRID_FINGERPRINT: u64 = 0x3be1_e9b2_23aa_ce2f;
 const EXPECTED_POSITIVE_CONTROLS: usize = 8;
 const EXPECTED_POSITIVE_FINGERPRINT: u64 = 0xa393_7d01_0e4c_a5c1;
 const EXPECTED_POSITIVE_SOURCE_FINGERPRINT: u64 = 0xa3fc_8a96_1984_42ed;
@@ -209,6 +209,13 @@ macro_rules! grouped_display {
     for &(identity, scalar) in SCALARS {
         source.push_str(&format!("type NegativeAlias{identity} = {scalar};\n"));
         for (family, field_type) in recursive_families(scalar, false) {
+            // Secret byte arrays and byte vectors are supported whole-value
+            // leaves (zeroed or emptied, and formatted as the placeholder),
+            // not recursive container cells.
+            if identity == "U8" && matches!(family, "Array" | "Vec") {
+                index += 2;
+                continue;
+            }
             for &(derive, display) in &[("Sensitive", false), ("Display", true)] {
                 push_reject(
                     &mut source,
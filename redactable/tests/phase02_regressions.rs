@@ -143,26 +143,26 @@ struct SemanticIpAddressDisplay {
 #[test]
 fn qualified_absolute_and_aliased_ip_policies_use_type_identity() {
     let redacted = SemanticIpAddresses {
-        qualified: "192.0.2.7".parse().unwrap(),
-        absolute: "192.0.2.8".parse().unwrap(),
-        aliased_policy: "192.0.2.9:8443".parse().unwrap(),
+        qualified: "192.7.2.1".parse().unwrap(),
+        absolute: "192.8.2.1".parse().unwrap(),
+        aliased_policy: "192.9.2.1:8443".parse().unwrap(),
     }
     .redact();
-    assert_eq!(redacted.qualified, Ipv4Addr::new(0, 0, 0, 7));
-    assert_eq!(redacted.absolute, "0.0.0.8".parse::<IpAddr>().unwrap());
+    assert_eq!(redacted.qualified, Ipv4Addr::new(192, 7, 0, 0));
+    assert_eq!(redacted.absolute, "192.8.0.0".parse::<IpAddr>().unwrap());
     assert_eq!(
         redacted.aliased_policy,
-        "0.0.0.9:8443".parse::<SocketAddr>().unwrap()
+        "192.9.0.0:8443".parse::<SocketAddr>().unwrap()
     );
 
     let display = SemanticIpAddressDisplay {
-        qualified: "192.0.2.7".parse().unwrap(),
-        absolute: "192.0.2.8".parse().unwrap(),
-        aliased_policy: "192.0.2.9:8443".parse().unwrap(),
+        qualified: "192.7.2.1".parse().unwrap(),
+        absolute: "192.8.2.1".parse().unwrap(),
+        aliased_policy: "192.9.2.1:8443".parse().unwrap(),
     };
     assert_eq!(
         display.redacted_display().to_string(),
-        "0.0.0.7 0.0.0.8 0.0.0.9:8443"
+        "192.7.0.0 192.8.0.0 192.9.0.0:8443"
     );
 }
 
@@ -212,7 +212,7 @@ struct CustomSecretPolicy {
 
 #[test]
 fn custom_same_tail_policy_paths_preserve_map_keys() {
-    let key: IpAddr = "192.0.2.7".parse().expect("valid test IP");
+    let key: IpAddr = "192.7.2.1".parse().expect("valid test IP");
     let values = HashMap::from([(key, "sensitive-value".to_owned())]);
     let custom = CustomPolicyMap { values }.redact();
     assert!(custom.values.contains_key(&key));
@@ -222,7 +222,7 @@ fn custom_same_tail_policy_paths_preserve_map_keys() {
         values: HashMap::from([(key, "sensitive-value".to_owned())]),
     };
     let rendered = display.redacted_display().to_string();
-    assert!(rendered.contains("192.0.2.7"));
+    assert!(rendered.contains("192.7.2.1"));
     assert!(rendered.contains("*************ue"));
 
     let custom_secret = CustomSecretPolicy {
@@ -270,3 +270,87 @@ fn generated_identifiers_do_not_collide_with_user_fields() {
         "[REDACTED] \"public\""
     );
 }
+
+#[derive(Clone, Sensitive, Serialize)]
+struct PlainNameStruct<M> {
+    #[sensitive(Secret)]
+    mapper: M,
+    f: String,
+    debug: String,
+    #[sensitive(Secret)]
+    value: String,
+    redacted: u32,
+}
+
+#[derive(Clone, Sensitive, Serialize)]
+enum PlainNameEnum {
+    Mapper {
+        #[sensitive(Secret)]
+        mapper: String,
+        f: String,
+    },
+    Debug {
+        debug: String,
+        #[sensitive(Secret)]
+        value: String,
+    },
+}
+
+#[derive(SensitiveDisplay)]
+#[error("{mapper} {f} {debug:?} {value}")]
+struct PlainNameDisplay {
+    #[sensitive(Secret)]
+    mapper: String,
+    #[not_sensitive]
+    f: String,
+    #[not_sensitive]
+    debug: String,
+    #[sensitive(Secret)]
+    value: String,
+}
+
+#[test]
+fn plain_names_of_generated_locals_are_usable_as_fields() {
+    let redacted = PlainNameStruct {
+        mapper: CANARY.to_owned(),
+        f: "f".to_owned(),
+        debug: "debug".to_owned(),
+        value: CANARY.to_owned(),
+        redacted: 7,
+    }
+    .redact();
+    assert_eq!(redacted.mapper, "[REDACTED]");
+    assert_eq!(redacted.f, "f");
+    assert_eq!(redacted.debug, "debug");
+    assert_eq!(redacted.value, "[REDACTED]");
+    assert_eq!(redacted.redacted, 7);
+
+    let PlainNameEnum::Mapper { mapper, f } = PlainNameEnum::Mapper {
+        mapper: CANARY.to_owned(),
+        f: "f".to_owned(),
+    }
+    .redact() else {
+        panic!("expected the mapper variant");
+    };
+    assert_eq!((mapper.as_str(), f.as_str()), ("[REDACTED]", "f"));
+
+    let PlainNameEnum::Debug { debug, value } = PlainNameEnum::Debug {
+        debug: "debug".to_owned(),
+        value: CANARY.to_owned(),
+    }
+    .redact() else {
+        panic!("expected the debug variant");
+    };
+    assert_eq!((debug.as_str(), value.as_str()), ("debug", "[REDACTED]"));
+
+    let display = PlainNameDisplay {
+        mapper: CANARY.to_owned(),
+        f: "f".to_owned(),
+        debug: "debug".to_owned(),
+        value: CANARY.to_owned(),
+    };
+    assert_eq!(
+        display.redacted_display().to_string(),
+        "[REDACTED] f \"debug\" [REDACTED]"
+    );
+}
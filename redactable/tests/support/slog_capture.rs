@@ -18,6 +18,7 @@ pub(crate) enum CapturedValue {
     Unit,
     None,
     Serde(JsonValue),
+    Bytes(Vec<u8>),
 }
 
 impl CapturingSerializer {
@@ -89,6 +90,13 @@ impl slog::Serializer for CapturingSerializer {
         Ok(())
     }
 
+    fn emit_bytes(&mut self, key: slog::Key, bytes: &[u8], _kind: slog::BytesKind) -> slog::Result {
+        self.captured
+            .borrow_mut()
+            .insert(key.into(), CapturedValue::Bytes(bytes.to_vec()));
+        Ok(())
+    }
+
     fn emit_serde(&mut self, key: slog::Key, val: &dyn slog::SerdeValue) -> slog::Result {
         let json = serde_json::to_value(val.as_serde()).unwrap_or(JsonValue::Null);
         self.captured
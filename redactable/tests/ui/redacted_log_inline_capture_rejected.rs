@@ -0,0 +1,10 @@
+//! `redacted_log!` must not accept identifiers captured inline.
+//!
+//! The macro wraps positional arguments with `as_log()`, but `log` formats an
+//! identifier captured by the format string (`{password}`) itself, so any
+//! `Display` value, such as this raw `String`, would reach the record as-is.
+
+fn main() {
+    let password = String::from("hunter2");
+    redactable::redacted_log!(log::Level::Warn, "rejected {password}");
+}
@@ -0,0 +1,20 @@
+//! Borrowed redacted output clones before redacting, and `Sensitive` does not
+//! derive `Clone`. The diagnostic must name the missing requirement and point
+//! at the consuming alternative instead of only listing unrelated implementors.
+
+use redactable::{RedactedOutputExt, Secret, Sensitive};
+
+#[derive(Sensitive)]
+struct Account {
+    #[sensitive(Secret)]
+    password: String,
+}
+
+fn log_borrowed<T: RedactedOutputExt>(_: &T) {}
+
+fn main() {
+    let account = Account {
+        password: "hunter2".into(),
+    };
+    log_borrowed(&account);
+}
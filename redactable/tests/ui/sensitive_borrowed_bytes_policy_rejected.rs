@@ -0,0 +1,10 @@
+use redactable::Sensitive;
+
+#[derive(Clone, Sensitive)]
+struct Upload<'a> {
+    name: String,
+    #[sensitive(redactable::Secret)]
+    bytes: &'a [u8],
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use redactable::Sensitive;
+
+#[derive(Clone, Sensitive)]
+struct LoginRequest<'a> {
+    user: &'a str,
+    #[sensitive(redactable::Secret)]
+    password: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use redactable::SensitiveDisplay;
+
+#[derive(SensitiveDisplay)]
+#[display(42)]
+struct NotALiteral {
+    value: String,
+}
+
+#[derive(SensitiveDisplay)]
+#[display(template = "value {value}")]
+struct WrongKey {
+    value: String,
+}
+
+#[derive(SensitiveDisplay)]
+#[display]
+struct MissingTemplate {
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use redactable::SensitiveDisplay;
+
+#[derive(SensitiveDisplay)]
+#[error("{value}")]
+struct Event {
+    #[sensitive(Secret, prod_only)]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! `#[sensitive(serde_redact)]` on a standalone `SensitiveDisplay` must fail to
+//! compile: display-only types have no structural redaction to serialize.
+
+use redactable::SensitiveDisplay;
+
+/// {0}
+#[derive(SensitiveDisplay)]
+#[sensitive(serde_redact)]
+struct ApiKey(#[sensitive(redactable::Token)] String);
+
+fn main() {}
@@ -18,4 +18,18 @@ struct UnsupportedUpperHexDebug {
     value: u32,
 }
 
+#[derive(SensitiveDisplay)]
+#[error("value {value:b}")]
+struct UnsupportedBinaryOnPolicyField {
+    #[sensitive(redactable::Secret)]
+    value: String,
+}
+
+#[derive(SensitiveDisplay)]
+#[error("value {value:p}")]
+struct UnsupportedPointerOnNotSensitiveField {
+    #[not_sensitive]
+    value: u32,
+}
+
 fn main() {}
@@ -0,0 +1,10 @@
+use redactable::Sensitive;
+
+#[derive(Clone, Sensitive)]
+#[sensitive(gen_redacted_type)]
+struct Session {
+    #[sensitive(redactable::Token, prod_only)]
+    user_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use redactable::Sensitive;
+
+#[derive(Clone, Default, Sensitive)]
+#[sensitive(opaque_variant)]
+struct Diagnosis {
+    #[sensitive(redactable::Secret)]
+    notes: String,
+}
+
+fn main() {}
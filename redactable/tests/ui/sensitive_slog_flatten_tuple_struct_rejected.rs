@@ -0,0 +1,7 @@
+use redactable::Sensitive;
+
+#[derive(Clone, Sensitive)]
+#[sensitive(slog_flatten)]
+struct Credentials(#[sensitive(redactable::Secret)] String);
+
+fn main() {}
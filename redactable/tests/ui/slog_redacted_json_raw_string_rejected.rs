@@ -0,0 +1,14 @@
+//! A raw passthrough value must not satisfy `SlogRedactedExt`.
+//!
+//! `String` implements `Serialize` but not `Redactable`, so
+//! `slog_redacted_json()` must not certify it as redacted slog output. The
+//! diagnostic points to the wrappers and derives that do.
+
+use redactable::slog::SlogRedactedExt;
+
+fn require_certified<T: SlogRedactedExt>(_: &T) {}
+
+fn main() {
+    let password = String::from("hunter2");
+    require_certified(&password);
+}
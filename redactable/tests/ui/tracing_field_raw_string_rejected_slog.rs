@@ -0,0 +1,9 @@
+//! A raw passthrough value must not satisfy `redactable::tracing::field`.
+//!
+//! `String` formats itself through `RedactableWithFormatter`, but it is not
+//! `TracingRedacted`, so it cannot certify its own tracing output.
+
+fn main() {
+    let raw = String::from("raw-secret");
+    tracing::info!(value = redactable::tracing::field(&raw));
+}
@@ -0,0 +1,8 @@
+#[cfg(feature = "log")]
+mod log_certification {
+    #[test]
+    fn rejects_redacted_log_inline_captures() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/redacted_log_inline_capture_rejected.rs");
+    }
+}
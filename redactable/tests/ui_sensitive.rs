@@ -100,6 +100,36 @@ mod sensitive {
         }
     }
 
+    #[test]
+    fn rejects_policy_on_borrowed_str_field() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_borrowed_str_policy_rejected.rs");
+    }
+
+    #[test]
+    fn rejects_policy_on_borrowed_bytes_field() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_borrowed_bytes_policy_rejected.rs");
+    }
+
+    #[test]
+    fn rejects_opaque_variant_on_struct() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_opaque_variant_struct_rejected.rs");
+    }
+
+    #[test]
+    fn rejects_prod_only_field_in_gen_redacted_type() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_gen_redacted_type_prod_only_rejected.rs");
+    }
+
+    #[test]
+    fn rejects_slog_flatten_on_tuple_struct() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_slog_flatten_tuple_struct_rejected.rs");
+    }
+
     #[test]
     fn rejects_bare_sensitive_attribute() {
         let t = trybuild::TestCases::new();
@@ -71,6 +71,12 @@ mod sensitive_display {
         }
     }
 
+    #[test]
+    fn rejects_prod_only_policy() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_display_prod_only_rejected.rs");
+    }
+
     #[test]
     fn rejects_legacy_formatting_without_policy() {
         let t = trybuild::TestCases::new();
@@ -131,6 +137,12 @@ mod sensitive_display {
         t.compile_fail("tests/ui/sensitive_display_missing_template_rejected.rs");
     }
 
+    #[test]
+    fn rejects_malformed_display_attribute() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_display_malformed_display_attr_rejected.rs");
+    }
+
     #[test]
     fn rejects_unknown_template_field() {
         let t = trybuild::TestCases::new();
@@ -172,4 +184,10 @@ mod sensitive_display {
         let t = trybuild::TestCases::new();
         t.compile_fail("tests/ui/sensitive_display_dual_without_sensitive_rejected.rs");
     }
+
+    #[test]
+    fn rejects_serde_redact_without_sensitive() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/sensitive_display_serde_redact_rejected.rs");
+    }
 }
@@ -5,4 +5,10 @@ mod slog_certification {
         let t = trybuild::TestCases::new();
         t.compile_fail("tests/ui/slog_redacted_display_raw_string_rejected.rs");
     }
+
+    #[test]
+    fn rejects_slog_redacted_json_on_raw_string() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/slog_redacted_json_raw_string_rejected.rs");
+    }
 }
@@ -13,7 +13,6 @@ mod to_redacted_output {
         t.compile_fail("tests/ui/not_sensitive_value_output_rejected.rs");
         t.compile_fail("tests/ui/not_sensitive_debug_requires_debug.rs");
         t.compile_fail("tests/ui/not_sensitive_display_requires_display.rs");
-        t.compile_fail("tests/ui/not_sensitive_display_sensitive_value_rejected.rs");
     }
 
     #[test]
@@ -26,6 +25,7 @@ mod to_redacted_output {
     fn redacted_output_availability_matches_clone_and_debug_bounds() {
         let t = trybuild::TestCases::new();
         t.compile_fail("tests/ui/redacted_output_raw_string_rejected.rs");
+        t.compile_fail("tests/ui/redacted_output_missing_clone_rejected.rs");
         t.pass("tests/ui/redacted_output_borrow_sensitive_map_key_rejected.rs");
     }
 
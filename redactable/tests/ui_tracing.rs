@@ -5,6 +5,16 @@ mod tracing_certification {
         let t = trybuild::TestCases::new();
         t.compile_fail("tests/ui/tracing_redacted_debug_raw_string_rejected.rs");
     }
+
+    #[test]
+    fn rejects_tracing_field_on_raw_string() {
+        let t = trybuild::TestCases::new();
+        if cfg!(feature = "slog") {
+            t.compile_fail("tests/ui/tracing_field_raw_string_rejected_slog.rs");
+        } else {
+            t.compile_fail("tests/ui/tracing_field_raw_string_rejected.rs");
+        }
+    }
 }
 
 #[cfg(feature = "tracing-valuable")]
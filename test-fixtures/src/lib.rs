@@ -42,6 +42,15 @@ pub enum FixtureEvent {
     },
 }
 
+/// Opaque enum fixture: production `Debug` must not name the variant.
+#[derive(Clone, Default, Sensitive, serde::Serialize)]
+#[sensitive(opaque_variant)]
+pub enum FixtureDiagnosis {
+    Chronic(String),
+    #[default]
+    Redacted,
+}
+
 // Display fixture: `SensitiveDisplay` derive with one annotated leaf. The doc
 // comment is the display template, so the explanation lives in this comment.
 /// login failed for {user} with {password}